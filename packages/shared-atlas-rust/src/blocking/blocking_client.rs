@@ -0,0 +1,286 @@
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::blocking::chat_response_iter::ChatResponseIter;
+use crate::client::atlas_client::AtlasClient;
+use crate::client::auth_mode::AuthMode;
+use crate::client::client_error::ClientError;
+use crate::client::upload_error::UploadError;
+use crate::client::upload_file::UploadFile;
+use crate::client::upload_options::UploadOptions;
+use crate::dto::get_messages_dto::GetMessagesDto;
+use crate::dto::list_notes_query::ListNotesQuery;
+use crate::dto::login_dto::LoginDto;
+use crate::dto::search_query::SearchQuery;
+use crate::dto::signup_dto::SignupDto;
+use crate::dto::upload_file_query_dto::UploadFileQueryDto;
+use crate::ids::block_id::BlockId;
+use crate::ids::message_id::MessageId;
+use crate::ids::note_id::NoteId;
+use crate::ids::session_id::SessionId;
+use crate::interfaces::add_message_dto::AddMessageDto;
+use crate::interfaces::auth_response::AuthResponse;
+use crate::interfaces::chat_message_response::ChatMessageResponse;
+use crate::interfaces::chat_session_response::ChatSessionResponse;
+use crate::interfaces::create_block_dto::CreateBlockDto;
+use crate::interfaces::create_note_dto::CreateNoteDto;
+use crate::interfaces::create_session_dto::CreateSessionDto;
+use crate::interfaces::get_messages_response::GetMessagesResponse;
+use crate::interfaces::iblock::IBlock;
+use crate::interfaces::ifile_upload_response::IFileUploadResponse;
+use crate::interfaces::inote::INote;
+use crate::interfaces::iuser::IUser;
+use crate::interfaces::logout_response::LogoutResponse;
+use crate::interfaces::message_response::MessageResponse;
+use crate::interfaces::move_block_dto::MoveBlockDto;
+use crate::interfaces::note_list_response::NoteListResponse;
+use crate::interfaces::note_with_blocks_response::NoteWithBlocksResponse;
+use crate::interfaces::search_result_dto::SearchResultDto;
+use crate::interfaces::success_response::SuccessResponse;
+use crate::interfaces::token_response::TokenResponse;
+use crate::interfaces::update_block_dto::UpdateBlockDto;
+use crate::interfaces::update_message_dto::UpdateMessageDto;
+use crate::interfaces::update_note_dto::UpdateNoteDto;
+use crate::interfaces::update_session_dto::UpdateSessionDto;
+
+/// Synchronous client for the Atlas REST API.
+/// Atlas REST API 同步客户端。
+///
+/// Wraps an [`AtlasClient`], so auth modes, token refresh and errors behave
+/// the same. Clones share the runtime, connection pool, cookies and tokens.
+/// Must not be called, or its last clone dropped, from async code.
+/// 封装 [`AtlasClient`]，认证模式、令牌刷新与错误行为一致。克隆体共享运行时、
+/// 连接池、Cookie 与令牌。不得在异步代码中调用，也不得在其中丢弃最后一个克隆体。
+#[derive(Clone)]
+pub struct BlockingClient {
+    inner: AtlasClient,
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingClient {
+    pub fn new(base_url: impl Into<String>, mode: AuthMode) -> Result<Self, ClientError> {
+        BlockingClient::from_async(AtlasClient::new(base_url, mode)?)
+    }
+
+    /// Runs a configured async client synchronously.
+    /// 以同步方式使用已配置的异步客户端。
+    pub fn from_async(inner: AtlasClient) -> Result<Self, ClientError> {
+        let runtime = Builder::new_current_thread().enable_all().build().map_err(ClientError::Runtime)?;
+        Ok(BlockingClient { inner, runtime: Arc::new(runtime) })
+    }
+
+    /// Restores a saved session.
+    /// 恢复已保存的会话。
+    pub fn with_tokens(self, tokens: TokenResponse) -> Self {
+        self.inner.set_tokens(Some(tokens));
+        self
+    }
+
+    /// The wrapped async client.
+    /// 被封装的异步客户端。
+    pub fn as_async(&self) -> &AtlasClient {
+        &self.inner
+    }
+
+    pub fn tokens(&self) -> Option<TokenResponse> {
+        self.inner.tokens()
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// `POST /auth/login`.
+    pub fn login(&self, dto: &LoginDto) -> Result<AuthResponse, ClientError> {
+        self.block_on(self.inner.login(dto))
+    }
+
+    /// `POST /auth/signup`.
+    pub fn signup(&self, dto: &SignupDto) -> Result<IUser, ClientError> {
+        self.block_on(self.inner.signup(dto))
+    }
+
+    /// `POST /auth/refresh`.
+    pub fn refresh(&self) -> Result<TokenResponse, ClientError> {
+        self.block_on(self.inner.refresh())
+    }
+
+    /// `GET /auth/profile`.
+    pub fn profile(&self) -> Result<IUser, ClientError> {
+        self.block_on(self.inner.profile())
+    }
+
+    /// `POST /auth/logout`.
+    pub fn logout(&self) -> Result<LogoutResponse, ClientError> {
+        self.block_on(self.inner.logout())
+    }
+
+    /// `POST /notes`.
+    pub fn create_note(&self, dto: &CreateNoteDto) -> Result<INote, ClientError> {
+        self.block_on(self.inner.create_note(dto))
+    }
+
+    /// `GET /notes`.
+    pub fn list_notes(&self, query: &ListNotesQuery) -> Result<NoteListResponse, ClientError> {
+        self.block_on(self.inner.list_notes(query))
+    }
+
+    /// `GET /notes/:id`.
+    pub fn get_note(&self, id: &NoteId) -> Result<INote, ClientError> {
+        self.block_on(self.inner.get_note(id))
+    }
+
+    /// The note and its blocks, see [`AtlasClient::get_note_with_blocks`].
+    /// 笔记及其块，见 [`AtlasClient::get_note_with_blocks`]。
+    pub fn get_note_with_blocks(&self, id: &NoteId) -> Result<NoteWithBlocksResponse, ClientError> {
+        self.block_on(self.inner.get_note_with_blocks(id))
+    }
+
+    /// `PATCH /notes/:id`.
+    pub fn update_note(&self, id: &NoteId, dto: &UpdateNoteDto) -> Result<INote, ClientError> {
+        self.block_on(self.inner.update_note(id, dto))
+    }
+
+    /// `DELETE /notes/:id`.
+    pub fn delete_note(&self, id: &NoteId) -> Result<MessageResponse, ClientError> {
+        self.block_on(self.inner.delete_note(id))
+    }
+
+    /// `POST /notes/:id/duplicate`.
+    pub fn duplicate_note(&self, id: &NoteId) -> Result<INote, ClientError> {
+        self.block_on(self.inner.duplicate_note(id))
+    }
+
+    /// `GET /notes/:noteId/blocks`.
+    pub fn list_blocks(&self, note_id: &NoteId) -> Result<Vec<IBlock>, ClientError> {
+        self.block_on(self.inner.list_blocks(note_id))
+    }
+
+    /// `POST /notes/:noteId/blocks`.
+    pub fn create_block(&self, note_id: &NoteId, dto: &CreateBlockDto) -> Result<IBlock, ClientError> {
+        self.block_on(self.inner.create_block(note_id, dto))
+    }
+
+    /// `PATCH /blocks/:id`.
+    pub fn update_block(&self, id: &BlockId, dto: &UpdateBlockDto) -> Result<IBlock, ClientError> {
+        self.block_on(self.inner.update_block(id, dto))
+    }
+
+    /// `DELETE /blocks/:id`.
+    pub fn delete_block(&self, id: &BlockId) -> Result<MessageResponse, ClientError> {
+        self.block_on(self.inner.delete_block(id))
+    }
+
+    /// `POST /blocks/:id/move`.
+    pub fn move_block(&self, id: &BlockId, dto: &MoveBlockDto) -> Result<IBlock, ClientError> {
+        self.block_on(self.inner.move_block(id, dto))
+    }
+
+    /// `POST /chat/sessions`.
+    pub fn create_session(&self, dto: &CreateSessionDto) -> Result<ChatSessionResponse, ClientError> {
+        self.block_on(self.inner.create_session(dto))
+    }
+
+    /// `GET /chat/sessions`.
+    pub fn list_sessions(&self) -> Result<Vec<ChatSessionResponse>, ClientError> {
+        self.block_on(self.inner.list_sessions())
+    }
+
+    /// `GET /chat/sessions/:id`.
+    pub fn get_session(&self, id: &SessionId) -> Result<ChatSessionResponse, ClientError> {
+        self.block_on(self.inner.get_session(id))
+    }
+
+    /// `PATCH /chat/sessions/:id`.
+    pub fn rename_session(&self, id: &SessionId, dto: &UpdateSessionDto) -> Result<ChatSessionResponse, ClientError> {
+        self.block_on(self.inner.rename_session(id, dto))
+    }
+
+    /// `DELETE /chat/sessions/:id`.
+    pub fn delete_session(&self, id: &SessionId) -> Result<SuccessResponse, ClientError> {
+        self.block_on(self.inner.delete_session(id))
+    }
+
+    /// `GET /chat/sessions/:id/messages`, oldest first.
+    pub fn list_messages(
+        &self,
+        session_id: &SessionId,
+        query: &GetMessagesDto,
+    ) -> Result<GetMessagesResponse, ClientError> {
+        self.block_on(self.inner.list_messages(session_id, query))
+    }
+
+    /// `POST /chat/sessions/:id/messages`; iterates over the answer as it is
+    /// generated.
+    /// 发送消息，并在回复生成时逐个迭代其事件。
+    pub fn add_message(&self, session_id: &SessionId, dto: &AddMessageDto) -> Result<ChatResponseIter, ClientError> {
+        let stream = self.block_on(self.inner.add_message(session_id, dto))?;
+        Ok(ChatResponseIter::new(self.runtime.clone(), stream))
+    }
+
+    /// `PATCH /chat/sessions/:sessionId/messages/:messageId`.
+    pub fn update_message(
+        &self,
+        session_id: &SessionId,
+        message_id: &MessageId,
+        dto: &UpdateMessageDto,
+    ) -> Result<ChatMessageResponse, ClientError> {
+        self.block_on(self.inner.update_message(session_id, message_id, dto))
+    }
+
+    /// `GET /search?q=`; notes whose title and blocks whose content match.
+    /// 搜索标题匹配的笔记与内容匹配的块。
+    pub fn search(&self, query: &SearchQuery) -> Result<SearchResultDto, ClientError> {
+        self.block_on(self.inner.search(query))
+    }
+
+    /// Opens the file at `path` for upload.
+    /// 打开 `path` 处的文件以供上传。
+    pub fn open_file(&self, path: impl AsRef<Path>) -> io::Result<UploadFile> {
+        self.block_on(UploadFile::open(path))
+    }
+
+    /// `POST /upload/image/:noteId`.
+    pub fn upload_image(
+        &self,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.block_on(self.inner.upload_image(note_id, file, options))
+    }
+
+    /// `POST /upload/video/:noteId`.
+    pub fn upload_video(
+        &self,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.block_on(self.inner.upload_video(note_id, file, options))
+    }
+
+    /// `POST /upload/file/:noteId`.
+    pub fn upload_attachment(
+        &self,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.block_on(self.inner.upload_attachment(note_id, file, options))
+    }
+
+    /// `POST /storage/upload?module=&folder=`.
+    pub fn upload_to_storage(
+        &self,
+        query: &UploadFileQueryDto,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.block_on(self.inner.upload_to_storage(query, file, options))
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+use tokio::runtime::Runtime;
+
+use crate::client::chat_response_stream::ChatResponseStream;
+use crate::client::client_error::ClientError;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+
+/// The streamed chat answer as a blocking iterator.
+/// 以阻塞迭代器形式提供的流式聊天回复。
+///
+/// Each `next` waits for the following event. Ends after `done` or `error`,
+/// or after the first failure, like [`ChatResponseStream`]. Dropping it
+/// closes the connection.
+/// 每次 `next` 等待下一个事件。与 [`ChatResponseStream`] 一样在 `done`、`error`
+/// 或首个失败之后结束。丢弃即关闭连接。
+pub struct ChatResponseIter {
+    runtime: Arc<Runtime>,
+    stream: ChatResponseStream,
+}
+
+impl ChatResponseIter {
+    pub(crate) fn new(runtime: Arc<Runtime>, stream: ChatResponseStream) -> Self {
+        ChatResponseIter { runtime, stream }
+    }
+}
+
+impl Iterator for ChatResponseIter {
+    type Item = Result<ChatStreamEvent, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
@@ -0,0 +1,11 @@
+//! Synchronous facade over the REST client (feature `blocking`).
+//! REST 客户端的同步封装（`blocking` 特性）。
+//!
+//! For CLI tools and build scripts without an async runtime: each call runs
+//! the async [`crate::client`] request to completion on a private
+//! current-thread runtime.
+//! 面向没有异步运行时的命令行工具与构建脚本：每次调用都在私有的单线程运行时上
+//! 执行 [`crate::client`] 的异步请求直至完成。
+
+pub mod blocking_client;
+pub mod chat_response_iter;
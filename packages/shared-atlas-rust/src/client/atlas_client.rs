@@ -0,0 +1,360 @@
+use std::sync::{Arc, RwLock};
+
+use reqwest::header::{HeaderValue, AUTHORIZATION, RETRY_AFTER};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::auth_mode::AuthMode;
+use crate::client::client_error::ClientError;
+use crate::client::session_refresh::SessionRefresh;
+use crate::client::social_login_outcome::SocialLoginOutcome;
+use crate::dto::google_login_dto::GoogleLoginDto;
+use crate::dto::login_dto::LoginDto;
+use crate::dto::microsoft_login_dto::MicrosoftLoginDto;
+use crate::dto::refresh_token_dto::RefreshTokenDto;
+use crate::dto::signup_dto::SignupDto;
+use crate::dto::social_signup_dto::SocialSignupDto;
+use crate::error::api_error::ApiError;
+use crate::interfaces::auth_response::AuthResponse;
+use crate::interfaces::iuser::IUser;
+use crate::interfaces::logout_response::LogoutResponse;
+use crate::interfaces::mobile_auth_response::MobileAuthResponse;
+use crate::interfaces::refresh_token_response::RefreshTokenResponse;
+use crate::interfaces::social_signup_required_response::SocialSignupRequiredResponse;
+use crate::interfaces::token_response::TokenResponse;
+
+/// Header selecting the mobile token-body mode.
+/// 选择移动端令牌模式的请求头。
+pub const AUTH_MODE_HEADER: &str = "x-auth-mode";
+
+const ACCESS_TOKEN_COOKIE: &str = "access_token";
+const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+
+/// Async client for the Atlas REST API.
+/// Atlas REST API 异步客户端。
+///
+/// `base_url` includes the API prefix, e.g. `https://atlas.example.com/api`.
+/// Clones share the HTTP connection pool, cookies and tokens.
+/// `base_url` 包含 API 前缀；克隆体共享连接池、Cookie 与令牌。
+///
+/// A request rejected with 401 triggers one `/auth/refresh`, shared by all
+/// requests failing at the same time, and is then replayed once. If the
+/// refresh token is rejected too, the call fails with `SessionExpired`.
+/// 请求返回 401 时会触发一次 `/auth/refresh`（同时失败的请求共享这一次刷新），
+/// 然后重放一次；若刷新令牌也被拒绝，则返回 `SessionExpired`。
+#[derive(Clone)]
+pub struct AtlasClient {
+    http: reqwest::Client,
+    base_url: String,
+    mode: AuthMode,
+    tokens: Arc<RwLock<Option<TokenResponse>>>,
+    session: Arc<SessionRefresh>,
+    auto_refresh: bool,
+}
+
+impl AtlasClient {
+    /// Only cookie mode gets a cookie store: the server reads the
+    /// `access_token` cookie before the `Authorization` header, so a stored
+    /// cookie would shadow the bearer tokens.
+    /// 仅 Cookie 模式启用 Cookie 存储：服务端先读取 `access_token` Cookie
+    /// 再读取 `Authorization` 头，保存的 Cookie 会覆盖 Bearer 令牌。
+    pub fn new(base_url: impl Into<String>, mode: AuthMode) -> Result<Self, ClientError> {
+        let http = reqwest::Client::builder().cookie_store(mode == AuthMode::Cookie).build()?;
+        Ok(AtlasClient::with_http_client(base_url, mode, http))
+    }
+
+    /// Uses a preconfigured `reqwest::Client`. Cookie mode needs its cookie
+    /// store enabled; bearer mode must not have one (see `new`).
+    /// 使用预先配置的 `reqwest::Client`。Cookie 模式需开启其 Cookie 存储；
+    /// Bearer 模式不得开启（见 `new`）。
+    pub fn with_http_client(base_url: impl Into<String>, mode: AuthMode, http: reqwest::Client) -> Self {
+        AtlasClient {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            mode,
+            tokens: Arc::new(RwLock::new(None)),
+            session: Arc::new(SessionRefresh::default()),
+            auto_refresh: true,
+        }
+    }
+
+    /// Restores a saved session, e.g. tokens kept in the device keychain.
+    /// 恢复已保存的会话，例如保存在设备钥匙串中的令牌。
+    pub fn with_tokens(self, tokens: TokenResponse) -> Self {
+        self.set_tokens(Some(tokens));
+        self
+    }
+
+    /// Turns the refresh-and-replay on 401 on or off (default: on).
+    /// 开启或关闭 401 时的自动刷新与重放（默认开启）。
+    pub fn with_auto_refresh(mut self, enabled: bool) -> Self {
+        self.auto_refresh = enabled;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn mode(&self) -> AuthMode {
+        self.mode
+    }
+
+    /// Tokens from the latest login or refresh, in either mode.
+    /// 最近一次登录或刷新得到的令牌（两种模式均可用）。
+    pub fn tokens(&self) -> Option<TokenResponse> {
+        self.tokens.read().unwrap().clone()
+    }
+
+    pub fn set_tokens(&self, tokens: Option<TokenResponse>) {
+        *self.tokens.write().unwrap() = tokens;
+        self.session.bump();
+    }
+
+    /// `POST /auth/login`. Fails with `InvalidCredentials` on a wrong
+    /// username or password.
+    /// 用户名或密码错误时返回 `InvalidCredentials`。
+    pub async fn login(&self, dto: &LoginDto) -> Result<AuthResponse, ClientError> {
+        let response = self.send_once(self.request(Method::POST, "/auth/login").json(dto)).await?;
+        self.authenticated(response).await
+    }
+
+    /// `POST /auth/signup`. Does not log in.
+    /// 注册账号，不会自动登录。
+    pub async fn signup(&self, dto: &SignupDto) -> Result<IUser, ClientError> {
+        let response = self.send_once(self.request(Method::POST, "/auth/signup").json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `POST /auth/google`.
+    pub async fn google_login(&self, dto: &GoogleLoginDto) -> Result<SocialLoginOutcome, ClientError> {
+        self.social_login("/auth/google", dto).await
+    }
+
+    /// `POST /auth/google/signup`, after `SignupRequired`.
+    pub async fn google_signup(&self, dto: &SocialSignupDto) -> Result<AuthResponse, ClientError> {
+        let response = self.send_once(self.request(Method::POST, "/auth/google/signup").json(dto)).await?;
+        self.authenticated(response).await
+    }
+
+    /// `POST /auth/microsoft`.
+    pub async fn microsoft_login(&self, dto: &MicrosoftLoginDto) -> Result<SocialLoginOutcome, ClientError> {
+        self.social_login("/auth/microsoft", dto).await
+    }
+
+    /// `POST /auth/microsoft/signup`, after `SignupRequired`.
+    pub async fn microsoft_signup(&self, dto: &SocialSignupDto) -> Result<AuthResponse, ClientError> {
+        let response = self.send_once(self.request(Method::POST, "/auth/microsoft/signup").json(dto)).await?;
+        self.authenticated(response).await
+    }
+
+    /// `POST /auth/refresh` with the stored refresh token.
+    /// 使用已保存的刷新令牌调用 `POST /auth/refresh`。
+    pub async fn refresh(&self) -> Result<TokenResponse, ClientError> {
+        let refresh_token = self.tokens().map(|tokens| tokens.refresh_token);
+        self.refresh_with(&RefreshTokenDto { refresh_token }).await
+    }
+
+    /// `POST /auth/refresh` with an explicit refresh token. In cookie mode
+    /// the `refresh_token` cookie is used when `dto.refresh_token` is `None`.
+    /// 使用指定的刷新令牌；Cookie 模式下若为 `None` 则使用 `refresh_token` Cookie。
+    pub async fn refresh_with(&self, dto: &RefreshTokenDto) -> Result<TokenResponse, ClientError> {
+        let response = self.send_once(self.request(Method::POST, "/auth/refresh").json(dto)).await?;
+        let tokens = match self.mode {
+            AuthMode::Bearer => decode::<RefreshTokenResponse>(response).await?.tokens,
+            AuthMode::Cookie => cookie_tokens(&response).ok_or(ClientError::MissingTokens)?,
+        };
+        self.set_tokens(Some(tokens.clone()));
+        Ok(tokens)
+    }
+
+    /// `GET /auth/profile`.
+    pub async fn profile(&self) -> Result<IUser, ClientError> {
+        let response = self.send(self.request(Method::GET, "/auth/profile")).await?;
+        decode(response).await
+    }
+
+    /// `POST /auth/logout`; forgets the stored tokens.
+    /// 退出登录并清除已保存的令牌。
+    pub async fn logout(&self) -> Result<LogoutResponse, ClientError> {
+        let response = self.send(self.request(Method::POST, "/auth/logout")).await?;
+        self.set_tokens(None);
+        decode(response).await
+    }
+
+    /// Builds a request to `path`, adding the auth mode header and, in bearer
+    /// mode, the access token.
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self.http.request(method, format!("{}{path}", self.base_url));
+        match self.mode {
+            AuthMode::Cookie => request,
+            AuthMode::Bearer => {
+                let request = request.header(AUTH_MODE_HEADER, "bearer");
+                match self.tokens() {
+                    Some(tokens) => request.bearer_auth(tokens.access_token),
+                    None => request,
+                }
+            }
+        }
+    }
+
+    /// Sends `request`, turning non-success statuses into `ClientError::Api`.
+    /// On 401 the access token is refreshed and the request replayed once.
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        let request = request.build()?;
+        let refreshable = self.mode == AuthMode::Cookie || self.tokens().is_some();
+        let replay = if self.auto_refresh && refreshable { request.try_clone() } else { None };
+        let generation = self.session.generation();
+        let response = self.http.execute(request).await?;
+        match replay {
+            Some(mut replay) if response.status() == StatusCode::UNAUTHORIZED => {
+                self.refresh_after(generation).await?;
+                if let (AuthMode::Bearer, Some(tokens)) = (self.mode, self.tokens()) {
+                    if let Ok(mut authorization) = HeaderValue::try_from(format!("Bearer {}", tokens.access_token)) {
+                        authorization.set_sensitive(true);
+                        replay.headers_mut().insert(AUTHORIZATION, authorization);
+                    }
+                }
+                checked(self.http.execute(replay).await?).await
+            }
+            _ => checked(response).await,
+        }
+    }
+
+    /// Sends `request` without refreshing on 401, for the auth endpoints.
+    async fn send_once(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        checked(request.send().await?).await
+    }
+
+    /// Refreshes the tokens after a 401 on a request sent at `generation`,
+    /// unless another request already did.
+    async fn refresh_after(&self, generation: u64) -> Result<(), ClientError> {
+        let _guard = self.session.lock().await;
+        if self.session.generation() != generation {
+            // Cleared by a failed refresh (or a logout) in the meantime.
+            return if self.tokens().is_some() { Ok(()) } else { Err(ClientError::SessionExpired) };
+        }
+        match self.refresh().await {
+            Ok(_) => Ok(()),
+            Err(ClientError::Api(err)) if matches!(err.status(), 401 | 403) => {
+                self.set_tokens(None);
+                Err(ClientError::SessionExpired)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn social_login<B: Serialize>(&self, path: &str, dto: &B) -> Result<SocialLoginOutcome, ClientError> {
+        let response = self.send_once(self.request(Method::POST, path).json(dto)).await?;
+        let cookies = cookie_tokens(&response);
+        let body: Value = decode(response).await?;
+        if body.get("requiresInvite").and_then(Value::as_bool) == Some(true) {
+            return Ok(SocialLoginOutcome::SignupRequired(serde_json::from_value::<SocialSignupRequiredResponse>(
+                body,
+            )?));
+        }
+        self.session(body, cookies).map(SocialLoginOutcome::Authenticated)
+    }
+
+    /// Reads the user and tokens of a successful login or signup.
+    async fn authenticated(&self, response: Response) -> Result<AuthResponse, ClientError> {
+        let cookies = cookie_tokens(&response);
+        let body: Value = decode(response).await?;
+        self.session(body, cookies)
+    }
+
+    fn session(&self, body: Value, cookies: Option<TokenResponse>) -> Result<AuthResponse, ClientError> {
+        if let Some(message) = login_failure(&body) {
+            return Err(ClientError::InvalidCredentials(message));
+        }
+        let session = match self.mode {
+            AuthMode::Bearer => {
+                let MobileAuthResponse { user, tokens } = serde_json::from_value(body)?;
+                AuthResponse { user, tokens }
+            }
+            AuthMode::Cookie => AuthResponse {
+                user: serde_json::from_value(body)?,
+                tokens: cookies.ok_or(ClientError::MissingTokens)?,
+            },
+        };
+        self.set_tokens(Some(session.tokens.clone()));
+        Ok(session)
+    }
+}
+
+impl std::fmt::Debug for AtlasClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AtlasClient")
+            .field("base_url", &self.base_url)
+            .field("mode", &self.mode)
+            .field("authenticated", &self.tokens.read().unwrap().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Lets the socket clients refresh their token through the REST API.
+/// 使 socket 客户端通过 REST API 刷新令牌。
+#[cfg(feature = "socket")]
+impl crate::socket::token_provider::TokenProvider for AtlasClient {
+    fn refresh(
+        &self,
+        request: RefreshTokenDto,
+    ) -> crate::socket::transport::BoxFuture<Result<TokenResponse, crate::socket::token_provider::BoxError>> {
+        let client = self.clone();
+        Box::pin(async move { Ok(client.refresh_with(&request).await?) })
+    }
+}
+
+/// Passes success responses through and turns the others into `ClientError::Api`.
+async fn checked(response: Response) -> Result<Response, ClientError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let retry_after = response.headers().get(RETRY_AFTER).and_then(|value| value.to_str().ok()).map(str::to_owned);
+    let body = response.bytes().await?;
+    Err(ApiError::from_response(status.as_u16(), retry_after.as_deref(), &body).into())
+}
+
+pub(crate) async fn decode<T: DeserializeOwned>(response: Response) -> Result<T, ClientError> {
+    let body = response.bytes().await?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+/// Percent-encodes `id` for use as a single path segment.
+pub(crate) fn segment(id: impl AsRef<str>) -> String {
+    let id = id.as_ref();
+    let mut encoded = String::with_capacity(id.len());
+    for byte in id.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Tokens set as `access_token`/`refresh_token` cookies.
+fn cookie_tokens(response: &Response) -> Option<TokenResponse> {
+    let (mut access_token, mut refresh_token) = (None, None);
+    for cookie in response.cookies() {
+        match cookie.name() {
+            ACCESS_TOKEN_COOKIE => access_token = Some(cookie.value().to_owned()),
+            REFRESH_TOKEN_COOKIE => refresh_token = Some(cookie.value().to_owned()),
+            _ => {}
+        }
+    }
+    Some(TokenResponse { access_token: access_token?, refresh_token: refresh_token? })
+}
+
+/// `/auth/login` answers bad credentials with `{ "message": ... }` and a
+/// success status.
+fn login_failure(body: &Value) -> Option<String> {
+    let object = body.as_object()?;
+    if object.contains_key("user") || object.contains_key("id") {
+        return None;
+    }
+    object.get("message").and_then(Value::as_str).map(str::to_owned)
+}
@@ -0,0 +1,15 @@
+/// How the client authenticates with the API.
+/// 客户端与 API 的认证方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AuthMode {
+    /// Web mode: the server sets `access_token`/`refresh_token` cookies,
+    /// which the client stores and sends back.
+    /// 网页模式：服务端设置 `access_token`/`refresh_token` Cookie，客户端保存并回传。
+    #[default]
+    Cookie,
+    /// Mobile mode: requests carry `x-auth-mode: bearer`, tokens come back in
+    /// the response body and are sent as `Authorization: Bearer`.
+    /// 移动端模式：请求携带 `x-auth-mode: bearer`，令牌在响应体中返回，
+    /// 并通过 `Authorization: Bearer` 发送。
+    Bearer,
+}
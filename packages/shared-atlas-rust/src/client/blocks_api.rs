@@ -0,0 +1,50 @@
+use reqwest::Method;
+
+use crate::client::atlas_client::{decode, segment, AtlasClient};
+use crate::client::client_error::ClientError;
+use crate::ids::block_id::BlockId;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::create_block_dto::CreateBlockDto;
+use crate::interfaces::iblock::IBlock;
+use crate::interfaces::message_response::MessageResponse;
+use crate::interfaces::move_block_dto::MoveBlockDto;
+use crate::interfaces::update_block_dto::UpdateBlockDto;
+
+/// Block endpoints (`/notes/:noteId/blocks`, `/blocks`).
+/// 块接口（`/notes/:noteId/blocks`、`/blocks`）。
+impl AtlasClient {
+    /// `GET /notes/:noteId/blocks`.
+    pub async fn list_blocks(&self, note_id: &NoteId) -> Result<Vec<IBlock>, ClientError> {
+        let path = format!("/notes/{}/blocks", segment(note_id));
+        let response = self.send(self.request(Method::GET, &path)).await?;
+        decode(response).await
+    }
+
+    /// `POST /notes/:noteId/blocks`.
+    pub async fn create_block(&self, note_id: &NoteId, dto: &CreateBlockDto) -> Result<IBlock, ClientError> {
+        let path = format!("/notes/{}/blocks", segment(note_id));
+        let response = self.send(self.request(Method::POST, &path).json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `PATCH /blocks/:id`.
+    pub async fn update_block(&self, id: &BlockId, dto: &UpdateBlockDto) -> Result<IBlock, ClientError> {
+        let path = format!("/blocks/{}", segment(id));
+        let response = self.send(self.request(Method::PATCH, &path).json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `DELETE /blocks/:id`.
+    pub async fn delete_block(&self, id: &BlockId) -> Result<MessageResponse, ClientError> {
+        let response = self.send(self.request(Method::DELETE, &format!("/blocks/{}", segment(id)))).await?;
+        decode(response).await
+    }
+
+    /// `POST /blocks/:id/move`; re-parents and/or reorders the block.
+    /// 移动块（更换父块和/或调整顺序）。
+    pub async fn move_block(&self, id: &BlockId, dto: &MoveBlockDto) -> Result<IBlock, ClientError> {
+        let path = format!("/blocks/{}/move", segment(id));
+        let response = self.send(self.request(Method::POST, &path).json(dto)).await?;
+        decode(response).await
+    }
+}
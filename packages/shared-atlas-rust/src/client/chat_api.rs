@@ -0,0 +1,132 @@
+use reqwest::header::ACCEPT;
+use reqwest::Method;
+
+use crate::client::atlas_client::{decode, segment, AtlasClient};
+use crate::client::chat_response_stream::ChatResponseStream;
+use crate::client::client_error::ClientError;
+use crate::client::page_stream::{Page, PageRequest, PageStream, MAX_PAGE_SIZE};
+use crate::dto::get_messages_dto::GetMessagesDto;
+use crate::ids::message_id::MessageId;
+use crate::ids::session_id::SessionId;
+use crate::interfaces::add_message_dto::AddMessageDto;
+use crate::interfaces::chat_message_response::ChatMessageResponse;
+use crate::interfaces::chat_session_response::ChatSessionResponse;
+use crate::interfaces::create_session_dto::CreateSessionDto;
+use crate::interfaces::get_messages_response::GetMessagesResponse;
+use crate::interfaces::success_response::SuccessResponse;
+use crate::interfaces::update_message_dto::UpdateMessageDto;
+use crate::interfaces::update_session_dto::UpdateSessionDto;
+
+/// Server default for `GetMessagesDto.limit`.
+const DEFAULT_MESSAGES_PAGE_SIZE: i32 = 20;
+
+/// Media types accepted for the streamed chat answer.
+const STREAM_ACCEPT: &str = "text/event-stream, application/x-ndjson";
+
+/// Chat endpoints (`/chat/sessions`).
+/// 聊天接口（`/chat/sessions`）。
+impl AtlasClient {
+    /// `POST /chat/sessions`.
+    pub async fn create_session(&self, dto: &CreateSessionDto) -> Result<ChatSessionResponse, ClientError> {
+        let response = self.send(self.request(Method::POST, "/chat/sessions").json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `GET /chat/sessions`.
+    pub async fn list_sessions(&self) -> Result<Vec<ChatSessionResponse>, ClientError> {
+        let response = self.send(self.request(Method::GET, "/chat/sessions")).await?;
+        decode(response).await
+    }
+
+    /// `GET /chat/sessions/:id`.
+    pub async fn get_session(&self, id: &SessionId) -> Result<ChatSessionResponse, ClientError> {
+        let response = self.send(self.request(Method::GET, &format!("/chat/sessions/{}", segment(id)))).await?;
+        decode(response).await
+    }
+
+    /// `PATCH /chat/sessions/:id`.
+    /// 重命名会话。
+    pub async fn rename_session(
+        &self,
+        id: &SessionId,
+        dto: &UpdateSessionDto,
+    ) -> Result<ChatSessionResponse, ClientError> {
+        let path = format!("/chat/sessions/{}", segment(id));
+        let response = self.send(self.request(Method::PATCH, &path).json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `DELETE /chat/sessions/:id`; the session is soft-deleted.
+    /// 删除会话（软删除）。
+    pub async fn delete_session(&self, id: &SessionId) -> Result<SuccessResponse, ClientError> {
+        let path = format!("/chat/sessions/{}", segment(id));
+        let response = self.send(self.request(Method::DELETE, &path)).await?;
+        decode(response).await
+    }
+
+    /// `GET /chat/sessions/:id/messages`, oldest first. An unknown session
+    /// yields an empty page.
+    /// 获取消息（按时间升序）；会话不存在时返回空页。
+    pub async fn list_messages(
+        &self,
+        session_id: &SessionId,
+        query: &GetMessagesDto,
+    ) -> Result<GetMessagesResponse, ClientError> {
+        let path = format!("/chat/sessions/{}/messages", segment(session_id));
+        let response = self.send(self.request(Method::GET, &path).query(query)).await?;
+        decode(response).await
+    }
+
+    /// Pages of `GET /chat/sessions/:id/messages`, newest page first and
+    /// each page oldest first, following `nextCursor` from `query.before`.
+    /// `query.limit` is capped at [`MAX_PAGE_SIZE`].
+    /// 按 `nextCursor` 从 `query.before` 开始逐页获取消息：先返回最新的一页，
+    /// 每页内按时间升序。`query.limit` 上限为 [`MAX_PAGE_SIZE`]。
+    pub fn message_pages(&self, session_id: &SessionId, query: GetMessagesDto) -> PageStream<ChatMessageResponse> {
+        let limit = query.limit.unwrap_or(DEFAULT_MESSAGES_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let query = GetMessagesDto { limit: Some(limit), ..query };
+        PageStream::new(message_page(self.clone(), session_id.clone(), query))
+    }
+
+    /// `POST /chat/sessions/:id/messages`; streams the answer as it is
+    /// generated, see [`ChatResponseStream`].
+    /// 发送消息并以流的形式接收回复，见 [`ChatResponseStream`]。
+    pub async fn add_message(
+        &self,
+        session_id: &SessionId,
+        dto: &AddMessageDto,
+    ) -> Result<ChatResponseStream, ClientError> {
+        let path = format!("/chat/sessions/{}/messages", segment(session_id));
+        let request = self.request(Method::POST, &path).header(ACCEPT, STREAM_ACCEPT).json(dto);
+        Ok(ChatResponseStream::new(self.send(request).await?))
+    }
+
+    /// `PATCH /chat/sessions/:sessionId/messages/:messageId`. Does not
+    /// regenerate the answer.
+    /// 编辑消息，不会重新生成回复。
+    pub async fn update_message(
+        &self,
+        session_id: &SessionId,
+        message_id: &MessageId,
+        dto: &UpdateMessageDto,
+    ) -> Result<ChatMessageResponse, ClientError> {
+        let path = format!("/chat/sessions/{}/messages/{}", segment(session_id), segment(message_id));
+        let response = self.send(self.request(Method::PATCH, &path).json(dto)).await?;
+        decode(response).await
+    }
+}
+
+fn message_page(client: AtlasClient, session_id: SessionId, query: GetMessagesDto) -> PageRequest<ChatMessageResponse> {
+    Box::pin(async move {
+        let page = client.list_messages(&session_id, &query).await?;
+        // `hasMore` without a cursor, or a cursor that does not advance, ends
+        // the listing instead of fetching the same page again.
+        let next = match page.next_cursor {
+            Some(cursor) if page.has_more && query.before.as_ref() != Some(&cursor) => {
+                Some(message_page(client, session_id, GetMessagesDto { before: Some(cursor), ..query }))
+            }
+            _ => None,
+        };
+        Ok(Page { items: page.messages, next })
+    })
+}
@@ -0,0 +1,91 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::{self, BoxStream};
+use futures_util::Stream;
+use reqwest::Response;
+
+use crate::client::client_error::ClientError;
+use crate::error::api_error::ApiError;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::protocol::chat_stream_decoder::ChatStreamDecoder;
+
+/// The streamed answer of `POST /chat/sessions/:id/messages`.
+/// `POST /chat/sessions/:id/messages` 的流式回复。
+///
+/// Yields events as the body arrives, until (and including) `done` or
+/// `error`, or until the body ends. A transport or decoding error, or a
+/// NestJS exception frame (as [`ClientError::Api`]), is yielded once and ends
+/// the stream. Dropping it closes the connection.
+/// 随响应体到达产出事件，直到 `done` 或 `error`（包含该事件）或响应体结束。
+/// 传输、解码错误或 NestJS 异常帧（[`ClientError::Api`]）只产出一次并结束流。
+/// 丢弃即关闭连接。
+pub struct ChatResponseStream {
+    inner: BoxStream<'static, Result<ChatStreamEvent, ClientError>>,
+}
+
+struct State {
+    response: Response,
+    decoder: ChatStreamDecoder,
+    eof: bool,
+    finished: bool,
+}
+
+impl ChatResponseStream {
+    pub(crate) fn new(response: Response) -> Self {
+        let state = State { response, decoder: ChatStreamDecoder::new(), eof: false, finished: false };
+        let inner = stream::unfold(state, |mut state| async move {
+            if state.finished {
+                return None;
+            }
+            let item = state.next().await;
+            state.finished = match &item {
+                Some(Ok(event)) => event.is_terminal(),
+                _ => true,
+            };
+            item.map(|item| (item, state))
+        });
+        ChatResponseStream { inner: Box::pin(inner) }
+    }
+}
+
+impl State {
+    async fn next(&mut self) -> Option<Result<ChatStreamEvent, ClientError>> {
+        loop {
+            match self.decoder.next_event() {
+                Ok(Some(ChatStreamEvent::Unknown(frame))) => {
+                    return Some(match ApiError::from_stream_frame(&frame) {
+                        Some(err) => Err(err.into()),
+                        None => Ok(ChatStreamEvent::Unknown(frame)),
+                    });
+                }
+                Ok(Some(event)) => return Some(Ok(event)),
+                Err(err) => return Some(Err(err.into())),
+                Ok(None) if self.eof => return None,
+                Ok(None) => {}
+            }
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => self.decoder.feed(&chunk),
+                Ok(None) => {
+                    self.eof = true;
+                    self.decoder.finish();
+                }
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+}
+
+impl Stream for ChatResponseStream {
+    type Item = Result<ChatStreamEvent, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl std::fmt::Debug for ChatResponseStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChatResponseStream").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::error::api_error::ApiError;
+use crate::protocol::protocol_error::ProtocolError;
+
+/// Failure of a REST call.
+/// REST 调用失败。
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request could not be sent or its response not read.
+    Http(reqwest::Error),
+    /// The server answered with a non-success status, or the response
+    /// stream carried an error frame.
+    Api(ApiError),
+    /// `/auth/login` rejected the username or password. The server reports
+    /// this with a success status and a `{ message }` body.
+    InvalidCredentials(String),
+    /// The response carried no access/refresh tokens.
+    MissingTokens,
+    /// The access token expired and the refresh token was rejected; the user
+    /// has to log in again.
+    SessionExpired,
+    /// The response body did not match the expected type.
+    Decode(serde_json::Error),
+    /// A streamed response body was malformed.
+    Protocol(ProtocolError),
+    /// The runtime of the blocking client could not be started.
+    Runtime(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Http(err) => write!(f, "http error: {err}"),
+            ClientError::Api(err) => err.fmt(f),
+            ClientError::InvalidCredentials(message) => write!(f, "login failed: {message}"),
+            ClientError::MissingTokens => write!(f, "response carried no tokens"),
+            ClientError::SessionExpired => write!(f, "session expired, log in again"),
+            ClientError::Decode(err) => write!(f, "invalid response body: {err}"),
+            ClientError::Protocol(err) => write!(f, "invalid response stream: {err}"),
+            ClientError::Runtime(err) => write!(f, "failed to start runtime: {err}"),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Http(err) => Some(err),
+            ClientError::Api(err) => Some(err),
+            ClientError::Decode(err) => Some(err),
+            ClientError::Protocol(err) => Some(err),
+            ClientError::Runtime(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(err: reqwest::Error) -> Self {
+        ClientError::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for ClientError {
+    fn from(err: serde_json::Error) -> Self {
+        ClientError::Decode(err)
+    }
+}
+
+impl From<ProtocolError> for ClientError {
+    fn from(err: ProtocolError) -> Self {
+        ClientError::Protocol(err)
+    }
+}
+
+impl From<ApiError> for ClientError {
+    fn from(err: ApiError) -> Self {
+        ClientError::Api(err)
+    }
+}
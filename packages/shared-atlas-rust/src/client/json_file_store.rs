@@ -0,0 +1,38 @@
+use std::io;
+use std::path::PathBuf;
+
+use crate::client::signed_url_store::SignedUrlStore;
+use crate::interfaces::isigned_url_response::ISignedUrlResponse;
+
+/// Stores signed URLs as a JSON array in one file.
+/// 以 JSON 数组形式将签名 URL 存储在单个文件中。
+#[derive(Debug, Clone)]
+pub struct JsonFileStore {
+    path: PathBuf,
+}
+
+impl JsonFileStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        JsonFileStore { path: path.into() }
+    }
+}
+
+impl SignedUrlStore for JsonFileStore {
+    /// A missing file loads as empty.
+    fn load(&self) -> io::Result<Vec<ISignedUrlResponse>> {
+        match std::fs::read(&self.path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes a temporary file and renames it, so a crash never leaves a
+    /// truncated file behind.
+    fn save(&self, urls: &[ISignedUrlResponse]) -> io::Result<()> {
+        let json = serde_json::to_vec(urls).map_err(io::Error::other)?;
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(tmp, &self.path)
+    }
+}
@@ -0,0 +1,43 @@
+//! Client-side MIME type detection for uploads.
+//! 上传前在客户端检测 MIME 类型。
+
+/// Bytes of the file head inspected by [`detect_mime_type`].
+/// [`detect_mime_type`] 检查的文件头字节数。
+pub const SNIFF_LEN: usize = 512;
+
+/// MIME type sent when none could be detected.
+pub const OCTET_STREAM: &str = "application/octet-stream";
+
+/// Detects the MIME type from the file's leading bytes, falling back to its
+/// extension. Office documents are ZIP or OLE containers, so for those the
+/// extension decides the exact type.
+/// 根据文件头字节检测 MIME 类型，无法识别时根据扩展名判断。Office 文档是
+/// ZIP 或 OLE 容器，此时以扩展名确定具体类型。
+pub fn detect_mime_type(file_name: &str, head: &[u8]) -> Option<&'static str> {
+    let by_extension = mime_guess::from_path(file_name).first_raw();
+    let sniffed = match head {
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => "image/png",
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => "image/gif",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "image/webp",
+        [b'%', b'P', b'D', b'F', b'-', ..] => "application/pdf",
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => "video/webm",
+        [b'O', b'g', b'g', b'S', ..] => "video/ogg",
+        [_, _, _, _, b'f', b't', b'y', b'p', b'q', b't', b' ', b' ', ..] => "video/quicktime",
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => "video/mp4",
+        [b'R', b'a', b'r', b'!', 0x1A, 0x07, ..] => "application/x-rar-compressed",
+        [b'P', b'K', 0x03, 0x04, ..] => {
+            return Some(by_extension.filter(|mime| mime.starts_with("application/vnd.")).unwrap_or("application/zip"));
+        }
+        [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1, ..] => return by_extension,
+        _ if is_svg(head) => "image/svg+xml",
+        _ => return by_extension,
+    };
+    Some(sniffed)
+}
+
+fn is_svg(head: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(head);
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    (text.starts_with("<?xml") || text.starts_with("<svg")) && text.contains("<svg")
+}
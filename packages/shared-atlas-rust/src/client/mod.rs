@@ -0,0 +1,31 @@
+//! Async REST client for the Atlas HTTP API (feature `client`).
+//! Atlas HTTP API 的异步 REST 客户端（`client` 特性）。
+//!
+//! Supports both the cookie-based web mode and the mobile token-body mode
+//! (`x-auth-mode: bearer`), see [`auth_mode::AuthMode`].
+//! 同时支持基于 Cookie 的网页模式与在响应体中返回令牌的移动端模式
+//! （`x-auth-mode: bearer`），见 [`auth_mode::AuthMode`]。
+
+pub mod atlas_client;
+pub mod auth_mode;
+pub mod blocks_api;
+pub mod cancel_token;
+pub mod chat_api;
+pub mod chat_response_stream;
+pub mod client_error;
+pub mod json_file_store;
+pub mod mime_sniff;
+pub mod notes_api;
+pub mod page_stream;
+pub mod search_api;
+pub(crate) mod session_refresh;
+pub mod signed_url_cache;
+pub mod signed_url_store;
+pub mod social_login_outcome;
+pub mod storage_api;
+pub mod upload_api;
+pub mod upload_error;
+pub mod upload_file;
+pub mod upload_kind;
+pub mod upload_options;
+pub mod upload_progress;
@@ -0,0 +1,88 @@
+use futures_util::future;
+use reqwest::Method;
+
+use crate::client::atlas_client::{decode, segment, AtlasClient};
+use crate::client::client_error::ClientError;
+use crate::client::page_stream::{Page, PageRequest, PageStream, MAX_PAGE_SIZE};
+use crate::dto::list_notes_query::ListNotesQuery;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::create_note_dto::CreateNoteDto;
+use crate::interfaces::inote::INote;
+use crate::interfaces::message_response::MessageResponse;
+use crate::interfaces::note_list_response::NoteListResponse;
+use crate::interfaces::note_with_blocks_response::NoteWithBlocksResponse;
+use crate::interfaces::update_note_dto::UpdateNoteDto;
+
+/// Server default for `ListNotesQuery.limit`.
+const DEFAULT_NOTES_PAGE_SIZE: i32 = 50;
+
+/// Notes endpoints (`/notes`).
+/// 笔记接口（`/notes`）。
+impl AtlasClient {
+    /// `POST /notes`.
+    pub async fn create_note(&self, dto: &CreateNoteDto) -> Result<INote, ClientError> {
+        let response = self.send(self.request(Method::POST, "/notes").json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `GET /notes`; root notes unless `query.parent_id` is set.
+    /// 列出笔记；未设置 `query.parent_id` 时返回根笔记。
+    pub async fn list_notes(&self, query: &ListNotesQuery) -> Result<NoteListResponse, ClientError> {
+        let response = self.send(self.request(Method::GET, "/notes").query(query)).await?;
+        decode(response).await
+    }
+
+    /// Pages of `GET /notes`, advancing `offset` until `total` is reached.
+    /// `query.limit` is capped at [`MAX_PAGE_SIZE`].
+    /// 逐页获取笔记，递增 `offset` 直到达到 `total`。`query.limit` 上限为 [`MAX_PAGE_SIZE`]。
+    pub fn note_pages(&self, query: ListNotesQuery) -> PageStream<INote> {
+        let limit = query.limit.unwrap_or(DEFAULT_NOTES_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        PageStream::new(note_page(self.clone(), ListNotesQuery { limit: Some(limit), ..query }))
+    }
+
+    /// `GET /notes/:id`, without its blocks.
+    /// 获取笔记（不含块）。
+    pub async fn get_note(&self, id: &NoteId) -> Result<INote, ClientError> {
+        let response = self.send(self.request(Method::GET, &format!("/notes/{}", segment(id)))).await?;
+        decode(response).await
+    }
+
+    /// `GET /notes/:id` and `GET /notes/:id/blocks`, fetched concurrently.
+    /// 并发获取笔记及其全部块。
+    pub async fn get_note_with_blocks(&self, id: &NoteId) -> Result<NoteWithBlocksResponse, ClientError> {
+        let (note, blocks) = future::try_join(self.get_note(id), self.list_blocks(id)).await?;
+        Ok(NoteWithBlocksResponse { blocks, note })
+    }
+
+    /// `PATCH /notes/:id`.
+    pub async fn update_note(&self, id: &NoteId, dto: &UpdateNoteDto) -> Result<INote, ClientError> {
+        let path = format!("/notes/{}", segment(id));
+        let response = self.send(self.request(Method::PATCH, &path).json(dto)).await?;
+        decode(response).await
+    }
+
+    /// `DELETE /notes/:id`; the note is soft-deleted.
+    /// 删除笔记（软删除）。
+    pub async fn delete_note(&self, id: &NoteId) -> Result<MessageResponse, ClientError> {
+        let response = self.send(self.request(Method::DELETE, &format!("/notes/{}", segment(id)))).await?;
+        decode(response).await
+    }
+
+    /// `POST /notes/:id/duplicate`; returns the copy.
+    /// 复制笔记并返回副本。
+    pub async fn duplicate_note(&self, id: &NoteId) -> Result<INote, ClientError> {
+        let path = format!("/notes/{}/duplicate", segment(id));
+        let response = self.send(self.request(Method::POST, &path)).await?;
+        decode(response).await
+    }
+}
+
+fn note_page(client: AtlasClient, query: ListNotesQuery) -> PageRequest<INote> {
+    Box::pin(async move {
+        let page = client.list_notes(&query).await?;
+        let offset = query.offset.unwrap_or(0).saturating_add(page.notes.len() as i32);
+        let next = (!page.notes.is_empty() && (offset as u64) < page.total)
+            .then(|| note_page(client, ListNotesQuery { offset: Some(offset), ..query }));
+        Ok(Page { items: page.notes, next })
+    })
+}
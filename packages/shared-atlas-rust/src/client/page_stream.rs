@@ -0,0 +1,94 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::future::BoxFuture;
+use futures_util::stream::{self, BoxStream};
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use tokio::task::JoinHandle;
+
+use crate::client::client_error::ClientError;
+
+/// Largest page the server returns (`GetMessagesDto.limit` max).
+/// 服务端单页返回的最大数量（`GetMessagesDto.limit` 的上限）。
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+/// One fetched page and the request for the following one.
+pub(crate) struct Page<T> {
+    pub(crate) items: Vec<T>,
+    /// `None` on the last page.
+    pub(crate) next: Option<PageRequest<T>>,
+}
+
+pub(crate) type PageRequest<T> = BoxFuture<'static, Result<Page<T>, ClientError>>;
+
+/// Lazily fetched pages of a paginated endpoint.
+/// 按需获取的分页结果流。
+///
+/// Nothing is requested until the stream is polled. Once a page is yielded
+/// the next one is fetched in the background, so at most one page is
+/// requested ahead. The stream ends after the last page or the first error;
+/// dropping it cancels the prefetch. Requires a tokio runtime.
+/// 首次轮询前不会发出请求。产出一页后在后台预取下一页，最多提前一页。
+/// 最后一页或首个错误后流结束；丢弃流会取消预取。需要 tokio 运行时。
+pub struct PageStream<T> {
+    queued: Option<PageRequest<T>>,
+    running: Option<JoinHandle<Result<Page<T>, ClientError>>>,
+}
+
+impl<T: Send + 'static> PageStream<T> {
+    pub(crate) fn new(first: PageRequest<T>) -> Self {
+        PageStream { queued: Some(first), running: None }
+    }
+
+    /// Flattens the pages into their items.
+    /// 将分页展开为逐条结果。
+    pub fn items(self) -> BoxStream<'static, Result<T, ClientError>> {
+        self.map_ok(|items| stream::iter(items.into_iter().map(Ok))).try_flatten().boxed()
+    }
+}
+
+impl<T: Send + 'static> Stream for PageStream<T> {
+    type Item = Result<Vec<T>, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.running.is_none() {
+            match self.queued.take() {
+                Some(request) => self.running = Some(tokio::spawn(request)),
+                None => return Poll::Ready(None),
+            }
+        }
+        let running = self.running.as_mut().expect("page request is running");
+        let result = match Pin::new(running).poll(cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => return Poll::Pending,
+        };
+        self.running = None;
+        match result {
+            Ok(Ok(Page { items, next })) => {
+                if items.is_empty() {
+                    return Poll::Ready(None);
+                }
+                self.running = next.map(tokio::spawn);
+                Poll::Ready(Some(Ok(items)))
+            }
+            Ok(Err(err)) => Poll::Ready(Some(Err(err))),
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Poll::Ready(None),
+        }
+    }
+}
+
+impl<T> Drop for PageStream<T> {
+    fn drop(&mut self) {
+        if let Some(running) = &self.running {
+            running.abort();
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for PageStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PageStream").field("fetching", &self.running.is_some()).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,17 @@
+use reqwest::Method;
+
+use crate::client::atlas_client::{decode, AtlasClient};
+use crate::client::client_error::ClientError;
+use crate::dto::search_query::SearchQuery;
+use crate::interfaces::search_result_dto::SearchResultDto;
+
+/// Search endpoint (`/search`).
+/// 搜索接口（`/search`）。
+impl AtlasClient {
+    /// `GET /search?q=`; notes whose title and blocks whose content match.
+    /// 搜索标题匹配的笔记与内容匹配的块。
+    pub async fn search(&self, query: &SearchQuery) -> Result<SearchResultDto, ClientError> {
+        let response = self.send(self.request(Method::GET, "/search").query(query)).await?;
+        decode(response).await
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use futures_util::lock::{Mutex, MutexGuard};
+
+/// Single-flight state for refreshing an expired access token.
+/// 访问令牌过期后单飞刷新的状态。
+///
+/// Every token change bumps `generation`. A request remembers the generation
+/// it was sent with; after a 401 it takes the lock and refreshes only if no
+/// one changed the tokens in the meantime, otherwise it just replays.
+/// 每次令牌变化都会递增 `generation`。请求记录发送时的代数；收到 401 后获取锁，
+/// 仅当期间无人更新令牌时才刷新，否则直接重放。
+#[derive(Debug, Default)]
+pub(crate) struct SessionRefresh {
+    lock: Mutex<()>,
+    generation: AtomicU64,
+}
+
+impl SessionRefresh {
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn bump(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+
+    pub(crate) async fn lock(&self) -> MutexGuard<'_, ()> {
+        self.lock.lock().await
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::lock::Mutex as AsyncMutex;
+use tokio::task::JoinHandle;
+
+use crate::client::atlas_client::AtlasClient;
+use crate::client::client_error::ClientError;
+use crate::client::signed_url_store::SignedUrlStore;
+use crate::dto::get_signed_url_query_dto::GetSignedUrlQueryDto;
+use crate::interfaces::isigned_url_response::ISignedUrlResponse;
+use crate::time::timestamp::Timestamp;
+
+/// Default time before `expiresAt` at which a URL is renewed.
+/// 默认在 `expiresAt` 之前多久续期 URL。
+pub const DEFAULT_RENEW_BEFORE: Duration = Duration::from_secs(5 * 60);
+
+/// Signed URLs keyed by storage `path`, renewed before they expire.
+/// 以存储 `path` 为键的签名 URL 缓存，在过期前续期。
+///
+/// A lookup returns the cached URL unless it is missing or within the renewal
+/// window (`renew_before`, at most half the URL's lifetime), in which case it
+/// calls `GET /storage/url`; concurrent lookups of the same path share one
+/// request. URLs read since their last fetch are renewed in the background
+/// ahead of `expiresAt`, so readers rarely wait. Clones share the cache.
+/// 查询时返回缓存的 URL；缺失或进入续期窗口（`renew_before`，最多为 URL 有效期
+/// 的一半）时调用 `GET /storage/url`，同一路径的并发查询共享一次请求。上次获取后
+/// 被读取过的 URL 会在 `expiresAt` 之前于后台续期。克隆体共享缓存。
+#[derive(Clone)]
+pub struct SignedUrlCache {
+    client: AtlasClient,
+    renew_before: Duration,
+    expiration_seconds: Option<i32>,
+    store: Option<Arc<dyn SignedUrlStore>>,
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+#[derive(Default)]
+struct Entry {
+    url: Option<ISignedUrlResponse>,
+    /// Held while fetching, so concurrent lookups wait for one request.
+    fetch: Arc<AsyncMutex<()>>,
+    /// Read since the last fetch; only such entries are renewed.
+    accessed: bool,
+    renewal: Option<JoinHandle<()>>,
+}
+
+impl SignedUrlCache {
+    pub fn new(client: AtlasClient) -> Self {
+        SignedUrlCache {
+            client,
+            renew_before: DEFAULT_RENEW_BEFORE,
+            expiration_seconds: None,
+            store: None,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_renew_before(mut self, renew_before: Duration) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    /// Lifetime requested for new URLs (server: 60–86400, default 3600).
+    /// 新 URL 请求的有效期（服务端范围 60–86400 秒，默认 3600）。
+    pub fn with_expiration_seconds(mut self, seconds: i32) -> Self {
+        self.expiration_seconds = Some(seconds);
+        self
+    }
+
+    /// Loads the unexpired URLs from `store` and saves every change to it.
+    /// 从 `store` 加载未过期的 URL，并在每次变化时保存。
+    pub fn with_store(mut self, store: impl SignedUrlStore) -> io::Result<Self> {
+        let mut entries = self.entries.lock().unwrap();
+        for url in store.load()? {
+            if !url.expires_at.is_past() {
+                entries.insert(url.path.clone(), Entry { url: Some(url), ..Entry::default() });
+            }
+        }
+        drop(entries);
+        self.store = Some(Arc::new(store));
+        Ok(self)
+    }
+
+    /// The signed URL for `path`, fetched if missing or about to expire.
+    /// 获取 `path` 的签名 URL；缺失或即将过期时重新获取。
+    pub async fn get(&self, path: &str) -> Result<ISignedUrlResponse, ClientError> {
+        let fetch = {
+            let mut entries = self.entries.lock().unwrap();
+            let entry = entries.entry(path.to_owned()).or_default();
+            entry.accessed = true;
+            if let Some(url) = entry.url.clone().filter(|url| self.is_fresh(url)) {
+                if entry.renewal.is_none() {
+                    entry.renewal = Some(self.schedule_renewal(&url));
+                }
+                return Ok(url);
+            }
+            entry.fetch.clone()
+        };
+        let _guard = fetch.lock().await;
+        if let Some(url) = self.cached(path) {
+            return Ok(url);
+        }
+        self.fetch(path).await
+    }
+
+    /// The cached URL for `path` if it is not about to expire; never fetches.
+    /// 返回未临近过期的缓存 URL，不会发起请求。
+    pub fn cached(&self, path: &str) -> Option<ISignedUrlResponse> {
+        let entries = self.entries.lock().unwrap();
+        entries.get(path)?.url.clone().filter(|url| self.is_fresh(url))
+    }
+
+    /// Forgets the URL for `path`, e.g. after the file was deleted.
+    /// 移除 `path` 的 URL，例如文件被删除后。
+    pub fn invalidate(&self, path: &str) {
+        let removed = self.entries.lock().unwrap().remove(path);
+        if let Some(renewal) = removed.and_then(|entry| entry.renewal) {
+            renewal.abort();
+        }
+        self.persist();
+    }
+
+    /// Forgets every URL and stops all background renewals.
+    /// 清空所有 URL 并停止全部后台续期。
+    pub fn clear(&self) {
+        let removed: Vec<Entry> = self.entries.lock().unwrap().drain().map(|(_, entry)| entry).collect();
+        for renewal in removed.into_iter().filter_map(|entry| entry.renewal) {
+            renewal.abort();
+        }
+        self.persist();
+    }
+
+    async fn fetch(&self, path: &str) -> Result<ISignedUrlResponse, ClientError> {
+        let query = GetSignedUrlQueryDto { path: path.to_owned(), expiration_seconds: self.expiration_seconds };
+        let url = self.client.signed_url(&query).await?;
+        {
+            let mut entries = self.entries.lock().unwrap();
+            let entry = entries.entry(path.to_owned()).or_default();
+            if let Some(renewal) = entry.renewal.replace(self.schedule_renewal(&url)) {
+                renewal.abort();
+            }
+            entry.url = Some(url.clone());
+            entry.accessed = false;
+        }
+        self.persist();
+        Ok(url)
+    }
+
+    /// Renews `url` at its renewal time if it was read in the meantime.
+    fn schedule_renewal(&self, url: &ISignedUrlResponse) -> JoinHandle<()> {
+        let cache = self.clone();
+        let path = url.path.clone();
+        let delay = self.renew_at(url).remaining();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let fetch = {
+                let mut entries = cache.entries.lock().unwrap();
+                let Some(entry) = entries.get_mut(&path) else { return };
+                // Detach first: `fetch` aborts the entry's current renewal,
+                // which is this task.
+                entry.renewal = None;
+                if !entry.accessed {
+                    return;
+                }
+                entry.fetch.clone()
+            };
+            let _guard = fetch.lock().await;
+            // A failed renewal is retried by the next lookup.
+            let _ = cache.fetch(&path).await;
+        })
+    }
+
+    /// Time after which `url` is renewed.
+    fn renew_at(&self, url: &ISignedUrlResponse) -> Timestamp {
+        let lifetime = Duration::try_from_secs_f64(url.expires_in).unwrap_or_default();
+        url.expires_at.checked_sub(self.renew_before.min(lifetime / 2)).unwrap_or(url.expires_at)
+    }
+
+    fn is_fresh(&self, url: &ISignedUrlResponse) -> bool {
+        !self.renew_at(url).is_past()
+    }
+
+    /// Best effort: a failed save only loses the URLs on restart.
+    fn persist(&self) {
+        let Some(store) = &self.store else { return };
+        let urls: Vec<ISignedUrlResponse> =
+            self.entries.lock().unwrap().values().filter_map(|entry| entry.url.clone()).collect();
+        let _ = store.save(&urls);
+    }
+}
+
+impl std::fmt::Debug for SignedUrlCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignedUrlCache")
+            .field("renew_before", &self.renew_before)
+            .field("expiration_seconds", &self.expiration_seconds)
+            .field("persistent", &self.store.is_some())
+            .field("entries", &self.entries.lock().unwrap().len())
+            .finish()
+    }
+}
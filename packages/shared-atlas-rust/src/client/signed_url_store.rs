@@ -0,0 +1,14 @@
+use std::io;
+
+use crate::interfaces::isigned_url_response::ISignedUrlResponse;
+
+/// Persistence for [`SignedUrlCache`](crate::client::signed_url_cache::SignedUrlCache)
+/// across restarts.
+/// `SignedUrlCache` 的持久化存储，用于跨重启保留签名 URL。
+pub trait SignedUrlStore: Send + Sync + 'static {
+    fn load(&self) -> io::Result<Vec<ISignedUrlResponse>>;
+
+    /// Replaces the stored URLs with `urls`.
+    /// 用 `urls` 替换已存储的 URL。
+    fn save(&self, urls: &[ISignedUrlResponse]) -> io::Result<()>;
+}
@@ -0,0 +1,14 @@
+use crate::interfaces::auth_response::AuthResponse;
+use crate::interfaces::social_signup_required_response::SocialSignupRequiredResponse;
+
+/// Result of a Google or Microsoft login.
+/// Google 或 Microsoft 登录的结果。
+#[derive(Debug, Clone)]
+pub enum SocialLoginOutcome {
+    /// The account exists and is now logged in.
+    /// 账号已存在并已登录。
+    Authenticated(AuthResponse),
+    /// No account yet; finish with `google_signup`/`microsoft_signup`.
+    /// 账号尚不存在，需调用 `google_signup`/`microsoft_signup` 完成注册。
+    SignupRequired(SocialSignupRequiredResponse),
+}
@@ -0,0 +1,19 @@
+use reqwest::Method;
+
+use crate::client::atlas_client::{decode, AtlasClient};
+use crate::client::client_error::ClientError;
+use crate::dto::get_signed_url_query_dto::GetSignedUrlQueryDto;
+use crate::interfaces::isigned_url_response::ISignedUrlResponse;
+
+/// Storage endpoints (`/storage`).
+/// 存储接口（`/storage`）。
+impl AtlasClient {
+    /// `GET /storage/url`; a fresh signed URL for a stored file.
+    /// See [`SignedUrlCache`](crate::client::signed_url_cache::SignedUrlCache)
+    /// to reuse URLs until they are about to expire.
+    /// 获取已存储文件的新签名 URL；可使用 `SignedUrlCache` 在过期前复用。
+    pub async fn signed_url(&self, query: &GetSignedUrlQueryDto) -> Result<ISignedUrlResponse, ClientError> {
+        let response = self.send(self.request(Method::GET, "/storage/url").query(query)).await?;
+        decode(response).await
+    }
+}
@@ -0,0 +1,193 @@
+use std::io;
+use std::pin::pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use futures_util::future::{self, Either};
+use futures_util::stream;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, Method, RequestBuilder};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::client::atlas_client::{decode, segment, AtlasClient};
+use crate::client::mime_sniff::{detect_mime_type, OCTET_STREAM, SNIFF_LEN};
+use crate::client::upload_error::UploadError;
+use crate::client::upload_file::UploadFile;
+use crate::client::upload_kind::UploadKind;
+use crate::client::upload_options::UploadOptions;
+use crate::client::upload_progress::UploadProgress;
+use crate::dto::upload_file_query_dto::UploadFileQueryDto;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::ifile_upload_response::IFileUploadResponse;
+
+/// Size of the chunks read from the file.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Upload endpoints (`/upload`, `/storage/upload`).
+/// 上传接口（`/upload`、`/storage/upload`）。
+///
+/// Files are streamed as `multipart/form-data` field `file`. Type and size
+/// are checked against the [`UploadKind`] limits before and while sending.
+/// Uploads are not replayed after a token refresh.
+/// 文件以 `multipart/form-data` 的 `file` 字段流式发送，发送前与发送中按
+/// [`UploadKind`] 限制校验类型与大小。令牌刷新后不会重放上传。
+impl AtlasClient {
+    /// `POST /upload/image/:noteId`.
+    pub async fn upload_image(
+        &self,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.upload_note_file(UploadKind::Image, note_id, file, options).await
+    }
+
+    /// `POST /upload/video/:noteId`.
+    pub async fn upload_video(
+        &self,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.upload_note_file(UploadKind::Video, note_id, file, options).await
+    }
+
+    /// `POST /upload/file/:noteId`.
+    pub async fn upload_attachment(
+        &self,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.upload_note_file(UploadKind::File, note_id, file, options).await
+    }
+
+    /// `POST /upload/:kind/:noteId`.
+    pub async fn upload_note_file(
+        &self,
+        kind: UploadKind,
+        note_id: &NoteId,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        let path = format!("/upload/{kind}/{}", segment(note_id));
+        self.upload(self.request(Method::POST, &path), Some(kind), file, options).await
+    }
+
+    /// `POST /storage/upload?module=&folder=`; any file type.
+    /// 通用上传，不限文件类型。
+    pub async fn upload_to_storage(
+        &self,
+        query: &UploadFileQueryDto,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        self.upload(self.request(Method::POST, "/storage/upload").query(query), None, file, options).await
+    }
+
+    async fn upload(
+        &self,
+        request: RequestBuilder,
+        kind: Option<UploadKind>,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        let Some(cancel) = options.cancel.clone() else {
+            return self.send_file(request, kind, file, options).await;
+        };
+        let cancelled = pin!(cancel.cancelled());
+        match future::select(pin!(self.send_file(request, kind, file, options)), cancelled).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(UploadError::Cancelled),
+        }
+    }
+
+    async fn send_file(
+        &self,
+        request: RequestBuilder,
+        kind: Option<UploadKind>,
+        file: UploadFile,
+        options: UploadOptions,
+    ) -> Result<IFileUploadResponse, UploadError> {
+        let UploadFile { file_name, mut reader, mime_type, len } = file;
+        let head = read_head(&mut reader).await?;
+        let mime_type =
+            mime_type.unwrap_or_else(|| detect_mime_type(&file_name, &head).unwrap_or(OCTET_STREAM).to_owned());
+        let limit = kind.map(UploadKind::max_size);
+        if let Some(kind) = kind {
+            if !kind.accepts(&mime_type) {
+                let accepted = kind.accepted_mime_types().iter().map(|mime| (*mime).to_owned()).collect();
+                return Err(UploadError::UnsupportedType { mime_type, accepted });
+            }
+        }
+        if let (Some(len), Some(limit)) = (len, limit) {
+            if len > limit {
+                return Err(UploadError::TooLarge { max_bytes: Some(limit) });
+            }
+        }
+
+        let sent = Arc::new(AtomicU64::new(0));
+        let body = body(head, reader, len, limit, sent.clone(), options.clone());
+        let part = match len {
+            Some(len) => Part::stream_with_length(body, len),
+            None => Part::stream(body),
+        };
+        let form = Form::new().part("file", part.file_name(file_name).mime_str(&mime_type)?);
+        match self.send(request.multipart(form)).await {
+            Ok(response) => Ok(decode(response).await?),
+            Err(_) if limit.is_some_and(|limit| sent.load(Ordering::Relaxed) > limit) => {
+                Err(UploadError::TooLarge { max_bytes: limit })
+            }
+            Err(err) => Err(UploadError::from_client(err, &mime_type)),
+        }
+    }
+}
+
+/// Reads up to [`SNIFF_LEN`] bytes for MIME detection.
+async fn read_head(reader: &mut (dyn AsyncRead + Send + Unpin)) -> io::Result<Vec<u8>> {
+    let mut head = Vec::with_capacity(SNIFF_LEN);
+    while head.len() < SNIFF_LEN {
+        let read = reader.take((SNIFF_LEN - head.len()) as u64).read_buf(&mut head).await?;
+        if read == 0 {
+            break;
+        }
+    }
+    Ok(head)
+}
+
+/// The request body: `head`, then the rest of `reader`, reporting progress and
+/// stopping once more than `limit` bytes were read.
+fn body(
+    head: Vec<u8>,
+    reader: Box<dyn AsyncRead + Send + Unpin>,
+    total: Option<u64>,
+    limit: Option<u64>,
+    sent: Arc<AtomicU64>,
+    options: UploadOptions,
+) -> Body {
+    let chunks = stream::try_unfold((Some(head), reader), move |(head, mut reader)| {
+        let (sent, options) = (sent.clone(), options.clone());
+        async move {
+            let chunk = match head {
+                Some(head) if !head.is_empty() => head,
+                _ => {
+                    let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+                    (&mut reader).take(CHUNK_SIZE as u64).read_to_end(&mut chunk).await?;
+                    if chunk.is_empty() {
+                        return Ok(None);
+                    }
+                    chunk
+                }
+            };
+            let sent = sent.fetch_add(chunk.len() as u64, Ordering::Relaxed) + chunk.len() as u64;
+            if limit.is_some_and(|limit| sent > limit) {
+                return Err(io::Error::other("file exceeds the upload size limit"));
+            }
+            if let Some(on_progress) = &options.on_progress {
+                on_progress(UploadProgress { sent, total });
+            }
+            Ok::<_, io::Error>(Some((chunk, (None, reader))))
+        }
+    });
+    Body::wrap_stream(chunks)
+}
@@ -0,0 +1,100 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::client::client_error::ClientError;
+
+const MB: u64 = 1024 * 1024;
+
+/// Failure of an upload.
+/// 上传失败。
+#[derive(Debug)]
+pub enum UploadError {
+    /// The file type is not accepted for this kind of upload.
+    UnsupportedType { mime_type: String, accepted: Vec<String> },
+    /// The file exceeds the size limit, when known.
+    TooLarge { max_bytes: Option<u64> },
+    /// The server received no file part.
+    NoFile,
+    /// The upload was cancelled through its `CancelToken`.
+    Cancelled,
+    /// Reading the file failed.
+    Io(io::Error),
+    /// Any other failure of the request.
+    Client(ClientError),
+}
+
+impl UploadError {
+    /// Decodes the server's rejections, e.g. `File too large. Maximum size:
+    /// 10MB` or `Invalid file type. Accepted types: image/png, ...`.
+    pub(crate) fn from_client(err: ClientError, mime_type: &str) -> Self {
+        let ClientError::Api(api) = &err else {
+            return UploadError::Client(err);
+        };
+        let message = api.message();
+        if api.status() == 413 {
+            return UploadError::TooLarge { max_bytes: None };
+        }
+        if api.status() != 400 {
+            return UploadError::Client(err);
+        }
+        if let Some(accepted) = message.strip_prefix("Invalid file type. Accepted types: ") {
+            return UploadError::UnsupportedType {
+                mime_type: mime_type.to_owned(),
+                accepted: accepted.split(", ").map(str::to_owned).collect(),
+            };
+        }
+        if let Some(max) = message.strip_prefix("File too large. Maximum size: ") {
+            let max_bytes = max.strip_suffix("MB").and_then(|mb| mb.parse::<u64>().ok()).map(|mb| mb * MB);
+            return UploadError::TooLarge { max_bytes };
+        }
+        if message == "No file uploaded" {
+            return UploadError::NoFile;
+        }
+        UploadError::Client(err)
+    }
+}
+
+impl fmt::Display for UploadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UploadError::UnsupportedType { mime_type, accepted } => {
+                write!(f, "file type {mime_type} is not accepted (accepted: {})", accepted.join(", "))
+            }
+            UploadError::TooLarge { max_bytes: Some(max) } => write!(f, "file exceeds the {max} byte limit"),
+            UploadError::TooLarge { max_bytes: None } => write!(f, "file is too large"),
+            UploadError::NoFile => write!(f, "no file was uploaded"),
+            UploadError::Cancelled => write!(f, "upload cancelled"),
+            UploadError::Io(err) => write!(f, "reading the file failed: {err}"),
+            UploadError::Client(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for UploadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            UploadError::Io(err) => Some(err),
+            UploadError::Client(err) => err.source(),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for UploadError {
+    fn from(err: io::Error) -> Self {
+        UploadError::Io(err)
+    }
+}
+
+impl From<ClientError> for UploadError {
+    fn from(err: ClientError) -> Self {
+        UploadError::Client(err)
+    }
+}
+
+impl From<reqwest::Error> for UploadError {
+    fn from(err: reqwest::Error) -> Self {
+        UploadError::Client(ClientError::Http(err))
+    }
+}
@@ -0,0 +1,65 @@
+use std::io;
+use std::path::Path;
+
+use tokio::io::AsyncRead;
+
+/// A file to upload, read as it is sent.
+/// 待上传的文件，发送时按需读取。
+pub struct UploadFile {
+    pub(crate) file_name: String,
+    pub(crate) reader: Box<dyn AsyncRead + Send + Unpin>,
+    pub(crate) mime_type: Option<String>,
+    pub(crate) len: Option<u64>,
+}
+
+impl UploadFile {
+    /// Uploads the contents of `reader` as `file_name`. The MIME type is
+    /// detected from the first bytes and the name unless set explicitly.
+    /// 以 `file_name` 上传 `reader` 的内容。未显式设置时根据文件头与文件名检测 MIME 类型。
+    pub fn new(file_name: impl Into<String>, reader: impl AsyncRead + Send + Unpin + 'static) -> Self {
+        UploadFile { file_name: file_name.into(), reader: Box::new(reader), mime_type: None, len: None }
+    }
+
+    pub fn from_bytes(file_name: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        let bytes = bytes.into();
+        let len = bytes.len() as u64;
+        UploadFile::new(file_name, io::Cursor::new(bytes)).with_len(len)
+    }
+
+    /// Opens the file at `path`, named after its last component.
+    /// 打开 `path` 处的文件，以其文件名上传。
+    pub async fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = tokio::fs::File::open(path).await?;
+        let len = file.metadata().await?.len();
+        let file_name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        Ok(UploadFile::new(file_name, file).with_len(len))
+    }
+
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Declares the size, enabling `Content-Length`, size checks before
+    /// sending and progress fractions.
+    /// 声明文件大小，以便设置 `Content-Length`、发送前校验大小并计算进度比例。
+    pub fn with_len(mut self, len: u64) -> Self {
+        self.len = Some(len);
+        self
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+}
+
+impl std::fmt::Debug for UploadFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadFile")
+            .field("file_name", &self.file_name)
+            .field("mime_type", &self.mime_type)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,62 @@
+/// Kind of note attachment, selecting `POST /upload/{image,video,file}/:noteId`.
+/// 笔记附件类型，对应 `POST /upload/{image,video,file}/:noteId`。
+///
+/// Accepted MIME types and size limits mirror the server's `UploadController`.
+/// 接受的 MIME 类型与大小限制与服务端 `UploadController` 保持一致。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UploadKind {
+    Image,
+    Video,
+    File,
+}
+
+impl UploadKind {
+    /// Path segment of the upload endpoint.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UploadKind::Image => "image",
+            UploadKind::Video => "video",
+            UploadKind::File => "file",
+        }
+    }
+
+    pub fn accepted_mime_types(self) -> &'static [&'static str] {
+        match self {
+            UploadKind::Image => &["image/jpeg", "image/png", "image/gif", "image/webp", "image/svg+xml"],
+            UploadKind::Video => &["video/mp4", "video/webm", "video/ogg", "video/quicktime"],
+            UploadKind::File => &[
+                "application/pdf",
+                "application/msword",
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+                "application/vnd.ms-excel",
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                "application/vnd.ms-powerpoint",
+                "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+                "text/plain",
+                "application/zip",
+                "application/x-rar-compressed",
+            ],
+        }
+    }
+
+    /// Largest accepted file, in bytes.
+    /// 允许的最大文件大小（字节）。
+    pub fn max_size(self) -> u64 {
+        const MB: u64 = 1024 * 1024;
+        match self {
+            UploadKind::Image => 10 * MB,
+            UploadKind::Video => 100 * MB,
+            UploadKind::File => 50 * MB,
+        }
+    }
+
+    pub fn accepts(self, mime_type: &str) -> bool {
+        self.accepted_mime_types().contains(&mime_type)
+    }
+}
+
+impl std::fmt::Display for UploadKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::client::cancel_token::CancelToken;
+use crate::client::upload_progress::UploadProgress;
+
+/// Progress callback and cancellation for an upload.
+/// 上传的进度回调与取消设置。
+#[derive(Clone, Default)]
+pub struct UploadOptions {
+    pub(crate) on_progress: Option<Arc<dyn Fn(UploadProgress) + Send + Sync>>,
+    pub(crate) cancel: Option<CancelToken>,
+}
+
+impl UploadOptions {
+    pub fn new() -> Self {
+        UploadOptions::default()
+    }
+
+    /// Called after each chunk of the file is handed to the connection.
+    /// 每个文件分块交给连接后调用。
+    pub fn with_progress(mut self, on_progress: impl Fn(UploadProgress) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Aborts the upload with `UploadError::Cancelled` once `cancel` fires.
+    /// `cancel` 触发后中止上传并返回 `UploadError::Cancelled`。
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+}
+
+impl std::fmt::Debug for UploadOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadOptions")
+            .field("on_progress", &self.on_progress.is_some())
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
@@ -0,0 +1,22 @@
+/// Progress of an upload, reported as the body is sent.
+/// 上传进度，随请求体发送而报告。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Bytes handed to the connection so far.
+    /// 已交给连接发送的字节数。
+    pub sent: u64,
+    /// File size, when known.
+    /// 文件大小（已知时）。
+    pub total: Option<u64>,
+}
+
+impl UploadProgress {
+    /// `sent / total` in `0.0..=1.0`, when the size is known.
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some((self.sent as f64 / total as f64).min(1.0)),
+            None => None,
+        }
+    }
+}
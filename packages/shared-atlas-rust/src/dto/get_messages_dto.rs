@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::message_id::MessageId;
 
 /// DTO for fetching chat messages with pagination.
 /// 获取聊天消息的分页 DTO。
@@ -14,10 +15,10 @@ pub struct GetMessagesDto {
     pub limit: Option<i32>,
 /// Cursor for pagination. Fetch messages OLDER than this message ID.
 /// 分页游标。获取早于此消息 ID 的消息。
-    pub before: Option<String>,
+    pub before: Option<MessageId>,
 /// Optional leaf message ID to fetch a specific conversation path.
 /// If provided, returns the lineage from root to this leaf.
 /// 可选的叶子消息 ID，用于获取特定的对话路径。
 /// 如果提供，则返回从根到此叶子的谱系。
-    pub leaf_message_id: Option<String>,
+    pub leaf_message_id: Option<MessageId>,
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
+
+/// Query parameters for listing notes.
+/// 列出笔记的查询参数。
+/// 
+/// API: `GET /api/notes`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListNotesQuery {
+/// Parent note ID; only its direct children are listed.
+/// Omitted: root notes only.
+/// 父笔记ID，仅列出其直接子笔记。省略时仅列出根笔记。
+    pub parent_id: Option<NoteId>,
+/// Page size. Default: 50
+/// 每页数量。默认：50
+    pub limit: Option<i32>,
+/// Number of notes to skip. Default: 0
+/// 跳过的笔记数量。默认：0
+    pub offset: Option<i32>,
+}
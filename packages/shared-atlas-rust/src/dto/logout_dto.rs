@@ -1,7 +1,8 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::user_id::UserId;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LogoutDto {
-    pub user_id: Option<String>,
+    pub user_id: Option<UserId>,
 }
\ No newline at end of file
@@ -6,6 +6,8 @@ pub mod social_signup_dto;
 pub mod refresh_token_dto;
 pub mod logout_dto;
 pub mod get_messages_dto;
+pub mod list_notes_query;
+pub mod search_query;
 pub mod upload_file_query_dto;
 pub mod get_signed_url_query_dto;
 pub mod storage_module;
\ No newline at end of file
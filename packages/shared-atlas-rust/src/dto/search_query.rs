@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+
+/// Query parameters for searching notes and blocks.
+/// 搜索笔记与块的查询参数。
+/// 
+/// API: `GET /api/search`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchQuery {
+/// Text to look for, case-insensitively, in note titles and block content.
+/// An empty query returns no results.
+/// 在笔记标题与块内容中查找的文本（不区分大小写）。空查询不返回结果。
+    #[serde(default)]
+    pub q: String,
+}
+
+impl SearchQuery {
+    pub fn new(q: impl Into<String>) -> Self {
+        SearchQuery { q: q.into() }
+    }
+}
@@ -5,10 +5,14 @@ use serde::{Serialize, Deserialize};
 /// 
 /// Defines valid module prefixes for file organization.
 /// 定义文件组织的有效模块前缀。
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StorageModule {
     #[serde(rename = "notes")]
     Notes,
     #[serde(rename = "chats")]
     Chat,
+/// A value this crate does not know yet, kept as sent.
+/// 本库尚未识别的值，按原样保留。
+    #[serde(untagged)]
+    Unknown(String),
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+// @generated by packages/shared-atlas/script/generate-rust.ts
 use serde::{Serialize, Deserialize};
 use crate::dto::storage_module::StorageModule;
 
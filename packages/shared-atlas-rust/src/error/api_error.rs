@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::error::api_error_body::ApiErrorBody;
+
+/// A failed Atlas REST call, decoded from the NestJS exception body.
+/// 从 NestJS 异常响应体解码的 Atlas REST 调用错误。
+///
+/// Built from an error response with [`ApiError::from_response`], or from an
+/// error frame inside a streamed body with [`ApiError::from_stream_frame`].
+/// 可由错误响应（[`ApiError::from_response`]）或流式响应中的错误帧
+/// （[`ApiError::from_stream_frame`]）构建。
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// 400 from the `ValidationPipe`; `fields` are the rejected properties.
+    /// `ValidationPipe` 返回的 400；`fields` 为校验失败的属性。
+    Validation { fields: Vec<String>, body: Box<ApiErrorBody> },
+    /// 401: missing or expired credentials.
+    Unauthorized(Box<ApiErrorBody>),
+    /// 403: authenticated but not allowed.
+    Forbidden(Box<ApiErrorBody>),
+    /// 404.
+    NotFound(Box<ApiErrorBody>),
+    /// 429, with the `Retry-After` delay when sent.
+    /// 429，若服务端发送了 `Retry-After` 则附带等待时间。
+    RateLimited { retry_after: Option<Duration>, body: Box<ApiErrorBody> },
+    /// 5xx.
+    Server(Box<ApiErrorBody>),
+    /// Any other status, e.g. a 400 raised by a controller.
+    /// 其他状态码，例如控制器抛出的 400。
+    Other(Box<ApiErrorBody>),
+}
+
+impl ApiError {
+    /// Decodes an error response; `retry_after` is the `Retry-After` header.
+    /// 解码错误响应；`retry_after` 为 `Retry-After` 响应头。
+    pub fn from_response(status: u16, retry_after: Option<&str>, body: &[u8]) -> Self {
+        ApiError::classify(ApiErrorBody::parse(status, body), retry_after)
+    }
+
+    /// Decodes a NestJS exception object sent as a frame of a streamed body,
+    /// e.g. by `POST /chat/sessions/:id/messages` after the headers were sent.
+    /// `None` if `frame` is not one.
+    /// 解码流式响应中的 NestJS 异常对象帧（例如响应头发送后出错），不是则返回 `None`。
+    pub fn from_stream_frame(frame: &Value) -> Option<Self> {
+        let status = u16::try_from(frame.get("statusCode")?.as_u64()?).ok()?;
+        frame.get("message")?;
+        Some(ApiError::classify(ApiErrorBody::from_value(status, frame, frame.to_string()), None))
+    }
+
+    fn classify(body: ApiErrorBody, retry_after: Option<&str>) -> Self {
+        let body = Box::new(body);
+        match body.status {
+            400 if !body.messages.is_empty() => ApiError::Validation { fields: fields(&body.messages), body },
+            401 => ApiError::Unauthorized(body),
+            403 => ApiError::Forbidden(body),
+            404 => ApiError::NotFound(body),
+            429 => ApiError::RateLimited {
+                retry_after: retry_after.and_then(ApiError::parse_retry_after),
+                body,
+            },
+            500..=599 => ApiError::Server(body),
+            _ => ApiError::Other(body),
+        }
+    }
+
+    /// Reads a `Retry-After` value, either delay-seconds or an HTTP-date; a
+    /// date in the past is no delay.
+    /// 解析 `Retry-After` 的值（秒数或 HTTP 日期），已过去的日期视为无需等待。
+    pub fn parse_retry_after(value: &str) -> Option<Duration> {
+        let value = value.trim();
+        if let Ok(secs) = value.parse() {
+            return Some(Duration::from_secs(secs));
+        }
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some((date.with_timezone(&Utc) - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+    }
+
+    pub fn body(&self) -> &ApiErrorBody {
+        match self {
+            ApiError::Validation { body, .. } | ApiError::RateLimited { body, .. } => body,
+            ApiError::Unauthorized(body)
+            | ApiError::Forbidden(body)
+            | ApiError::NotFound(body)
+            | ApiError::Server(body)
+            | ApiError::Other(body) => body,
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.body().status
+    }
+
+    pub fn message(&self) -> &str {
+        &self.body().message
+    }
+
+    /// Whether the same request may succeed later (429 and 5xx).
+    /// 同一请求稍后是否可能成功（429 与 5xx）。
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ApiError::RateLimited { .. } | ApiError::Server(_))
+    }
+}
+
+/// Property names from class-validator messages, e.g. `email` from
+/// `"email must be an email"` or `foo` from `"property foo should not exist"`.
+fn fields(messages: &[String]) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+    for message in messages {
+        let mut words = message.split_whitespace();
+        let field = match words.next() {
+            Some("property") => words.next(),
+            word => word,
+        };
+        if let Some(field) = field.filter(|field| !fields.iter().any(|known| known == field)) {
+            fields.push(field.to_owned());
+        }
+    }
+    fields
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "server returned {}: {}", self.status(), self.message())
+    }
+}
+
+impl Error for ApiError {}
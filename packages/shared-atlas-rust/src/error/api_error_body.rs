@@ -0,0 +1,52 @@
+use serde_json::Value;
+
+/// A NestJS exception body (`{ statusCode, message, error }`) plus the raw
+/// text it was decoded from.
+/// NestJS 异常响应体（`{ statusCode, message, error }`）及其原始文本。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiErrorBody {
+    /// HTTP status (or `statusCode` of a streamed error frame).
+    /// HTTP 状态码（流式错误帧则为其 `statusCode`）。
+    pub status: u16,
+    /// `message`, with validation messages joined by `"; "`.
+    /// `message` 字段；多条校验信息以 `"; "` 连接。
+    pub message: String,
+    /// Each message when `message` was an array (class-validator output).
+    /// `message` 为数组（class-validator 输出）时的各条信息。
+    pub messages: Vec<String>,
+    /// Status phrase in `error`, e.g. `"Bad Request"`.
+    /// `error` 字段中的状态描述，例如 `"Bad Request"`。
+    pub error: Option<String>,
+    /// The body as received; may not be JSON (e.g. a proxy error page).
+    /// 收到的原始响应体，可能不是 JSON（例如代理的错误页面）。
+    pub raw: String,
+}
+
+impl ApiErrorBody {
+    /// Decodes `body`; non-JSON bodies are kept as the message.
+    /// 解码 `body`；非 JSON 的响应体作为 message 保留。
+    pub fn parse(status: u16, body: &[u8]) -> Self {
+        let raw = String::from_utf8_lossy(body).into_owned();
+        match serde_json::from_slice::<Value>(body) {
+            Ok(value) => ApiErrorBody::from_value(status, &value, raw),
+            Err(_) => {
+                let message = if raw.trim().is_empty() { format!("HTTP {status}") } else { raw.clone() };
+                ApiErrorBody { status, message, messages: Vec::new(), error: None, raw }
+            }
+        }
+    }
+
+    pub(crate) fn from_value(status: u16, value: &Value, raw: String) -> Self {
+        let messages = match value.get("message") {
+            Some(Value::Array(messages)) => messages.iter().filter_map(Value::as_str).map(str::to_owned).collect(),
+            _ => Vec::new(),
+        };
+        let error = value.get("error").and_then(Value::as_str).map(str::to_owned);
+        let message = match value.get("message") {
+            Some(Value::String(message)) => message.clone(),
+            Some(Value::Array(_)) => messages.join("; "),
+            _ => error.clone().unwrap_or_else(|| format!("HTTP {status}")),
+        };
+        ApiErrorBody { status, message, messages, error, raw }
+    }
+}
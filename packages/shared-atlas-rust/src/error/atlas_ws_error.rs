@@ -0,0 +1,67 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::interfaces::error_category::ErrorCategory;
+use crate::interfaces::web_socket_error_code::{ErrorDisposition, WebSocketErrorCode};
+use crate::interfaces::ws_error_response::WsErrorResponse;
+
+/// A gateway error frame (`WsErrorResponse`) as a Rust error.
+/// 将网关错误帧（`WsErrorResponse`）包装为 Rust 错误。
+#[derive(Debug, Clone)]
+pub struct AtlasWsError {
+    response: WsErrorResponse,
+}
+
+impl AtlasWsError {
+    pub fn new(response: WsErrorResponse) -> Self {
+        AtlasWsError { response }
+    }
+
+    pub fn code(&self) -> WebSocketErrorCode {
+        self.response.code
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        self.response.category
+    }
+
+    pub fn message(&self) -> &str {
+        &self.response.message
+    }
+
+    /// Extra context attached by the server (e.g. validation output, `dbCode`).
+    /// 服务端附带的额外上下文（例如校验结果、`dbCode`）。
+    pub fn details(&self) -> Option<&serde_json::Value> {
+        self.response.details.as_ref()
+    }
+
+    pub fn disposition(&self) -> ErrorDisposition {
+        self.response.code.disposition()
+    }
+
+    pub fn response(&self) -> &WsErrorResponse {
+        &self.response
+    }
+
+    pub fn into_response(self) -> WsErrorResponse {
+        self.response
+    }
+}
+
+impl From<WsErrorResponse> for AtlasWsError {
+    fn from(response: WsErrorResponse) -> Self {
+        AtlasWsError::new(response)
+    }
+}
+
+impl fmt::Display for AtlasWsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WebSocket error {}: {}", self.response.code, self.response.message)?;
+        if let Some(details) = &self.response.details {
+            write!(f, " ({details})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for AtlasWsError {}
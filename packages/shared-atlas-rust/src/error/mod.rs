@@ -0,0 +1,3 @@
+pub mod api_error;
+pub mod api_error_body;
+pub mod atlas_ws_error;
@@ -0,0 +1,30 @@
+use crate::interfaces::chat_send_payload::ChatSendPayload;
+use crate::interfaces::cursor_update_payload::CursorUpdatePayload;
+use crate::interfaces::note_join_payload::NoteJoinPayload;
+use crate::interfaces::note_leave_payload::NoteLeavePayload;
+use crate::interfaces::ws_message_ack_payload::WsMessageAckPayload;
+use crate::interfaces::ws_token_refreshed_payload::WsTokenRefreshedPayload;
+use crate::interfaces::yjs_update_payload::YjsUpdatePayload;
+
+socket_events! {
+    /// Events a client emits to the Atlas gateways.
+    /// 客户端发送给 Atlas 网关的事件。
+    ///
+    /// Wire format / 传输格式: `["note:join", { "noteId": "..." }]`
+    pub enum AtlasClientEvent {
+        /// `note:join` (collaboration)
+        NoteJoin(NoteJoinPayload) => "note:join",
+        /// `note:leave` (collaboration)
+        NoteLeave(NoteLeavePayload) => "note:leave",
+        /// `yjs:update` (collaboration)
+        YjsUpdate(YjsUpdatePayload) => "yjs:update",
+        /// `cursor:update` (collaboration)
+        CursorUpdate(CursorUpdatePayload) => "cursor:update",
+        /// `chat:send` (chat)
+        ChatSend(ChatSendPayload) => "chat:send",
+        /// `message:ack` (chat, collaboration)
+        MessageAck(WsMessageAckPayload) => "message:ack",
+        /// `auth:token-refreshed` (chat, collaboration)
+        TokenRefreshed(WsTokenRefreshedPayload) => "auth:token-refreshed",
+    }
+}
@@ -0,0 +1,57 @@
+use crate::interfaces::activity_event_payload::ActivityEventPayload;
+use crate::interfaces::chat_error_payload::ChatErrorPayload;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::interfaces::collaboration_error_payload::CollaborationErrorPayload;
+use crate::interfaces::collaboration_limit_payload::CollaborationLimitPayload;
+use crate::interfaces::cursor_update_payload::CursorUpdatePayload;
+use crate::interfaces::icollaborator::ICollaborator;
+use crate::interfaces::presence_join_payload::PresenceJoinPayload;
+use crate::interfaces::presence_leave_payload::PresenceLeavePayload;
+use crate::interfaces::ws_auth_error_payload::WsAuthErrorPayload;
+use crate::interfaces::ws_error_response::WsErrorResponse;
+use crate::interfaces::ws_token_expiring_payload::WsTokenExpiringPayload;
+use crate::interfaces::ws_token_renewed_payload::WsTokenRenewedPayload;
+use crate::interfaces::yjs_sync_payload::YjsSyncPayload;
+use crate::interfaces::yjs_update_payload::YjsUpdatePayload;
+
+socket_events! {
+    /// Events the Atlas gateways emit to clients.
+    /// Atlas 网关发送给客户端的事件。
+    ///
+    /// Wire format / 传输格式: `["chat:stream", { "type": "answer_chunk", ... }]`
+    pub enum AtlasServerEvent {
+        /// `yjs:sync` (collaboration)
+        YjsSync(YjsSyncPayload) => "yjs:sync",
+        /// `yjs:update` (collaboration)
+        YjsUpdate(YjsUpdatePayload) => "yjs:update",
+        /// `cursor:update` (collaboration)
+        CursorUpdate(CursorUpdatePayload) => "cursor:update",
+        /// `presence:join` (collaboration)
+        PresenceJoin(PresenceJoinPayload) => "presence:join",
+        /// `presence:leave` (collaboration)
+        PresenceLeave(PresenceLeavePayload) => "presence:leave",
+        /// `presence:list` (collaboration)
+        PresenceList(Vec<ICollaborator>) => "presence:list",
+        /// `collaboration:limit` (collaboration)
+        CollaborationLimit(CollaborationLimitPayload) => "collaboration:limit",
+        /// `collaboration:error` (collaboration)
+        CollaborationError(CollaborationErrorPayload) => "collaboration:error",
+        /// `chat:stream` (chat)
+        ChatStream(ChatStreamEvent) => "chat:stream",
+        /// `chat:error` (chat)
+        ChatError(ChatErrorPayload) => "chat:error",
+        /// `activity:status` (activity)
+        ActivityStatus(ActivityEventPayload) => "activity:status",
+        /// `auth:token-expiring` (chat, collaboration)
+        TokenExpiring(WsTokenExpiringPayload) => "auth:token-expiring",
+        /// `auth:token-renewed` (chat, collaboration)
+        TokenRenewed(WsTokenRenewedPayload) => "auth:token-renewed",
+        /// `auth:error` (chat, collaboration)
+        AuthError(WsAuthErrorPayload) => "auth:error",
+        /// `packet:ping`, an application-level ping that expects an ack.
+        /// 应用层 ping，需要 ack 回复。
+        PacketPing(serde_json::Value) => "packet:ping",
+        /// `error` (all gateways)
+        Error(WsErrorResponse) => "error",
+    }
+}
@@ -0,0 +1,118 @@
+//! Typed socket.io events, keyed by their wire name.
+//! 按事件名区分的强类型 socket.io 事件。
+//!
+//! Each event (de)serializes as the `[eventName, payload]` array that
+//! socket.io puts on the wire.
+//! 每个事件序列化为 socket.io 传输使用的 `[eventName, payload]` 数组。
+
+/// Declares an event enum with one payload per wire name, plus an `Unknown`
+/// fallback, and implements the `[eventName, ...args]` JSON mapping.
+macro_rules! socket_events {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$vmeta:meta])*
+                $variant:ident($payload:ty) => $event:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant($payload),
+            )*
+            /// An event this crate does not model, with its raw arguments.
+            /// 本库未建模的事件及其原始参数。
+            Unknown {
+                name: String,
+                args: Vec<serde_json::Value>,
+            },
+        }
+
+        impl $name {
+            /// Wire name of the event, e.g. `note:join`.
+            /// 事件在传输中的名称，例如 `note:join`。
+            pub fn name(&self) -> &str {
+                match self {
+                    $( $name::$variant(_) => $event, )*
+                    $name::Unknown { name, .. } => name,
+                }
+            }
+
+            /// Event arguments (everything after the name) as JSON values.
+            /// 以 JSON 值表示的事件参数（事件名之后的部分）。
+            pub fn to_args(&self) -> Result<Vec<serde_json::Value>, serde_json::Error> {
+                match self {
+                    $( $name::$variant(payload) => Ok(vec![serde_json::to_value(payload)?]), )*
+                    $name::Unknown { args, .. } => Ok(args.clone()),
+                }
+            }
+
+            /// Builds an event from its wire name and arguments.
+            /// 根据事件名和参数构建事件。
+            pub fn from_args(
+                name: &str,
+                args: Vec<serde_json::Value>,
+            ) -> Result<Self, serde_json::Error> {
+                match name {
+                    $(
+                        $event => {
+                            let payload = args.into_iter().next().unwrap_or(serde_json::Value::Null);
+                            Ok($name::$variant(serde_json::from_value(payload)?))
+                        }
+                    )*
+                    _ => Ok($name::Unknown { name: name.to_owned(), args }),
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeSeq;
+                match self {
+                    $(
+                        $name::$variant(payload) => {
+                            let mut seq = serializer.serialize_seq(Some(2))?;
+                            seq.serialize_element($event)?;
+                            seq.serialize_element(payload)?;
+                            seq.end()
+                        }
+                    )*
+                    $name::Unknown { name, args } => {
+                        let mut seq = serializer.serialize_seq(Some(args.len() + 1))?;
+                        seq.serialize_element(name)?;
+                        for arg in args {
+                            seq.serialize_element(arg)?;
+                        }
+                        seq.end()
+                    }
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+                let mut items = <Vec<serde_json::Value>>::deserialize(deserializer)?;
+                if items.is_empty() {
+                    return Err(D::Error::custom("socket.io event array is empty"));
+                }
+                let name = match items.remove(0) {
+                    serde_json::Value::String(name) => name,
+                    other => {
+                        return Err(D::Error::custom(format!(
+                            "socket.io event name must be a string, got {other}"
+                        )))
+                    }
+                };
+                $name::from_args(&name, items).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+pub mod atlas_client_event;
+pub mod atlas_server_event;
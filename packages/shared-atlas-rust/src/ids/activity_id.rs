@@ -0,0 +1,9 @@
+use crate::ids::is_uuid;
+
+id_type!(
+    /// ID of an agent activity in a chat session.
+    /// 聊天会话中智能体活动的 ID。
+    ActivityId,
+    "activity",
+    is_uuid
+);
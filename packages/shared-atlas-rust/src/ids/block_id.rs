@@ -0,0 +1,9 @@
+use crate::ids::is_uuid;
+
+id_type!(
+    /// ID of a block.
+    /// 块的 ID。
+    BlockId,
+    "block",
+    is_uuid
+);
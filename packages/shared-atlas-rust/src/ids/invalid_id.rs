@@ -0,0 +1,20 @@
+use std::error::Error;
+use std::fmt;
+
+/// A string that is not a valid ID of the expected kind.
+/// 不是预期类型有效 ID 的字符串。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidId {
+    /// The expected kind, e.g. `"note"`.
+    /// 预期的 ID 类型，例如 `"note"`。
+    pub kind: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for InvalidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} id {:?}: expected a UUID", self.kind, self.value)
+    }
+}
+
+impl Error for InvalidId {}
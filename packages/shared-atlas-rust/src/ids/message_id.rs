@@ -0,0 +1,26 @@
+use crate::ids::is_uuid;
+
+/// Parent ID of the first message of a chat session.
+/// 聊天会话第一条消息的父 ID。
+pub const ROOT_MESSAGE_ID: &str = "ROOT";
+
+id_type!(
+    /// ID of a chat message, or [`ROOT_MESSAGE_ID`] as the parent of the
+    /// first message.
+    /// 聊天消息的 ID；第一条消息的父 ID 为 [`ROOT_MESSAGE_ID`]。
+    MessageId,
+    "message",
+    |value| value == ROOT_MESSAGE_ID || is_uuid(value)
+);
+
+impl MessageId {
+    /// The parent of the first message of a session.
+    /// 会话第一条消息的父节点。
+    pub fn root() -> Self {
+        MessageId(ROOT_MESSAGE_ID.to_owned())
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0 == ROOT_MESSAGE_ID
+    }
+}
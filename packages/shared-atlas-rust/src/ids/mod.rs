@@ -0,0 +1,112 @@
+//! Typed IDs of the Atlas entities.
+//! Atlas 实体的强类型 ID。
+//!
+//! Each ID is a UUID string under its own type, so a block ID cannot be
+//! passed where a note ID is expected. IDs serialize as plain JSON strings and
+//! are validated when deserialized or parsed.
+//! 每种 ID 都是独立类型的 UUID 字符串，块 ID 无法传给需要笔记 ID 的地方。
+//! ID 序列化为普通 JSON 字符串，反序列化或解析时进行校验。
+
+/// Declares a UUID string newtype: validation on parse and deserialize,
+/// transparent JSON, `Display`, `FromStr` and comparisons with `&str`.
+/// `$accepts` decides which values are valid.
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, $accepts:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Validates `value` as this kind of ID.
+            /// 校验 `value` 是否为该类 ID。
+            pub fn parse(value: impl Into<String>) -> Result<Self, crate::ids::invalid_id::InvalidId> {
+                let value = value.into();
+                let accepts: fn(&str) -> bool = $accepts;
+                if accepts(&value) {
+                    Ok($name(value))
+                } else {
+                    Err(crate::ids::invalid_id::InvalidId { kind: $kind, value })
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::ids::invalid_id::InvalidId;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                $name::parse(value)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                $name::parse(value).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub mod activity_id;
+pub mod block_id;
+pub mod invalid_id;
+pub mod message_id;
+pub mod note_id;
+pub mod session_id;
+pub mod template_id;
+pub mod user_id;
+
+/// Whether `value` is a hyphenated UUID, e.g.
+/// `123e4567-e89b-12d3-a456-426614174000` (any case).
+/// 判断 `value` 是否为带连字符的 UUID（不区分大小写）。
+pub fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.bytes().enumerate().all(|(index, byte)| match index {
+            8 | 13 | 18 | 23 => byte == b'-',
+            _ => byte.is_ascii_hexdigit(),
+        })
+}
@@ -0,0 +1,9 @@
+use crate::ids::is_uuid;
+
+id_type!(
+    /// ID of a note.
+    /// 笔记的 ID。
+    NoteId,
+    "note",
+    is_uuid
+);
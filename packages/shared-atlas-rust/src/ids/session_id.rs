@@ -0,0 +1,9 @@
+use crate::ids::is_uuid;
+
+id_type!(
+    /// ID of a chat session.
+    /// 聊天会话的 ID。
+    SessionId,
+    "session",
+    is_uuid
+);
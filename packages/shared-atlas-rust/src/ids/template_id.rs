@@ -0,0 +1,9 @@
+use crate::ids::is_uuid;
+
+id_type!(
+    /// ID of a note template.
+    /// 笔记模板的 ID。
+    TemplateId,
+    "template",
+    is_uuid
+);
@@ -0,0 +1,9 @@
+use crate::ids::is_uuid;
+
+id_type!(
+    /// ID of a user.
+    /// 用户的 ID。
+    UserId,
+    "user",
+    is_uuid
+);
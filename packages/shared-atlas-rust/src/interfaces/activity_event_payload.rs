@@ -1,15 +1,18 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::activity_id::ActivityId;
+use crate::ids::session_id::SessionId;
 use crate::interfaces::activity_status::ActivityStatus;
+use crate::time::timestamp::Timestamp;
 use serde_json;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActivityEventPayload {
-    pub session_id: String,
-    pub activity_id: String,
+    pub session_id: SessionId,
+    pub activity_id: ActivityId,
     pub r#type: String,
     pub description: String,
     pub status: ActivityStatus,
-    pub timestamp: f64,
+    pub timestamp: Timestamp,
     pub metadata: Option<serde_json::Value>,
 }
\ No newline at end of file
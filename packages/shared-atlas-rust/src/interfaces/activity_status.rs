@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ActivityStatus {
     #[serde(rename = "STARTED")]
     Started,
@@ -10,4 +10,8 @@ pub enum ActivityStatus {
     Completed,
     #[serde(rename = "FAILED")]
     Failed,
+/// A value this crate does not know yet, kept as sent.
+/// 本库尚未识别的值，按原样保留。
+    #[serde(untagged)]
+    Unknown(String),
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::message_id::MessageId;
 use crate::interfaces::chat_role::ChatRole;
 
 /// Payload for adding a new message to a session via REST API.
@@ -23,5 +24,5 @@ pub struct AddMessageDto {
     pub model: Option<String>,
 /// Optional parent ID.
 /// 可选的父消息 ID。
-    pub parent_id: Option<String>,
+    pub parent_id: Option<MessageId>,
 }
\ No newline at end of file
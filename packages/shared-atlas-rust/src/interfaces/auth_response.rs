@@ -1,11 +1,11 @@
 use serde::{Serialize, Deserialize};
 use crate::interfaces::iuser::IUser;
-use serde_json;
+use crate::interfaces::token_response::TokenResponse;
 
 /// Standard Auth Response (Web/Mobile)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthResponse {
     pub user: IUser,
-    pub tokens: serde_json::Value,
+    pub tokens: TokenResponse,
 }
\ No newline at end of file
@@ -0,0 +1,68 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
+use crate::interfaces::block_type::BlockType;
+use crate::interfaces::callout_block_metadata::CalloutBlockMetadata;
+use crate::interfaces::code_block_metadata::CodeBlockMetadata;
+use crate::interfaces::media_block_metadata::MediaBlockMetadata;
+use crate::interfaces::table_block_metadata::TableBlockMetadata;
+use crate::interfaces::todo_block_metadata::TodoBlockMetadata;
+use crate::interfaces::toggle_block_metadata::ToggleBlockMetadata;
+
+/// Typed `metadata` of a block, selected by its [`BlockType`].
+/// 块的强类型 `metadata`，由其 [`BlockType`] 决定。
+///
+/// The JSON has no tag of its own, so it is read with
+/// [`BlockMetadata::from_value`]. It serializes as the bare metadata object.
+/// JSON 本身不带类型标签，需通过 [`BlockMetadata::from_value`] 读取；
+/// 序列化结果为元数据对象本身。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BlockMetadata {
+/// `CODE`.
+    Code(CodeBlockMetadata),
+/// `TODO_ITEM` and `TODO_LIST`.
+    Todo(TodoBlockMetadata),
+/// `IMAGE`, `VIDEO` and `FILE`.
+    Media(MediaBlockMetadata),
+/// `CALLOUT`.
+    Callout(CalloutBlockMetadata),
+/// `TOGGLE`.
+    Toggle(ToggleBlockMetadata),
+/// `TABLE`.
+    Table(TableBlockMetadata),
+/// Any other block type, or metadata that does not have the expected shape,
+/// kept as the raw JSON.
+/// 其他块类型，或结构不符合预期的元数据，保留原始 JSON。
+    Other(serde_json::Value),
+}
+
+impl BlockMetadata {
+    /// Reads the metadata of a block of type `block_type`.
+    /// Unknown keys are kept; metadata of the wrong shape becomes [`BlockMetadata::Other`].
+    /// 读取 `block_type` 类型块的元数据。保留未知键；结构不符时返回 [`BlockMetadata::Other`]。
+    pub fn from_value(block_type: &BlockType, value: serde_json::Value) -> Self {
+        match block_type {
+            BlockType::Code => typed(value, BlockMetadata::Code),
+            BlockType::TodoItem | BlockType::TodoList => typed(value, BlockMetadata::Todo),
+            BlockType::Image | BlockType::Video | BlockType::File => typed(value, BlockMetadata::Media),
+            BlockType::Callout => typed(value, BlockMetadata::Callout),
+            BlockType::Toggle => typed(value, BlockMetadata::Toggle),
+            BlockType::Table => typed(value, BlockMetadata::Table),
+            _ => BlockMetadata::Other(value),
+        }
+    }
+
+    /// The metadata as sent to the server.
+    /// 发送给服务端的元数据 JSON。
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+}
+
+fn typed<T: DeserializeOwned>(value: serde_json::Value, variant: fn(T) -> BlockMetadata) -> BlockMetadata {
+    match T::deserialize(&value) {
+        Ok(metadata) => variant(metadata),
+        Err(_) => BlockMetadata::Other(value),
+    }
+}
@@ -0,0 +1,93 @@
+use std::ops::Range;
+
+use serde::{Serialize, Deserialize};
+use crate::ids::block_id::BlockId;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::block_type::BlockType;
+use crate::interfaces::note_ref::NoteRef;
+use crate::time::timestamp::Timestamp;
+
+/// A block whose content matched a search.
+/// 内容与搜索匹配的块。
+/// 
+/// API: `GET /api/search` (`SearchResultDto.blocks`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockSearchHit {
+/// UUID of the block.
+/// 块的 UUID。
+    pub id: BlockId,
+/// UUID of the note this block belongs to.
+/// 此块所属笔记的 UUID。
+    pub note_id: NoteId,
+/// Type of the block.
+/// 块的类型。
+    pub r#type: BlockType,
+/// Full content of the block.
+/// 块的完整内容。
+    pub content: String,
+/// Timestamp when the block was last updated.
+/// 块最后更新时间。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+/// Note this block belongs to.
+/// 此块所属的笔记。
+    pub note: NoteRef,
+/// Excerpt of the content around the first match, with `...` where it was cut.
+/// 首个匹配处附近的内容摘录，截断处以 `...` 标记。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<String>,
+}
+
+impl BlockSearchHit {
+    /// Byte ranges of the terms of `query` in [`BlockSearchHit::highlight`].
+    /// The server centers the excerpt on the whole query; here the query is
+    /// split on whitespace so each term is marked on its own, matched
+    /// case-insensitively. Overlapping or touching matches are merged and the
+    /// ranges come back in order. Empty without a highlight.
+    /// `query` 各词在 [`BlockSearchHit::highlight`] 中的字节区间。服务端按整个查询
+    /// 截取摘录，此处则在客户端按空白拆分查询，逐词不区分大小写地标记；重叠或相邻的
+    /// 匹配会被合并并按顺序返回。没有摘录时为空。
+    pub fn highlight_ranges(&self, query: &str) -> Vec<Range<usize>> {
+        let Some(highlight) = &self.highlight else { return Vec::new() };
+        let chars: Vec<(usize, char)> = highlight.char_indices().collect();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for term in query.split_whitespace() {
+            let needle: Vec<char> = term.chars().flat_map(char::to_lowercase).collect();
+            let mut start = 0;
+            while start < chars.len() {
+                match match_len(&chars[start..], &needle) {
+                    Some(len) => {
+                        let end = chars.get(start + len).map_or(highlight.len(), |(index, _)| *index);
+                        ranges.push(chars[start].0..end);
+                        start += len;
+                    }
+                    None => start += 1,
+                }
+            }
+        }
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+}
+
+/// Number of chars at the start of `chars` whose lowercase form is `needle`.
+fn match_len(chars: &[(usize, char)], needle: &[char]) -> Option<usize> {
+    let mut rest = needle;
+    for (len, (_, c)) in chars.iter().enumerate() {
+        if rest.is_empty() {
+            return Some(len);
+        }
+        for lower in c.to_lowercase() {
+            rest = rest.strip_prefix(&[lower])?;
+        }
+    }
+    rest.is_empty().then_some(chars.len())
+}
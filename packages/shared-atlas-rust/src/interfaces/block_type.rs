@@ -6,7 +6,7 @@ use serde::{Serialize, Deserialize};
 /// Usage / 使用场景:
 /// - `IBlock.type`
 /// - `CreateBlockDto.type`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BlockType {
     #[serde(rename = "TEXT")]
     Text,
@@ -42,4 +42,8 @@ pub enum BlockType {
     Callout,
     #[serde(rename = "TOGGLE")]
     Toggle,
+/// A value this crate does not know yet, kept as sent.
+/// 本库尚未识别的值，按原样保留。
+    #[serde(untagged)]
+    Unknown(String),
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+use serde_json;
+
+/// Metadata of a `CALLOUT` block.
+/// `CALLOUT` 块的元数据。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalloutBlockMetadata {
+/// Icon shown next to the text, usually an emoji.
+/// 文本旁显示的图标，通常为 emoji。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+/// Background color.
+/// 背景颜色。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+/// Keys this crate does not know, kept as sent.
+/// 本库未识别的键，按原样保留。
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
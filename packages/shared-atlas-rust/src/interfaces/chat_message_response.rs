@@ -1,5 +1,8 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::message_id::MessageId;
+use crate::ids::session_id::SessionId;
 use crate::interfaces::chat_role::ChatRole;
+use crate::time::timestamp::Timestamp;
 
 /// API response structure for a chat message.
 /// Match of IChatMessage for now.
@@ -9,10 +12,10 @@ use crate::interfaces::chat_role::ChatRole;
 pub struct ChatMessageResponse {
 /// Unique UUID of the message.
 /// 消息的唯一 UUID。
-    pub id: String,
+    pub id: MessageId,
 /// UUID of the session this message belongs to.
 /// 此消息所属会话的 UUID。
-    pub session_id: String,
+    pub session_id: SessionId,
 /// The role of the sender (User or Assistant).
 /// 发送者角色（用户或 AI）。
     pub role: ChatRole,
@@ -21,8 +24,8 @@ pub struct ChatMessageResponse {
     pub content: String,
 /// Timestamp when the message was created.
 /// 消息创建时间。
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Timestamp,
 /// ID of the parent message.
 /// 父消息 ID。
-    pub parent_id: String,
+    pub parent_id: MessageId,
 }
\ No newline at end of file
@@ -7,10 +7,18 @@ use serde::{Serialize, Deserialize};
 /// - `IChatMessage.role`
 /// - `ChatSendPayload.role`
 /// - `AddMessageDto.role`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ChatRole {
     #[serde(rename = "user")]
     User,
     #[serde(rename = "assistant")]
     Assistant,
+    #[serde(rename = "system")]
+    System,
+    #[serde(rename = "tool")]
+    Tool,
+/// A value this crate does not know yet, kept as sent.
+/// 本库尚未识别的值，按原样保留。
+    #[serde(untagged)]
+    Unknown(String),
 }
\ No newline at end of file
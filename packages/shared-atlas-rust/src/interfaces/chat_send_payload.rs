@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::message_id::MessageId;
+use crate::ids::session_id::SessionId;
 use crate::interfaces::chat_role::ChatRole;
 
 /// Payload for sending a message via WebSocket.
@@ -10,7 +12,7 @@ use crate::interfaces::chat_role::ChatRole;
 pub struct ChatSendPayload {
 /// Session ID to send the message to.
 /// 目标会话 ID。
-    pub session_id: String,
+    pub session_id: SessionId,
 /// Message content.
 /// 消息内容。
     pub content: String,
@@ -22,5 +24,5 @@ pub struct ChatSendPayload {
     pub model: Option<String>,
 /// Optional parent ID.
 /// 父消息 ID (可选).
-    pub parent_id: Option<String>,
+    pub parent_id: Option<MessageId>,
 }
\ No newline at end of file
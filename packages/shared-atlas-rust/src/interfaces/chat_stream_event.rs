@@ -3,24 +3,58 @@ use serde_json;
 
 /// Stream event from server to client.
 /// 服务端发送给客户端的流式事件。
-/// 
+///
 /// Event: `socket.on('chat:stream', (event: ChatStreamEvent) => { ... })`
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ChatStreamEvent {
-/// Event type:
-/// - `chunk`: A piece of the generated text. (生成的一段文本)
-/// - `done`: Generation completed. (生成完成)
-/// - `error`: An error occurred. (发生错误)
-    pub r#type: serde_json::Value,
-/// Text data for `chunk` events.
-/// 当 type='chunk' 时，包含文本片段。
-    pub data: Option<String>,
-/// Error message for `error` events.
-/// 当 type='error' 时，包含错误信息。
-    pub error: Option<String>,
-/// Optional updated session title.
-/// Sent with 'done' event if the title was updated or requested.
-/// 发送 'done' 事件时可能包含的更新后的会话标题。
-    pub title: Option<String>,
-}
\ No newline at end of file
+///
+/// This is a discriminated union type that matches the AgentEvent structure
+/// to preserve full event information from the agent execution.
+/// 此类型为可辨识联合类型，与 AgentEvent 结构匹配，以保留代理执行的完整事件信息。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatStreamEvent {
+/// Agent reasoning/thinking process
+/// 代理推理/思考过程
+    Thought {
+        content: String,
+    },
+/// Agent is calling a tool
+/// 代理正在调用工具
+    ToolCall {
+        tool: String,
+        args: serde_json::Value,
+    },
+/// Result from tool execution
+/// 工具执行结果
+    ToolResult {
+        tool: String,
+        result: serde_json::Value,
+    },
+/// A chunk of the final answer being generated
+/// 正在生成的最终答案片段
+    AnswerChunk {
+        content: String,
+    },
+/// Generation completed successfully
+/// 生成成功完成
+    Done {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+/// An error occurred during generation
+/// 生成过程中发生错误
+    Error {
+        message: String,
+    },
+/// Any event this crate does not know yet, kept as the raw JSON object.
+/// 本库尚未识别的事件，保留原始 JSON 对象。
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl ChatStreamEvent {
+    /// Whether this event ends the stream (`done` or `error`).
+    /// 此事件是否结束流（`done` 或 `error`）。
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ChatStreamEvent::Done { .. } | ChatStreamEvent::Error { .. })
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Serialize, Deserialize};
 use serde_json;
 
+#[deprecated(note = "use `crate::events::atlas_client_event::AtlasClientEvent`")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientToServerEvents {
@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+use serde_json;
+
+/// Metadata of a `CODE` block.
+/// `CODE` 块的元数据。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeBlockMetadata {
+/// Language used for syntax highlighting, e.g. `"rust"`.
+/// 用于语法高亮的语言，例如 `"rust"`。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+/// Keys this crate does not know, kept as sent.
+/// 本库未识别的键，按原样保留。
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
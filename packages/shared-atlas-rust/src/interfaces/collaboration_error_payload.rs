@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+
+/// Event when a collaboration request is rejected (e.g. no edit permission).
+/// 协作请求被拒绝时的事件（例如没有编辑权限）。
+///
+/// Event: `server.emit('collaboration:error', payload)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollaborationErrorPayload {
+/// Error message / 错误消息
+    pub error: String,
+}
@@ -1,3 +1,4 @@
+// @generated by packages/shared-atlas/script/generate-rust.ts
 use serde::{Serialize, Deserialize};
 
 /// Event when max concurrent editors limit is reached.
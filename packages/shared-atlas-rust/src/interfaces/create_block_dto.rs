@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::block_id::BlockId;
+use crate::interfaces::block_metadata::BlockMetadata;
 use crate::interfaces::block_type::BlockType;
 use serde_json;
 
@@ -20,8 +22,21 @@ pub struct CreateBlockDto {
     pub metadata: Option<serde_json::Value>,
 /// Optional parent block ID.
 /// 可选的父块ID。
-    pub parent_block_id: Option<String>,
+    pub parent_block_id: Option<BlockId>,
 /// Optional position (defaults to end).
 /// 可选的位置（默认为末尾）。
     pub position: Option<f64>,
-}
\ No newline at end of file
+}
+
+impl CreateBlockDto {
+    /// A block appended at the end of the note, without metadata.
+    /// 追加到笔记末尾、不带元数据的块。
+    pub fn new(r#type: BlockType, content: impl Into<String>) -> Self {
+        CreateBlockDto { r#type, content: content.into(), metadata: None, parent_block_id: None, position: None }
+    }
+
+    pub fn with_metadata(mut self, metadata: BlockMetadata) -> Self {
+        self.metadata = Some(metadata.to_value());
+        self
+    }
+}
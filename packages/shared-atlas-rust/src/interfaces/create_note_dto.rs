@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
+use crate::ids::template_id::TemplateId;
 
 /// Payload for creating a new note.
 /// 创建新笔记的请求体。
@@ -12,8 +14,8 @@ pub struct CreateNoteDto {
     pub title: String,
 /// Optional template ID to create from.
 /// 可选的模板ID。
-    pub template_id: Option<String>,
+    pub template_id: Option<TemplateId>,
 /// Optional parent note ID.
 /// 可选的父笔记ID。
-    pub parent_id: Option<String>,
+    pub parent_id: Option<NoteId>,
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
+use crate::ids::user_id::UserId;
 use serde_json;
 
 /// Payload for cursor position update.
@@ -11,11 +13,15 @@ use serde_json;
 pub struct CursorUpdatePayload {
 /// Note ID.
 /// 笔记ID。
-    pub note_id: String,
+    pub note_id: NoteId,
 /// Current cursor position.
 /// 当前光标位置。
     pub position: Option<serde_json::Value>,
 /// Current selection range.
 /// 当前选区范围。
     pub selection: Option<serde_json::Value>,
-}
\ No newline at end of file
+/// Author of the cursor move; set by the server when broadcasting.
+/// 光标所属用户ID，由服务端广播时填充。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+}
@@ -0,0 +1,18 @@
+use serde::{Serialize, Deserialize};
+
+/// Category of a WebSocket error.
+/// WebSocket 错误类别。
+///
+/// Usage / 使用场景:
+/// - `WsErrorResponse.category`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ErrorCategory {
+    #[serde(rename = "AUTH")]
+    Auth,
+    #[serde(rename = "PERMISSION")]
+    Permission,
+    #[serde(rename = "VALIDATION")]
+    Validation,
+    #[serde(rename = "SERVER")]
+    Server,
+}
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::message_id::MessageId;
 use crate::interfaces::chat_message_response::ChatMessageResponse;
 
 /// Response structure for fetching messages.
@@ -13,6 +14,7 @@ pub struct GetMessagesResponse {
 /// 是否还有更多旧消息。
     pub has_more: bool,
 /// Cursor for the next page requests (use as 'before' param).
-/// 下一页请求的游标（作为 'before' 参数使用）。
-    pub next_cursor: String,
+/// `None` when there are no older messages.
+/// 下一页请求的游标（作为 'before' 参数使用）。没有更旧的消息时为 `None`。
+    pub next_cursor: Option<MessageId>,
 }
\ No newline at end of file
@@ -1,5 +1,11 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::block_id::BlockId;
+use crate::ids::note_id::NoteId;
+use crate::ids::user_id::UserId;
+use crate::interfaces::block_metadata::BlockMetadata;
 use crate::interfaces::block_type::BlockType;
+use crate::interfaces::media_block_metadata::MediaBlockMetadata;
+use crate::time::timestamp::Timestamp;
 use serde_json;
 
 /// Represents a content block within a note.
@@ -14,10 +20,10 @@ use serde_json;
 pub struct IBlock {
 /// Unique UUID of the block.
 /// 块的唯一 UUID。
-    pub id: String,
+    pub id: BlockId,
 /// UUID of the note this block belongs to.
 /// 此块所属笔记的 UUID。
-    pub note_id: String,
+    pub note_id: NoteId,
 /// Type of the block (text, heading, image, etc.).
 /// 块的类型（文本、标题、图片等）。
     pub r#type: BlockType,
@@ -30,29 +36,66 @@ pub struct IBlock {
 /// Examples: table structure, code language, file info.
 /// 块类型特定的元数据（JSON对象）。
 /// 示例：表格结构、代码语言、文件信息。
+/// See [`IBlock::typed_metadata`].
     pub metadata: serde_json::Value,
 /// Optional parent block ID for nested structures.
 /// 可选的父块ID，用于嵌套结构。
-    pub parent_block_id: Option<String>,
+    pub parent_block_id: Option<BlockId>,
 /// Position/order within parent (0-indexed).
 /// 在父级中的位置/顺序（从0开始）。
     pub position: f64,
 /// Timestamp when the block was created.
 /// 块创建时间。
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Timestamp,
 /// Timestamp when the block was last updated.
 /// 块最后更新时间。
-    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Timestamp,
 /// UUID of the user who created this block.
 /// 创建此块的用户UUID。
-    pub created_by: String,
+    pub created_by: UserId,
 /// UUID of the user who last edited this block.
 /// 最后编辑此块的用户UUID。
-    pub last_edited_by: String,
+    pub last_edited_by: UserId,
 /// Optional nested children blocks (for tree structure rendering).
 /// 可选的嵌套子块（用于树状结构渲染）。
     pub children: Option<Vec<IBlock>>,
 /// Optional soft delete flag.
 /// 可选的软删除标记。
     pub is_deleted: Option<bool>,
-}
\ No newline at end of file
+}
+
+impl IBlock {
+    /// [`IBlock::metadata`] read according to the block type.
+    /// 按块类型解析的 [`IBlock::metadata`]。
+    pub fn typed_metadata(&self) -> BlockMetadata {
+        BlockMetadata::from_value(&self.r#type, self.metadata.clone())
+    }
+
+    pub fn set_metadata(&mut self, metadata: BlockMetadata) {
+        self.metadata = metadata.to_value();
+    }
+
+    /// Highlighting language of a `CODE` block.
+    /// `CODE` 块的高亮语言。
+    pub fn code_language(&self) -> Option<String> {
+        match self.typed_metadata() {
+            BlockMetadata::Code(code) => code.language,
+            _ => None,
+        }
+    }
+
+    /// Whether this is a checked to-do block.
+    /// 是否为已勾选的待办块。
+    pub fn is_checked(&self) -> bool {
+        matches!(self.typed_metadata(), BlockMetadata::Todo(todo) if todo.checked == Some(true))
+    }
+
+    /// File details of an `IMAGE`, `VIDEO` or `FILE` block.
+    /// `IMAGE`、`VIDEO` 或 `FILE` 块的文件信息。
+    pub fn media(&self) -> Option<MediaBlockMetadata> {
+        match self.typed_metadata() {
+            BlockMetadata::Media(media) => Some(media),
+            _ => None,
+        }
+    }
+}
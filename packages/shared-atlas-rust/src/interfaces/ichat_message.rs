@@ -1,5 +1,8 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::message_id::MessageId;
+use crate::ids::session_id::SessionId;
 use crate::interfaces::chat_role::ChatRole;
+use crate::time::timestamp::Timestamp;
 
 /// Represents a single message within a chat session.
 /// 代表聊天会话中的单条消息。
@@ -11,10 +14,10 @@ use crate::interfaces::chat_role::ChatRole;
 pub struct IChatMessage {
 /// Unique UUID of the message.
 /// 消息的唯一 UUID。
-    pub id: String,
+    pub id: MessageId,
 /// UUID of the session this message belongs to.
 /// 此消息所属会话的 UUID。
-    pub session_id: String,
+    pub session_id: SessionId,
 /// The role of the sender (User or Assistant).
 /// 发送者角色（用户或 AI）。
     pub role: ChatRole,
@@ -23,8 +26,8 @@ pub struct IChatMessage {
     pub content: String,
 /// Timestamp when the message was created.
 /// 消息创建时间。
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Timestamp,
 /// ID of the parent message.
 /// 父消息 ID。
-    pub parent_id: String,
+    pub parent_id: MessageId,
 }
\ No newline at end of file
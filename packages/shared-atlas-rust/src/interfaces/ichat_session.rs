@@ -1,4 +1,7 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::session_id::SessionId;
+use crate::ids::user_id::UserId;
+use crate::time::timestamp::Timestamp;
 
 /// Represents a chat session or conversation thread.
 /// Only metadata is stored here; messages are retrieved separately.
@@ -13,18 +16,18 @@ use serde::{Serialize, Deserialize};
 pub struct IChatSession {
 /// Unique UUID of the session.
 /// 会话的唯一 UUID。
-    pub id: String,
+    pub id: SessionId,
 /// UUID of the user who owns this session.
 /// 拥有此会话的用户的 UUID。
-    pub user_id: String,
+    pub user_id: UserId,
 /// Auto-generated title of the conversation.
 /// Usually summarized from the first message.
 /// 会话标题，通常由第一条消息自动总结生成。
     pub title: String,
 /// Timestamp when the session was created.
 /// 会话创建时间。
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Timestamp,
 /// Timestamp when the last message was added or session updated.
 /// 会话最后更新时间（如有新消息）。
-    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Timestamp,
 }
\ No newline at end of file
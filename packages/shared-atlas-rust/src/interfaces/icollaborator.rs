@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::user_id::UserId;
+use crate::time::timestamp::Timestamp;
 use serde_json;
 
 /// Represents a collaborator in a note editing session.
@@ -11,7 +13,7 @@ use serde_json;
 pub struct ICollaborator {
 /// UUID of the collaborating user.
 /// 协作用户的 UUID。
-    pub user_id: String,
+    pub user_id: UserId,
 /// Username for display.
 /// 用于显示的用户名。
     pub username: String,
@@ -27,5 +29,5 @@ pub struct ICollaborator {
 /// Current text selection range.
 /// 当前文本选区范围。
     pub selection: Option<serde_json::Value>,
-    pub connected_at: chrono::DateTime<chrono::Utc>,
+    pub connected_at: Timestamp,
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::time::timestamp::Timestamp;
 use serde_json;
 
 /// Storage Interfaces
@@ -17,7 +18,7 @@ pub struct IFileUploadResponse {
     pub path: String,
 /// URL expiration timestamp (Unix milliseconds)
 /// URL 过期时间戳（Unix 毫秒）
-    pub expires_at: Option<f64>,
+    pub expires_at: Option<Timestamp>,
 /// File metadata
 /// 文件元数据
     pub metadata: serde_json::Value,
@@ -1,5 +1,7 @@
 use serde::{Serialize, Deserialize};
-use serde_json;
+use crate::ids::note_id::NoteId;
+use crate::ids::user_id::UserId;
+use crate::time::timestamp::Timestamp;
 
 /// Represents a note/page in the system.
 /// 代表系统中的一个笔记/页面。
@@ -13,10 +15,10 @@ use serde_json;
 pub struct INote {
 /// Unique UUID of the note.
 /// 笔记的唯一 UUID。
-    pub id: String,
+    pub id: NoteId,
 /// UUID of the user who owns this note.
 /// 拥有此笔记的用户的 UUID。
-    pub user_id: String,
+    pub user_id: UserId,
 /// Title of the note (max 200 characters).
 /// 笔记标题（最多200字符）。
     pub title: String,
@@ -28,7 +30,7 @@ pub struct INote {
     pub icon: Option<String>,
 /// Optional parent note ID for hierarchical structure.
 /// 可选的父笔记ID，用于构建层级结构。
-    pub parent_id: Option<String>,
+    pub parent_id: Option<NoteId>,
 /// Whether the note has children (computed property).
 /// 笔记是否有子节点（计算属性）。
     pub has_children: Option<bool>,
@@ -43,11 +45,11 @@ pub struct INote {
     pub is_deleted: bool,
 /// Timestamp when the note was created.
 /// 笔记创建时间。
-    pub created_at: serde_json::Value,
+    pub created_at: Timestamp,
 /// Timestamp when the note was last updated.
 /// 笔记最后更新时间。
-    pub updated_at: serde_json::Value,
+    pub updated_at: Timestamp,
 /// UUID of the user who last edited this note.
 /// 最后编辑此笔记的用户UUID。
-    pub last_edited_by: String,
+    pub last_edited_by: UserId,
 }
\ No newline at end of file
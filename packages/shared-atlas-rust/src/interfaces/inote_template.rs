@@ -1,4 +1,7 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::template_id::TemplateId;
+use crate::ids::user_id::UserId;
+use crate::time::timestamp::Timestamp;
 use serde_json;
 
 /// Represents a note template.
@@ -12,7 +15,7 @@ use serde_json;
 pub struct INoteTemplate {
 /// Unique UUID of the template.
 /// 模板的唯一 UUID。
-    pub id: String,
+    pub id: TemplateId,
 /// Template name.
 /// 模板名称。
     pub name: String,
@@ -30,7 +33,7 @@ pub struct INoteTemplate {
     pub is_public: bool,
 /// Optional creator user ID (null for system templates).
 /// 可选的创建者用户ID（系统模板为null）。
-    pub created_by: Option<String>,
+    pub created_by: Option<UserId>,
 /// Template structure (array of block definitions).
 /// 模板结构（块定义数组）。
     pub template_data: serde_json::Value,
@@ -39,8 +42,8 @@ pub struct INoteTemplate {
     pub usage_count: f64,
 /// Timestamp when the template was created.
 /// 模板创建时间。
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Timestamp,
 /// Timestamp when the template was last updated.
 /// 模板最后更新时间。
-    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Timestamp,
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::time::timestamp::Timestamp;
 
 /// Signed URL Response
 /// 签名 URL 响应
@@ -13,7 +14,7 @@ pub struct ISignedUrlResponse {
     pub path: String,
 /// URL expiration timestamp (Unix milliseconds)
 /// URL 过期时间戳（Unix 毫秒）
-    pub expires_at: f64,
+    pub expires_at: Timestamp,
 /// Seconds until expiration
 /// 距离过期的秒数
     pub expires_in: f64,
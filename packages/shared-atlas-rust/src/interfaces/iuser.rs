@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::user_id::UserId;
+use crate::time::timestamp::Timestamp;
 
 /// Represents a User entity in the system for frontend consumption.
 /// Contains public profile information. Sensitive data like passwords are excluded.
@@ -6,7 +8,7 @@ use serde::{Serialize, Deserialize};
 #[serde(rename_all = "camelCase")]
 pub struct IUser {
 /// Unique UUID of the user.
-    pub id: String,
+    pub id: UserId,
 /// Unique username chosen by the user.
     pub username: String,
 /// Email address of the user.
@@ -15,7 +17,7 @@ pub struct IUser {
 /// Can be a GCS signed URL or external URL (e.g., Google profile picture).
     pub avatar: Option<String>,
 /// Timestamp when the user account was registered.
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Timestamp,
 /// Timestamp when the user profile was last updated.
-    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Timestamp,
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use serde::{Serialize, Deserialize};
+use serde_json;
+
+/// Metadata of an `IMAGE`, `VIDEO` or `FILE` block.
+/// The file itself is at the block's `content` URL.
+/// `IMAGE`、`VIDEO` 或 `FILE` 块的元数据。文件本身位于块 `content` 中的 URL。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaBlockMetadata {
+/// Source URL, when it differs from the block content.
+/// 源 URL（与块内容不同时）。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+/// Display width in pixels.
+/// 显示宽度（像素）。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<f64>,
+/// Alternative text.
+/// 替代文本。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+/// Original file name, as returned by the upload endpoints.
+/// 原始文件名，与上传接口返回的一致。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+/// File size in bytes.
+/// 文件大小（字节）。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<f64>,
+/// MIME type of the file.
+/// 文件的 MIME 类型。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+/// Keys this crate does not know, kept as sent.
+/// 本库未识别的键，按原样保留。
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+/// Confirmation returned by endpoints without a resource to return.
+/// 无资源返回的接口所返回的确认消息。
+/// 
+/// APIs: `DELETE /api/notes/:id`, `DELETE /api/blocks/:id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageResponse {
+    pub message: String,
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use crate::interfaces::iuser::IUser;
-use serde_json;
+use crate::interfaces::token_response::TokenResponse;
 
 /// Prefix: Mobile
 /// Used for mobile app authentication response.
@@ -8,5 +8,5 @@ use serde_json;
 #[serde(rename_all = "camelCase")]
 pub struct MobileAuthResponse {
     pub user: IUser,
-    pub tokens: serde_json::Value,
+    pub tokens: TokenResponse,
 }
\ No newline at end of file
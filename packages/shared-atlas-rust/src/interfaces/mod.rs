@@ -2,6 +2,7 @@ pub mod activity_event_payload;
 pub mod activity_status;
 pub mod mobile_auth_response;
 pub mod auth_response;
+pub mod social_signup_required_response;
 pub mod token_response;
 pub mod refresh_token_response;
 pub mod refresh_message_response;
@@ -10,9 +11,12 @@ pub mod ichat_session;
 pub mod ichat_message;
 pub mod create_session_dto;
 pub mod add_message_dto;
+pub mod update_session_dto;
+pub mod update_message_dto;
 pub mod chat_session_response;
 pub mod chat_message_response;
 pub mod get_messages_response;
+pub mod success_response;
 pub mod chat_send_payload;
 pub mod chat_stream_event;
 pub mod chat_error_payload;
@@ -27,7 +31,11 @@ pub mod create_block_dto;
 pub mod update_block_dto;
 pub mod move_block_dto;
 pub mod note_with_blocks_response;
+pub mod note_list_response;
+pub mod message_response;
 pub mod search_result_dto;
+pub mod block_search_hit;
+pub mod note_ref;
 pub mod note_join_payload;
 pub mod note_leave_payload;
 pub mod yjs_update_payload;
@@ -39,6 +47,13 @@ pub mod yjs_sync_payload;
 pub mod client_to_server_events;
 pub mod server_to_client_events;
 pub mod block_type;
+pub mod block_metadata;
+pub mod code_block_metadata;
+pub mod todo_block_metadata;
+pub mod media_block_metadata;
+pub mod callout_block_metadata;
+pub mod toggle_block_metadata;
+pub mod table_block_metadata;
 pub mod ifile_upload_response;
 pub mod isigned_url_response;
 pub mod iuser;
@@ -48,4 +63,8 @@ pub mod ws_error_response;
 pub mod ws_token_expiring_payload;
 pub mod ws_token_refreshed_payload;
 pub mod ws_message_ack_payload;
-pub mod web_socket_error_code;
\ No newline at end of file
+pub mod web_socket_error_code;
+pub mod error_category;
+pub mod collaboration_error_payload;
+pub mod ws_token_renewed_payload;
+pub mod ws_auth_error_payload;
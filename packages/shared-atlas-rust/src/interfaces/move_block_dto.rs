@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::block_id::BlockId;
 
 /// Payload for moving a block.
 /// 移动块的请求体。
@@ -12,5 +13,5 @@ pub struct MoveBlockDto {
     pub position: f64,
 /// Optional new parent block ID.
 /// 可选的新父块ID。
-    pub parent_block_id: Option<String>,
+    pub parent_block_id: Option<BlockId>,
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
 
 /// Payload for joining a note editing session.
 /// 加入笔记编辑会话的 Payload。
@@ -9,5 +10,5 @@ use serde::{Serialize, Deserialize};
 pub struct NoteJoinPayload {
 /// Note ID to join.
 /// 要加入的笔记ID。
-    pub note_id: String,
+    pub note_id: NoteId,
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
 
 /// Payload for leaving a note editing session.
 /// 离开笔记编辑会话的 Payload。
@@ -9,5 +10,5 @@ use serde::{Serialize, Deserialize};
 pub struct NoteLeavePayload {
 /// Note ID to leave.
 /// 要离开的笔记ID。
-    pub note_id: String,
+    pub note_id: NoteId,
 }
\ No newline at end of file
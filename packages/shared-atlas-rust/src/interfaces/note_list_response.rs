@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+use crate::interfaces::inote::INote;
+
+/// One page of notes.
+/// 一页笔记。
+/// 
+/// API: `GET /api/notes`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteListResponse {
+/// Notes of the requested page.
+/// 当前页的笔记。
+    pub notes: Vec<INote>,
+/// Number of notes matching the query, across all pages.
+/// 符合条件的笔记总数（所有页）。
+    pub total: u64,
+}
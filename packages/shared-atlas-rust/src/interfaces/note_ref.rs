@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
+
+/// ID and title of a note, as embedded in search hits.
+/// 笔记的 ID 与标题，内嵌于搜索结果中。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteRef {
+    pub id: NoteId,
+    pub title: String,
+}
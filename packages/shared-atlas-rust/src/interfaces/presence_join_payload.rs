@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::user_id::UserId;
 
 /// Event when a user joins the collaboration session.
 /// 用户加入协作会话时的事件。
@@ -8,7 +9,7 @@ use serde::{Serialize, Deserialize};
 #[serde(rename_all = "camelCase")]
 pub struct PresenceJoinPayload {
 /// User ID / 用户ID
-    pub user_id: String,
+    pub user_id: UserId,
 /// Username / 用户名
     pub username: String,
 /// User avatar URL / 用户头像URL
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::user_id::UserId;
 
 /// Event when a user leaves the collaboration session.
 /// 用户离开协作会话时的事件。
@@ -8,5 +9,5 @@ use serde::{Serialize, Deserialize};
 #[serde(rename_all = "camelCase")]
 pub struct PresenceLeavePayload {
 /// User ID / 用户ID
-    pub user_id: String,
+    pub user_id: UserId,
 }
\ No newline at end of file
@@ -1,9 +1,11 @@
 use serde::{Serialize, Deserialize};
+use crate::interfaces::block_search_hit::BlockSearchHit;
 use crate::interfaces::inote::INote;
-use serde_json;
 
 /// API response for search results.
 /// 搜索结果的 API 响应。
+/// 
+/// API: `GET /api/search`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchResultDto {
@@ -12,8 +14,8 @@ pub struct SearchResultDto {
     pub notes: Vec<INote>,
 /// Matching blocks with highlight info.
 /// 匹配的块及高亮信息。
-    pub blocks: Vec<serde_json::Value>,
-/// Total count of results.
-/// 结果总数。
-    pub total: f64,
+    pub blocks: Vec<BlockSearchHit>,
+/// Total count of results: matching notes plus matching blocks.
+/// 结果总数：匹配的笔记数加匹配的块数。
+    pub total: u64,
 }
\ No newline at end of file
@@ -0,0 +1,19 @@
+use serde::{Serialize, Deserialize};
+
+/// Returned by `/auth/google` and `/auth/microsoft` when no account exists
+/// for the social identity yet. Pass `signup_token` with an invitation code to
+/// the matching `/signup` endpoint.
+/// 社交账号尚未注册时由 `/auth/google` 与 `/auth/microsoft` 返回；
+/// 将 `signup_token` 与邀请码一起提交到对应的 `/signup` 接口。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SocialSignupRequiredResponse {
+    pub requires_invite: bool,
+    pub email: Option<String>,
+    /// Profile picture URL (Google only).
+    /// 头像地址（仅 Google）。
+    pub picture: Option<String>,
+    /// Short-lived (10 minutes) token identifying the social account.
+    /// 标识该社交账号的短期令牌（10 分钟）。
+    pub signup_token: String,
+}
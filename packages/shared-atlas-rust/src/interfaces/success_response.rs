@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+/// Confirmation of an operation without a resource to return.
+/// 无资源返回的操作的确认结果。
+/// 
+/// API: `DELETE /api/chat/sessions/:id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessResponse {
+    pub success: bool,
+}
@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+use serde_json;
+
+/// Metadata of a `TABLE` block.
+/// `TABLE` 块的元数据。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableBlockMetadata {
+/// Number of rows.
+/// 行数。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<u32>,
+/// Number of columns.
+/// 列数。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<u32>,
+/// Keys this crate does not know, kept as sent.
+/// 本库未识别的键，按原样保留。
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
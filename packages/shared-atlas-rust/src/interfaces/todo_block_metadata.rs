@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+use serde_json;
+
+/// Metadata of a `TODO_ITEM` or `TODO_LIST` block.
+/// `TODO_ITEM` 或 `TODO_LIST` 块的元数据。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoBlockMetadata {
+/// Whether the item is done.
+/// 该项是否已完成。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
+/// Keys this crate does not know, kept as sent.
+/// 本库未识别的键，按原样保留。
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+use serde_json;
+
+/// Metadata of a `TOGGLE` block.
+/// `TOGGLE` 块的元数据。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleBlockMetadata {
+/// Whether the children are shown.
+/// 是否展开显示子块。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<bool>,
+/// Keys this crate does not know, kept as sent.
+/// 本库未识别的键，按原样保留。
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -1,17 +1,29 @@
 use serde::{Serialize, Deserialize};
 use serde_json;
+use crate::interfaces::block_metadata::BlockMetadata;
 
 /// Payload for updating a block.
 /// 更新块的请求体。
+/// Omitted (`None`) fields are left unchanged.
+/// 省略（`None`）的字段保持不变。
 /// 
 /// API: `PATCH /api/blocks/:id`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateBlockDto {
 /// Optional new content.
 /// 可选的新内容。
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
 /// Optional new metadata.
 /// 可选的新元数据。
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
-}
\ No newline at end of file
+}
+
+impl UpdateBlockDto {
+    pub fn with_metadata(mut self, metadata: BlockMetadata) -> Self {
+        self.metadata = Some(metadata.to_value());
+        self
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+/// Payload for editing a chat message. The previous content is archived.
+/// 编辑聊天消息的请求体，原内容会被归档。
+/// 
+/// API: `PATCH /api/chat/sessions/:sessionId/messages/:messageId`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMessageDto {
+/// New content; must not be empty.
+/// 新内容，不能为空。
+    pub content: String,
+}
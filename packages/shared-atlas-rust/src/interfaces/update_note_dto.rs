@@ -2,18 +2,23 @@ use serde::{Serialize, Deserialize};
 
 /// Payload for updating note metadata.
 /// 更新笔记元数据的请求体。
+/// Omitted (`None`) fields are left unchanged.
+/// 省略（`None`）的字段保持不变。
 /// 
 /// API: `PATCH /api/notes/:id`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateNoteDto {
 /// Optional new title.
 /// 可选的新标题。
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 /// Optional new cover image URL.
 /// 可选的新封面图片URL。
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cover_image: Option<String>,
 /// Optional new icon.
 /// 可选的新图标。
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 }
\ No newline at end of file
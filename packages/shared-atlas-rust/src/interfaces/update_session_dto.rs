@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+/// Payload for renaming a chat session.
+/// 重命名聊天会话的请求体。
+/// 
+/// API: `PATCH /api/chat/sessions/:id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSessionDto {
+/// New title; must not be empty.
+/// 新标题，不能为空。
+    pub title: String,
+}
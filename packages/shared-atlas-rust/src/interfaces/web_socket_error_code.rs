@@ -1,29 +1,156 @@
-use serde::{Serialize, Deserialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::interfaces::error_category::ErrorCategory;
+
+/// Numeric error codes sent by the WebSocket gateways.
+/// WebSocket 网关发送的数字错误码。
+///
+/// Serialized as JSON numbers (e.g. `4012`), matching the server.
+/// 序列化为 JSON 数字（例如 `4012`），与服务端一致。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WebSocketErrorCode {
-    #[serde(rename = "4010")]
     AuthTokenMissing,
-    #[serde(rename = "4011")]
     AuthTokenInvalid,
-    #[serde(rename = "4012")]
     AuthTokenExpired,
-    #[serde(rename = "4030")]
     PermissionDenied,
-    #[serde(rename = "4031")]
     RateLimitExceeded,
-    #[serde(rename = "4032")]
     ConcurrentLimitReached,
-    #[serde(rename = "4220")]
     InvalidPayload,
-    #[serde(rename = "4221")]
     NoteNotFound,
-    #[serde(rename = "4222")]
     SessionNotFound,
-    #[serde(rename = "5000")]
     InternalError,
-    #[serde(rename = "5001")]
     DatabaseError,
-    #[serde(rename = "5002")]
     YjsSyncFailed,
-}
\ No newline at end of file
+    /// A code this crate does not know yet, kept as sent.
+    /// 本库尚未识别的错误码，按原样保留。
+    Unknown(u16),
+}
+
+/// How a client should react to an error code.
+/// 客户端应如何处理某个错误码。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorDisposition {
+    /// Retrying cannot succeed; surface the error.
+    /// 重试无法成功，应直接上报错误。
+    Fatal,
+    /// Obtain a new access token, then reconnect.
+    /// 获取新的访问令牌后重新连接。
+    Reauthenticate,
+    /// Transient; retry with backoff.
+    /// 暂时性错误，按退避策略重试。
+    Retry,
+}
+
+impl WebSocketErrorCode {
+    /// Numeric wire value of the code.
+    /// 错误码的数字值。
+    pub fn code(self) -> u16 {
+        match self {
+            WebSocketErrorCode::AuthTokenMissing => 4010,
+            WebSocketErrorCode::AuthTokenInvalid => 4011,
+            WebSocketErrorCode::AuthTokenExpired => 4012,
+            WebSocketErrorCode::PermissionDenied => 4030,
+            WebSocketErrorCode::RateLimitExceeded => 4031,
+            WebSocketErrorCode::ConcurrentLimitReached => 4032,
+            WebSocketErrorCode::InvalidPayload => 4220,
+            WebSocketErrorCode::NoteNotFound => 4221,
+            WebSocketErrorCode::SessionNotFound => 4222,
+            WebSocketErrorCode::InternalError => 5000,
+            WebSocketErrorCode::DatabaseError => 5001,
+            WebSocketErrorCode::YjsSyncFailed => 5002,
+            WebSocketErrorCode::Unknown(code) => code,
+        }
+    }
+
+    /// Looks up a code by its numeric value; unknown values become
+    /// [`WebSocketErrorCode::Unknown`].
+    /// 根据数字值查找错误码；未知值返回 [`WebSocketErrorCode::Unknown`]。
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            4010 => WebSocketErrorCode::AuthTokenMissing,
+            4011 => WebSocketErrorCode::AuthTokenInvalid,
+            4012 => WebSocketErrorCode::AuthTokenExpired,
+            4030 => WebSocketErrorCode::PermissionDenied,
+            4031 => WebSocketErrorCode::RateLimitExceeded,
+            4032 => WebSocketErrorCode::ConcurrentLimitReached,
+            4220 => WebSocketErrorCode::InvalidPayload,
+            4221 => WebSocketErrorCode::NoteNotFound,
+            4222 => WebSocketErrorCode::SessionNotFound,
+            5000 => WebSocketErrorCode::InternalError,
+            5001 => WebSocketErrorCode::DatabaseError,
+            5002 => WebSocketErrorCode::YjsSyncFailed,
+            code => WebSocketErrorCode::Unknown(code),
+        }
+    }
+
+    /// Category implied by the code range (401x, 403x, 422x, 500x).
+    /// 由错误码区间推导的类别（401x、403x、422x、500x）。
+    pub fn category(self) -> ErrorCategory {
+        match self.code() / 10 {
+            401 => ErrorCategory::Auth,
+            403 => ErrorCategory::Permission,
+            422 => ErrorCategory::Validation,
+            _ => ErrorCategory::Server,
+        }
+    }
+
+    /// How a client should react to this code. Unknown 401x codes
+    /// reauthenticate, unknown 5xxx codes are retried, the rest are fatal.
+    /// 客户端应如何处理此错误码。未知的 401x 错误码重新认证，5xxx 重试，其余视为致命错误。
+    pub fn disposition(self) -> ErrorDisposition {
+        match self {
+            WebSocketErrorCode::AuthTokenMissing
+            | WebSocketErrorCode::AuthTokenInvalid
+            | WebSocketErrorCode::AuthTokenExpired => ErrorDisposition::Reauthenticate,
+            WebSocketErrorCode::RateLimitExceeded
+            | WebSocketErrorCode::ConcurrentLimitReached
+            | WebSocketErrorCode::InternalError
+            | WebSocketErrorCode::DatabaseError
+            | WebSocketErrorCode::YjsSyncFailed => ErrorDisposition::Retry,
+            WebSocketErrorCode::PermissionDenied
+            | WebSocketErrorCode::InvalidPayload
+            | WebSocketErrorCode::NoteNotFound
+            | WebSocketErrorCode::SessionNotFound => ErrorDisposition::Fatal,
+            WebSocketErrorCode::Unknown(code) if code / 10 == 401 => ErrorDisposition::Reauthenticate,
+            WebSocketErrorCode::Unknown(code) if code >= 5000 => ErrorDisposition::Retry,
+            WebSocketErrorCode::Unknown(_) => ErrorDisposition::Fatal,
+        }
+    }
+
+    /// Retrying the same operation cannot succeed.
+    /// 重试相同操作无法成功。
+    pub fn is_fatal(self) -> bool {
+        self.disposition() == ErrorDisposition::Fatal
+    }
+
+    /// The access token must be refreshed before reconnecting.
+    /// 重新连接前必须刷新访问令牌。
+    pub fn requires_reauth(self) -> bool {
+        self.disposition() == ErrorDisposition::Reauthenticate
+    }
+
+    /// The error is transient and may be retried with backoff.
+    /// 暂时性错误，可按退避策略重试。
+    pub fn is_retryable(self) -> bool {
+        self.disposition() == ErrorDisposition::Retry
+    }
+}
+
+impl fmt::Display for WebSocketErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl Serialize for WebSocketErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebSocketErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u16::deserialize(deserializer).map(WebSocketErrorCode::from_code)
+    }
+}
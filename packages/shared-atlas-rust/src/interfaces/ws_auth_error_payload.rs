@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+/// Sent when a refreshed token could not be verified.
+/// 刷新后的令牌验证失败时发送。
+///
+/// Event: `server.emit('auth:error', payload)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsAuthErrorPayload {
+    pub message: String,
+}
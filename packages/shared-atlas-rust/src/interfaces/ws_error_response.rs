@@ -1,13 +1,19 @@
 use serde::{Serialize, Deserialize};
+use crate::interfaces::error_category::ErrorCategory;
 use crate::interfaces::web_socket_error_code::WebSocketErrorCode;
+use crate::time::timestamp::Timestamp;
 use serde_json;
 
+/// Structured error emitted on the `error` event by the WebSocket gateways.
+/// WebSocket 网关通过 `error` 事件发送的结构化错误。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WsErrorResponse {
     pub code: WebSocketErrorCode,
     pub message: String,
-    pub category: serde_json::Value,
+    pub category: ErrorCategory,
     pub details: Option<serde_json::Value>,
-    pub timestamp: String,
-}
\ No newline at end of file
+/// ISO-8601 time of the error. Omitted by the connection-time auth check.
+/// 错误发生时间（ISO-8601）。连接时的认证检查不会发送此字段。
+    pub timestamp: Option<Timestamp>,
+}
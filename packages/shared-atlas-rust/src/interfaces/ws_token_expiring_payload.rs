@@ -4,4 +4,7 @@ use serde::{Serialize, Deserialize};
 #[serde(rename_all = "camelCase")]
 pub struct WsTokenExpiringPayload {
     pub expires_in: f64,
+    /// What the client should do; the server sends `"refresh"`.
+    /// 客户端应执行的操作，服务端发送 `"refresh"`。
+    pub action: Option<String>,
 }
\ No newline at end of file
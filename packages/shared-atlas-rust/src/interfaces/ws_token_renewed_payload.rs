@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+/// Sent after the server accepted an `auth:token-refreshed` token.
+/// 服务端接受 `auth:token-refreshed` 令牌后发送。
+///
+/// Event: `server.emit('auth:token-renewed', payload)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsTokenRenewedPayload {
+    pub success: bool,
+}
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
 
 /// Payload for Y.js initial sync.
 /// Y.js 初始同步的 Payload。
@@ -8,7 +9,7 @@ use serde::{Serialize, Deserialize};
 #[serde(rename_all = "camelCase")]
 pub struct YjsSyncPayload {
 /// Note ID / 笔记ID
-    pub note_id: String,
+    pub note_id: NoteId,
 /// Y.js update data (base64) / Y.js 更新数据
     pub update: String,
 /// State vector (base64) / 状态向量
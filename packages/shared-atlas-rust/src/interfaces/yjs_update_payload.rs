@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::ids::note_id::NoteId;
 
 /// Payload for Y.js update synchronization.
 /// Y.js 更新同步的 Payload。
@@ -10,7 +11,7 @@ use serde::{Serialize, Deserialize};
 pub struct YjsUpdatePayload {
 /// Note ID.
 /// 笔记ID。
-    pub note_id: String,
+    pub note_id: NoteId,
 /// Y.js update data (Uint8Array encoded as base64 for JSON transport).
 /// Y.js 更新数据（Uint8Array编码为base64用于JSON传输）。
     pub update: String,
@@ -1,2 +1,17 @@
 pub mod dto;
-pub mod interfaces;
\ No newline at end of file
+pub mod ids;
+pub mod time;
+pub mod interfaces;
+pub mod error;
+pub mod events;
+pub mod protocol;
+#[cfg(feature = "socket")]
+pub mod socket;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "scheduler")]
+pub mod scheduler;
+#[cfg(feature = "mock-server")]
+pub mod mock;
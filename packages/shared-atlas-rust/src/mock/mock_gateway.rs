@@ -0,0 +1,348 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::events::atlas_server_event::AtlasServerEvent;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::chat_send_payload::ChatSendPayload;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::interfaces::collaboration_error_payload::CollaborationErrorPayload;
+use crate::interfaces::collaboration_limit_payload::CollaborationLimitPayload;
+use crate::interfaces::cursor_update_payload::CursorUpdatePayload;
+use crate::interfaces::icollaborator::ICollaborator;
+use crate::interfaces::iuser::IUser;
+use crate::interfaces::presence_join_payload::PresenceJoinPayload;
+use crate::interfaces::presence_leave_payload::PresenceLeavePayload;
+use crate::interfaces::ws_auth_error_payload::WsAuthErrorPayload;
+use crate::interfaces::ws_token_renewed_payload::WsTokenRenewedPayload;
+use crate::interfaces::yjs_sync_payload::YjsSyncPayload;
+use crate::interfaces::yjs_update_payload::YjsUpdatePayload;
+use crate::mock::mock_state::MockState;
+use crate::protocol::engine_handshake::EngineHandshake;
+use crate::protocol::engine_packet::EnginePacket;
+use crate::protocol::frame::Frame;
+use crate::protocol::socket_packet::SocketPacket;
+use crate::protocol::{CHAT_NAMESPACE, COLLABORATION_NAMESPACE};
+use crate::socket::socket_error::SocketError;
+use crate::socket::transport::Transport;
+use crate::time::timestamp::Timestamp;
+
+const PING_INTERVAL: Duration = Duration::from_secs(25);
+const PING_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Yjs update of an empty document, sent in `yjs:sync` for notes without a
+/// document set through `MockServer::set_document`.
+const EMPTY_DOCUMENT: &[u8] = &[0, 0];
+
+/// Cursor colors handed out to collaborators in join order.
+const COLORS: [&str; 5] = ["#FF6B6B", "#4ECDC4", "#45B7D1", "#FFA07A", "#98D8C8"];
+
+/// Sockets currently connected to the mock gateways.
+#[derive(Default)]
+pub(crate) struct Peers {
+    next_id: AtomicU64,
+    peers: Mutex<Vec<Peer>>,
+}
+
+struct Peer {
+    id: u64,
+    user: IUser,
+    notes: HashSet<NoteId>,
+    connected_at: Timestamp,
+    cursor: Option<(Option<Value>, Option<Value>)>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+enum Command {
+    Emit(AtlasServerEvent),
+    /// Closes the transport without a socket.io `DISCONNECT`, like a lost
+    /// network connection.
+    Drop,
+}
+
+impl Peers {
+    /// Sends `event` to every connected socket.
+    pub(crate) fn broadcast(&self, event: &AtlasServerEvent) {
+        for peer in self.peers.lock().unwrap().iter() {
+            let _ = peer.commands.send(Command::Emit(event.clone()));
+        }
+    }
+
+    pub(crate) fn drop_all(&self) {
+        for peer in self.peers.lock().unwrap().iter() {
+            let _ = peer.commands.send(Command::Drop);
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.peers.lock().unwrap().len()
+    }
+
+    fn emit(&self, id: u64, event: AtlasServerEvent) {
+        if let Some(peer) = self.peers.lock().unwrap().iter().find(|peer| peer.id == id) {
+            let _ = peer.commands.send(Command::Emit(event));
+        }
+    }
+
+    /// Sends `event` to the other sockets that joined `note_id`.
+    fn emit_to_note(&self, note_id: &NoteId, except: u64, event: &AtlasServerEvent) {
+        for peer in self.peers.lock().unwrap().iter() {
+            if peer.id != except && peer.notes.contains(note_id) {
+                let _ = peer.commands.send(Command::Emit(event.clone()));
+            }
+        }
+    }
+
+    fn with_peer<R>(&self, id: u64, f: impl FnOnce(&mut Peer) -> R) -> Option<R> {
+        self.peers.lock().unwrap().iter_mut().find(|peer| peer.id == id).map(f)
+    }
+
+    /// Collaborators of `note_id`, one per user.
+    fn collaborators(&self, note_id: &NoteId) -> Vec<ICollaborator> {
+        let peers = self.peers.lock().unwrap();
+        let mut collaborators: Vec<ICollaborator> = Vec::new();
+        for peer in peers.iter().filter(|peer| peer.notes.contains(note_id)) {
+            if collaborators.iter().any(|known| known.user_id == peer.user.id) {
+                continue;
+            }
+            let (cursor_position, selection) = peer.cursor.clone().unwrap_or((None, None));
+            collaborators.push(ICollaborator {
+                user_id: peer.user.id.clone(),
+                username: peer.user.username.clone(),
+                avatar: peer.user.avatar.clone(),
+                color: COLORS[collaborators.len() % COLORS.len()].to_owned(),
+                cursor_position,
+                selection,
+                connected_at: peer.connected_at,
+            });
+        }
+        collaborators
+    }
+}
+
+/// Serves one socket.io connection on the server end of `transport`.
+pub(crate) async fn serve<T: Transport>(state: Arc<MockState>, mut transport: T) {
+    let sid = format!("mock-{}", state.peers.next_id.fetch_add(1, Ordering::Relaxed));
+    let handshake = EngineHandshake {
+        sid: sid.clone(),
+        upgrades: vec![],
+        ping_interval: PING_INTERVAL.as_millis() as u64,
+        ping_timeout: PING_TIMEOUT.as_millis() as u64,
+        max_payload: 1_000_000,
+    };
+    if transport.send(EnginePacket::Open(handshake).encode()).await.is_err() {
+        return;
+    }
+    let Some((namespace, auth)) = expect_connect(&mut transport).await else {
+        return;
+    };
+    let token = auth.get("token").and_then(Value::as_str).unwrap_or_default();
+    let token = token.strip_prefix("Bearer ").unwrap_or(token).to_owned();
+    let (user, expired) = {
+        let store = state.store.lock().unwrap();
+        (store.authenticate(&token), store.expired_tokens.contains(&token))
+    };
+    let connected = SocketPacket::connect(&namespace, Some(json!({ "sid": sid })));
+    if send(&mut transport, &connected).await.is_err() {
+        return;
+    }
+    let Some(user) = user else {
+        // The gateways accept the namespace, then report the error and disconnect.
+        let (code, message) = if expired { (4012, "Token expired") } else { (4011, "Invalid token") };
+        let error = json!({ "code": code, "message": message, "category": "AUTH" });
+        let error = SocketPacket::Event { namespace: namespace.clone(), id: None, data: vec![json!("error"), error] };
+        let _ = send(&mut transport, &error).await;
+        let _ = send(&mut transport, &SocketPacket::Disconnect { namespace }).await;
+        return;
+    };
+
+    let (commands, mut inbox) = mpsc::unbounded_channel();
+    let id = state.peers.next_id.fetch_add(1, Ordering::Relaxed);
+    state.peers.peers.lock().unwrap().push(Peer {
+        id,
+        user,
+        notes: HashSet::new(),
+        connected_at: Timestamp::now(),
+        cursor: None,
+        commands,
+    });
+
+    let mut ping = tokio::time::interval_at(tokio::time::Instant::now() + PING_INTERVAL, PING_INTERVAL);
+    loop {
+        tokio::select! {
+            frame = transport.next() => {
+                let Some(Ok(frame)) = frame else { break };
+                match EnginePacket::decode(frame) {
+                    Ok(EnginePacket::Message(Frame::Text(text))) => match SocketPacket::decode(&text) {
+                        Ok(SocketPacket::Event { data, .. }) => {
+                            if let Some(event) = client_event(data) {
+                                handle(&state, id, &namespace, event);
+                            }
+                        }
+                        Ok(SocketPacket::Disconnect { .. }) => break,
+                        _ => {}
+                    },
+                    Ok(EnginePacket::Close) | Err(_) => break,
+                    Ok(_) => {}
+                }
+            }
+            command = inbox.recv() => match command {
+                Some(Command::Emit(event)) => {
+                    let Ok(packet) = SocketPacket::event(&namespace, None, &event) else { continue };
+                    if send(&mut transport, &packet).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Command::Drop) | None => break,
+            },
+            _ = ping.tick() => {
+                if transport.send(EnginePacket::Ping(None).encode()).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+    leave_all(&state, id);
+}
+
+async fn expect_connect<T: Transport>(transport: &mut T) -> Option<(String, Value)> {
+    while let Some(Ok(frame)) = transport.next().await {
+        if let Ok(EnginePacket::Message(Frame::Text(text))) = EnginePacket::decode(frame) {
+            if let Ok(SocketPacket::Connect { namespace, data }) = SocketPacket::decode(&text) {
+                return Some((namespace, data.unwrap_or(Value::Null)));
+            }
+        }
+    }
+    None
+}
+
+async fn send<T: Transport>(transport: &mut T, packet: &SocketPacket) -> Result<(), SocketError> {
+    for packet in packet.to_engine_packets() {
+        transport.send(packet.encode()).await?;
+    }
+    Ok(())
+}
+
+fn client_event(mut data: Vec<Value>) -> Option<AtlasClientEvent> {
+    if data.is_empty() {
+        return None;
+    }
+    let name = data.remove(0);
+    AtlasClientEvent::from_args(name.as_str()?, data).ok()
+}
+
+fn handle(state: &Arc<MockState>, id: u64, namespace: &str, event: AtlasClientEvent) {
+    let Some(user) = state.peers.with_peer(id, |peer| peer.user.clone()) else {
+        return;
+    };
+    match event {
+        AtlasClientEvent::TokenRefreshed(payload) => {
+            let renewed = state.store.lock().unwrap().authenticate(&payload.new_token).is_some();
+            let reply = if renewed {
+                AtlasServerEvent::TokenRenewed(WsTokenRenewedPayload { success: true })
+            } else {
+                AtlasServerEvent::AuthError(WsAuthErrorPayload { message: "Invalid token".to_owned() })
+            };
+            state.peers.emit(id, reply);
+        }
+        AtlasClientEvent::ChatSend(payload) if namespace == CHAT_NAMESPACE => {
+            tokio::spawn(stream_chat(state.clone(), id, user, payload));
+        }
+        AtlasClientEvent::NoteJoin(payload) if namespace == COLLABORATION_NAMESPACE => {
+            join(state, id, &user, &payload.note_id);
+        }
+        AtlasClientEvent::NoteLeave(payload) if namespace == COLLABORATION_NAMESPACE => {
+            state.peers.with_peer(id, |peer| peer.notes.remove(&payload.note_id));
+            let left = AtlasServerEvent::PresenceLeave(PresenceLeavePayload { user_id: user.id });
+            state.peers.emit_to_note(&payload.note_id, id, &left);
+        }
+        AtlasClientEvent::YjsUpdate(payload) if namespace == COLLABORATION_NAMESPACE => {
+            let update = YjsUpdatePayload { note_id: payload.note_id.clone(), update: payload.update };
+            state.peers.emit_to_note(&payload.note_id, id, &AtlasServerEvent::YjsUpdate(update));
+        }
+        AtlasClientEvent::CursorUpdate(payload) if namespace == COLLABORATION_NAMESPACE => {
+            state.peers.with_peer(id, |peer| peer.cursor = Some((payload.position.clone(), payload.selection.clone())));
+            let cursor = CursorUpdatePayload { user_id: Some(user.id), ..payload.clone() };
+            state.peers.emit_to_note(&payload.note_id, id, &AtlasServerEvent::CursorUpdate(cursor));
+        }
+        _ => {}
+    }
+}
+
+/// Streams the answer to `chat:send`, pausing `stream_delay` before each event.
+async fn stream_chat(state: Arc<MockState>, id: u64, user: IUser, payload: ChatSendPayload) {
+    let events = state.store.lock().unwrap().chat_turn(
+        &user.id,
+        &payload.session_id,
+        &payload.content,
+        payload.parent_id.as_ref(),
+    );
+    let events = events.unwrap_or_else(|| vec![ChatStreamEvent::Error { message: "Session not found".to_owned() }]);
+    for event in events {
+        tokio::time::sleep(state.stream_delay()).await;
+        state.peers.emit(id, AtlasServerEvent::ChatStream(event));
+    }
+}
+
+fn join(state: &MockState, id: u64, user: &IUser, note_id: &NoteId) {
+    let document = {
+        let store = state.store.lock().unwrap();
+        if store.note(note_id, &user.id).is_none() {
+            drop(store);
+            let error = CollaborationErrorPayload { error: "No edit permission".to_owned() };
+            state.peers.emit(id, AtlasServerEvent::CollaborationError(error));
+            return;
+        }
+        store.documents.get(note_id).cloned().unwrap_or_else(|| EMPTY_DOCUMENT.to_vec())
+    };
+
+    let editors = state.peers.collaborators(note_id);
+    let max_editors = state.max_editors.load(Ordering::Relaxed);
+    if editors.len() >= max_editors && !editors.iter().any(|editor| editor.user_id == user.id) {
+        let limit = CollaborationLimitPayload {
+            error: "Maximum concurrent editors reached".to_owned(),
+            current_editors: editors.len() as f64,
+            max_editors: max_editors as f64,
+        };
+        state.peers.emit(id, AtlasServerEvent::CollaborationLimit(limit));
+        return;
+    }
+
+    state.peers.with_peer(id, |peer| peer.notes.insert(note_id.to_owned()));
+    let collaborators = state.peers.collaborators(note_id);
+    if let Some(me) = collaborators.iter().find(|collaborator| collaborator.user_id == user.id) {
+        let joined = PresenceJoinPayload {
+            user_id: me.user_id.clone(),
+            username: me.username.clone(),
+            avatar: me.avatar.clone(),
+            color: me.color.clone(),
+        };
+        state.peers.emit_to_note(note_id, id, &AtlasServerEvent::PresenceJoin(joined));
+    }
+    let sync = YjsSyncPayload {
+        note_id: note_id.to_owned(),
+        update: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &document),
+        state_vector: "AA==".to_owned(),
+    };
+    state.peers.emit(id, AtlasServerEvent::YjsSync(sync));
+    state.peers.emit(id, AtlasServerEvent::PresenceList(collaborators));
+}
+
+/// Unregisters a closed socket and tells the notes it was in.
+fn leave_all(state: &MockState, id: u64) {
+    let removed = {
+        let mut peers = state.peers.peers.lock().unwrap();
+        let Some(index) = peers.iter().position(|peer| peer.id == id) else { return };
+        peers.remove(index)
+    };
+    for note_id in &removed.notes {
+        let left = AtlasServerEvent::PresenceLeave(PresenceLeavePayload { user_id: removed.user.id.clone() });
+        state.peers.emit_to_note(note_id, id, &left);
+    }
+}
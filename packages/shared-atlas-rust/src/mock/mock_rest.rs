@@ -0,0 +1,767 @@
+use std::cmp::Reverse;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::extract::ws::WebSocketUpgrade;
+use axum::extract::{DefaultBodyLimit, Multipart, Path, Query, Request, State};
+use axum::http::header::{AUTHORIZATION, CONTENT_DISPOSITION, CONTENT_TYPE, COOKIE, RETRY_AFTER, SET_COOKIE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, patch, post};
+use axum::{Json, Router};
+use futures_util::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::atlas_client::AUTH_MODE_HEADER;
+use crate::client::upload_kind::UploadKind;
+use crate::dto::get_messages_dto::GetMessagesDto;
+use crate::dto::get_signed_url_query_dto::GetSignedUrlQueryDto;
+use crate::dto::list_notes_query::ListNotesQuery;
+use crate::dto::login_dto::LoginDto;
+use crate::dto::refresh_token_dto::RefreshTokenDto;
+use crate::dto::search_query::SearchQuery;
+use crate::dto::signup_dto::SignupDto;
+use crate::dto::upload_file_query_dto::UploadFileQueryDto;
+use crate::ids::block_id::BlockId;
+use crate::ids::message_id::MessageId;
+use crate::ids::note_id::NoteId;
+use crate::ids::session_id::SessionId;
+use crate::interfaces::add_message_dto::AddMessageDto;
+use crate::interfaces::block_search_hit::BlockSearchHit;
+use crate::interfaces::chat_message_response::ChatMessageResponse;
+use crate::interfaces::chat_role::ChatRole;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::interfaces::create_block_dto::CreateBlockDto;
+use crate::interfaces::create_note_dto::CreateNoteDto;
+use crate::interfaces::create_session_dto::CreateSessionDto;
+use crate::interfaces::get_messages_response::GetMessagesResponse;
+use crate::interfaces::ichat_message::IChatMessage;
+use crate::interfaces::ichat_session::IChatSession;
+use crate::interfaces::ifile_upload_response::IFileUploadResponse;
+use crate::interfaces::isigned_url_response::ISignedUrlResponse;
+use crate::interfaces::iuser::IUser;
+use crate::interfaces::message_response::MessageResponse;
+use crate::interfaces::mobile_auth_response::MobileAuthResponse;
+use crate::interfaces::move_block_dto::MoveBlockDto;
+use crate::interfaces::note_ref::NoteRef;
+use crate::interfaces::note_list_response::NoteListResponse;
+use crate::interfaces::refresh_token_response::RefreshTokenResponse;
+use crate::interfaces::search_result_dto::SearchResultDto;
+use crate::interfaces::success_response::SuccessResponse;
+use crate::interfaces::token_response::TokenResponse;
+use crate::interfaces::update_block_dto::UpdateBlockDto;
+use crate::interfaces::update_message_dto::UpdateMessageDto;
+use crate::interfaces::update_note_dto::UpdateNoteDto;
+use crate::interfaces::update_session_dto::UpdateSessionDto;
+use crate::mock::mock_gateway;
+use crate::mock::mock_state::MockState;
+use crate::mock::mock_store::DEFAULT_SESSION_TITLE;
+use crate::mock::mock_websocket::MockWebSocket;
+use crate::time::timestamp::Timestamp;
+use crate::time::timestamp_format::TimestampFormat;
+
+type Shared = State<Arc<MockState>>;
+type Reply = Result<Response, HttpError>;
+
+/// The REST API, without the `/api` prefix.
+pub(crate) fn router(state: Arc<MockState>) -> Router {
+    Router::new()
+        .route("/auth/signup", post(signup))
+        .route("/auth/login", post(login))
+        .route("/auth/refresh", post(refresh))
+        .route("/auth/profile", get(profile))
+        .route("/auth/logout", post(logout))
+        .route("/notes", post(create_note).get(list_notes))
+        .route("/notes/{id}", get(get_note).patch(update_note).delete(delete_note))
+        .route("/notes/{id}/duplicate", post(duplicate_note))
+        .route("/notes/{id}/blocks", get(list_blocks).post(create_block))
+        .route("/blocks/{id}", patch(update_block).delete(delete_block))
+        .route("/blocks/{id}/move", post(move_block))
+        .route("/versions/blocks/{id}", get(block_history))
+        .route("/versions/blocks/{id}/rollback/{version_id}", post(rollback_block))
+        .route("/versions/notes/{id}/snapshots", get(list_snapshots).post(create_snapshot))
+        .route("/export/{id}/markdown", get(export_markdown))
+        .route("/export/{id}/html", get(export_html))
+        .route("/search", get(search))
+        .route("/chat/sessions", post(create_session).get(list_sessions))
+        .route("/chat/sessions/{id}", get(get_session).patch(rename_session).delete(delete_session))
+        .route("/chat/sessions/{id}/messages", get(list_messages).post(add_message))
+        .route("/chat/sessions/{id}/messages/{message_id}", patch(update_message))
+        .route("/storage/url", get(signed_url))
+        .route("/storage/upload", post(upload_to_storage))
+        .route("/storage/files/{*path}", get(download))
+        .route("/upload/{kind}/{note_id}", post(upload_note_file))
+        .route("/socket.io/", get(socket_io))
+        .fallback(|request: Request| async move {
+            let message = format!("Cannot {} {}", request.method(), request.uri().path());
+            error(StatusCode::NOT_FOUND, &message)
+        })
+        .layer(middleware::from_fn_with_state(state.clone(), scripted_failure))
+        .layer(DefaultBodyLimit::disable())
+        .with_state(state)
+}
+
+/// Answers with the failure queued by `MockServer::fail_next`, if any.
+async fn scripted_failure(State(state): Shared, request: Request, next: Next) -> Response {
+    let Some(failure) = state.take_failure(request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let status = StatusCode::from_u16(failure.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let mut response = error(status, &failure.message).into_response();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        response.headers_mut().insert(RETRY_AFTER, HeaderValue::from_static("1"));
+    }
+    response
+}
+
+/// A failed request, answered with a NestJS exception body.
+struct HttpError {
+    status: StatusCode,
+    message: String,
+}
+
+impl IntoResponse for HttpError {
+    fn into_response(self) -> Response {
+        let body = json!({
+            "statusCode": self.status.as_u16(),
+            "message": self.message,
+            "error": self.status.canonical_reason().unwrap_or_default(),
+        });
+        (self.status, Json(body)).into_response()
+    }
+}
+
+fn error(status: StatusCode, message: &str) -> HttpError {
+    HttpError { status, message: message.to_owned() }
+}
+
+fn not_found(what: &str) -> HttpError {
+    error(StatusCode::NOT_FOUND, &format!("{what} not found"))
+}
+
+fn ok<T: Serialize>(value: T) -> Reply {
+    Ok(Json(value).into_response())
+}
+
+fn created<T: Serialize>(value: T) -> Reply {
+    Ok((StatusCode::CREATED, Json(value)).into_response())
+}
+
+fn cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers.get_all(COOKIE).iter().filter_map(|value| value.to_str().ok()).flat_map(|value| value.split(';')).find_map(
+        |pair| {
+            let (key, value) = pair.trim().split_once('=')?;
+            (key == name).then(|| value.to_owned())
+        },
+    )
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    let bearer = headers.get(AUTHORIZATION).and_then(|value| value.to_str().ok());
+    bearer.and_then(|value| value.strip_prefix("Bearer ")).map(str::to_owned)
+}
+
+/// The `access_token` cookie, else the bearer header, like the server's
+/// `ExtractJwt.fromExtractors([cookie, bearer])`.
+fn access_token(headers: &HeaderMap) -> Option<String> {
+    cookie(headers, "access_token").or_else(|| bearer_token(headers))
+}
+
+fn authenticate(state: &MockState, headers: &HeaderMap) -> Result<IUser, HttpError> {
+    access_token(headers)
+        .and_then(|token| state.store.lock().unwrap().authenticate(&token))
+        .ok_or_else(|| error(StatusCode::UNAUTHORIZED, "Unauthorized"))
+}
+
+fn bearer_mode(headers: &HeaderMap) -> bool {
+    headers.get(AUTH_MODE_HEADER).and_then(|value| value.to_str().ok()) == Some("bearer")
+}
+
+/// The tokens as a mobile body, or as cookies next to `cookie_body`.
+fn with_tokens<T: Serialize>(headers: &HeaderMap, mobile_body: Value, cookie_body: T, tokens: &TokenResponse) -> Reply {
+    if bearer_mode(headers) {
+        return created(mobile_body);
+    }
+    let mut response = (StatusCode::CREATED, Json(cookie_body)).into_response();
+    for (name, value) in [("access_token", &tokens.access_token), ("refresh_token", &tokens.refresh_token)] {
+        if let Ok(cookie) = HeaderValue::try_from(format!("{name}={value}; Path=/; HttpOnly")) {
+            response.headers_mut().append(SET_COOKIE, cookie);
+        }
+    }
+    Ok(response)
+}
+
+async fn signup(State(state): Shared, Json(dto): Json<SignupDto>) -> Reply {
+    let mut store = state.store.lock().unwrap();
+    if store.users.iter().any(|entry| entry.user.username == dto.username) {
+        return Err(error(StatusCode::CONFLICT, "Username already exists"));
+    }
+    let email = dto.email.clone().unwrap_or_else(|| format!("{}@example.com", dto.username));
+    created(store.add_user(&dto.username, &email, &dto.password))
+}
+
+async fn login(State(state): Shared, headers: HeaderMap, Json(dto): Json<LoginDto>) -> Reply {
+    let mut store = state.store.lock().unwrap();
+    let user = store
+        .users
+        .iter()
+        .find(|entry| entry.user.username == dto.username && entry.password == dto.password)
+        .map(|entry| entry.user.clone());
+    let Some(user) = user else {
+        // The server reports bad credentials with a success status.
+        return created(MessageResponse { message: "Invalid credentials".to_owned() });
+    };
+    let tokens = store.issue_tokens(&user.id);
+    let mobile = json!(MobileAuthResponse { user: user.clone(), tokens: tokens.clone() });
+    with_tokens(&headers, mobile, user, &tokens)
+}
+
+async fn refresh(State(state): Shared, headers: HeaderMap, body: Option<Json<RefreshTokenDto>>) -> Reply {
+    // Same order as the server's refresh strategy: cookie, body, bearer header.
+    let refresh_token = cookie(&headers, "refresh_token")
+        .or_else(|| body.and_then(|Json(dto)| dto.refresh_token))
+        .or_else(|| bearer_token(&headers));
+    let tokens = refresh_token.and_then(|token| state.store.lock().unwrap().rotate(&token));
+    let Some(tokens) = tokens else {
+        return Err(error(StatusCode::UNAUTHORIZED, "Invalid refresh token"));
+    };
+    let mobile = json!(RefreshTokenResponse { tokens: tokens.clone() });
+    let message = MessageResponse { message: "Tokens refreshed successfully".to_owned() };
+    with_tokens(&headers, mobile, message, &tokens)
+}
+
+async fn profile(State(state): Shared, headers: HeaderMap) -> Reply {
+    ok(authenticate(&state, &headers)?)
+}
+
+async fn logout(State(state): Shared, headers: HeaderMap) -> Reply {
+    authenticate(&state, &headers)?;
+    if let Some(token) = access_token(&headers) {
+        state.store.lock().unwrap().access_tokens.remove(&token);
+    }
+    let mut response = Json(MessageResponse { message: "Logged out successfully".to_owned() }).into_response();
+    for name in ["access_token", "refresh_token"] {
+        if let Ok(cookie) = HeaderValue::try_from(format!("{name}=; Path=/; Max-Age=0")) {
+            response.headers_mut().append(SET_COOKIE, cookie);
+        }
+    }
+    Ok(response)
+}
+
+async fn create_note(State(state): Shared, headers: HeaderMap, Json(dto): Json<CreateNoteDto>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    created(state.store.lock().unwrap().create_note(&user.id, &dto))
+}
+
+async fn list_notes(State(state): Shared, headers: HeaderMap, Query(query): Query<ListNotesQuery>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let store = state.store.lock().unwrap();
+    let notes: Vec<_> = store
+        .notes
+        .iter()
+        .filter(|note| note.user_id == user.id && !note.is_deleted && note.parent_id == query.parent_id)
+        .collect();
+    let total = notes.len() as u64;
+    let offset = query.offset.unwrap_or(0).max(0) as usize;
+    let limit = query.limit.unwrap_or(50).max(0) as usize;
+    let notes = notes.into_iter().skip(offset).take(limit).cloned().collect();
+    ok(NoteListResponse { notes, total })
+}
+
+async fn get_note(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let store = state.store.lock().unwrap();
+    ok(store.note(&id, &user.id).ok_or_else(|| not_found("Note"))?)
+}
+
+async fn update_note(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<NoteId>,
+    Json(dto): Json<UpdateNoteDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let note = store.note_mut(&id, &user.id).ok_or_else(|| not_found("Note"))?;
+    if let Some(title) = dto.title {
+        note.title = title;
+    }
+    if dto.cover_image.is_some() {
+        note.cover_image = dto.cover_image;
+    }
+    if dto.icon.is_some() {
+        note.icon = dto.icon;
+    }
+    note.updated_at = Timestamp::now();
+    note.last_edited_by = user.id;
+    ok(note.clone())
+}
+
+async fn delete_note(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    store.note_mut(&id, &user.id).ok_or_else(|| not_found("Note"))?.is_deleted = true;
+    ok(MessageResponse { message: "Note deleted successfully".to_owned() })
+}
+
+async fn duplicate_note(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let original = store.note(&id, &user.id).ok_or_else(|| not_found("Note"))?.clone();
+    let dto = CreateNoteDto {
+        title: format!("{} (Copy)", original.title),
+        template_id: None,
+        parent_id: original.parent_id.clone(),
+    };
+    let note = store.create_note(&user.id, &dto);
+    let copy = store.note_mut(&note.id, &user.id).ok_or_else(|| not_found("Note"))?;
+    copy.cover_image = original.cover_image;
+    copy.icon = original.icon;
+    created(copy.clone())
+}
+
+async fn list_blocks(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let store = state.store.lock().unwrap();
+    store.note(&id, &user.id).ok_or_else(|| not_found("Note"))?;
+    ok(store.blocks_of(&id))
+}
+
+async fn create_block(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<NoteId>,
+    Json(dto): Json<CreateBlockDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    store.note(&id, &user.id).ok_or_else(|| not_found("Note"))?;
+    created(store.create_block(&id, &user.id, &dto))
+}
+
+async fn update_block(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<BlockId>,
+    Json(dto): Json<UpdateBlockDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let block = store.block_mut(&id, &user.id).ok_or_else(|| not_found("Block"))?;
+    if let Some(content) = dto.content {
+        block.content = content;
+    }
+    if let Some(metadata) = dto.metadata {
+        block.metadata = metadata;
+    }
+    block.updated_at = Timestamp::now();
+    block.last_edited_by = user.id.clone();
+    let block = block.clone();
+    store.record_version(&block, "updated", &user.id);
+    ok(block)
+}
+
+async fn delete_block(State(state): Shared, headers: HeaderMap, Path(id): Path<BlockId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let block = store.block_mut(&id, &user.id).ok_or_else(|| not_found("Block"))?.clone();
+    store.blocks.retain(|known| known.id != id);
+    store.record_version(&block, "deleted", &user.id);
+    ok(MessageResponse { message: "Block deleted successfully".to_owned() })
+}
+
+async fn move_block(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<BlockId>,
+    Json(dto): Json<MoveBlockDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let block = store.block_mut(&id, &user.id).ok_or_else(|| not_found("Block"))?;
+    block.position = dto.position;
+    block.parent_block_id = dto.parent_block_id;
+    block.updated_at = Timestamp::now();
+    ok(block.clone())
+}
+
+#[derive(Deserialize)]
+struct HistoryQuery {
+    limit: Option<usize>,
+}
+
+async fn block_history(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<BlockId>,
+    Query(query): Query<HistoryQuery>,
+) -> Reply {
+    authenticate(&state, &headers)?;
+    let store = state.store.lock().unwrap();
+    let versions: Vec<&Value> = store
+        .block_versions
+        .iter()
+        .rev()
+        .filter(|version| version["blockId"] == id.as_str())
+        .take(query.limit.unwrap_or(20))
+        .collect();
+    ok(versions)
+}
+
+async fn rollback_block(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path((id, version_id)): Path<(BlockId, String)>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let version = store
+        .block_versions
+        .iter()
+        .find(|version| version["id"] == version_id.as_str() && version["blockId"] == id.as_str())
+        .cloned()
+        .ok_or_else(|| not_found("Version"))?;
+    let block = store.block_mut(&id, &user.id).ok_or_else(|| not_found("Block"))?;
+    if let Some(content) = version["content"].as_str() {
+        block.content = content.to_owned();
+        block.metadata = version["metadata"].clone();
+    }
+    block.updated_at = Timestamp::now();
+    let block = block.clone();
+    store.record_version(&block, "updated", &user.id);
+    created(block)
+}
+
+async fn list_snapshots(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    authenticate(&state, &headers)?;
+    let store = state.store.lock().unwrap();
+    let snapshots: Vec<&Value> =
+        store.snapshots.iter().rev().filter(|snapshot| snapshot["noteId"] == id.as_str()).collect();
+    ok(snapshots)
+}
+
+async fn create_snapshot(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let snapshot = state.store.lock().unwrap().create_snapshot(&id, &user.id);
+    created(snapshot.ok_or_else(|| not_found("Note"))?)
+}
+
+fn export(state: &MockState, headers: &HeaderMap, id: &NoteId) -> Result<String, HttpError> {
+    let user = authenticate(state, headers)?;
+    let store = state.store.lock().unwrap();
+    let note = store.note(id, &user.id).ok_or_else(|| not_found("Note"))?;
+    Ok(store.markdown(note))
+}
+
+async fn export_markdown(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let markdown = export(&state, &headers, &id)?;
+    let disposition = format!("attachment; filename=\"note_{id}.md\"");
+    Ok(([(CONTENT_TYPE, "text/markdown".to_owned()), (CONTENT_DISPOSITION, disposition)], markdown).into_response())
+}
+
+async fn export_html(State(state): Shared, headers: HeaderMap, Path(id): Path<NoteId>) -> Reply {
+    let html = format!("<html><body>{}</body></html>", export(&state, &headers, &id)?.replace('\n', "<br>"));
+    let disposition = format!("attachment; filename=\"note_{id}.html\"");
+    Ok(([(CONTENT_TYPE, "text/html".to_owned()), (CONTENT_DISPOSITION, disposition)], html).into_response())
+}
+
+/// Case-insensitive substring search over note titles and block content.
+async fn search(State(state): Shared, headers: HeaderMap, Query(query): Query<SearchQuery>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let needle = query.q.trim().to_lowercase();
+    if needle.is_empty() {
+        return ok(SearchResultDto { notes: vec![], blocks: vec![], total: 0 });
+    }
+    let store = state.store.lock().unwrap();
+    let owned = |note_id: &NoteId| store.note(note_id, &user.id);
+    let notes: Vec<_> = store
+        .notes
+        .iter()
+        .filter(|note| owned(&note.id).is_some() && note.title.to_lowercase().contains(&needle))
+        .cloned()
+        .collect();
+    let blocks: Vec<_> = store
+        .blocks
+        .iter()
+        .filter(|block| block.content.to_lowercase().contains(&needle))
+        .filter_map(|block| {
+            let note = owned(&block.note_id)?;
+            Some(BlockSearchHit {
+                id: block.id.clone(),
+                note_id: block.note_id.clone(),
+                r#type: block.r#type.clone(),
+                content: block.content.clone(),
+                updated_at: Some(block.updated_at),
+                note: NoteRef { id: note.id.clone(), title: note.title.clone() },
+                highlight: Some(snippet(&block.content, &needle)),
+            })
+        })
+        .collect();
+    let total = (notes.len() + blocks.len()) as u64;
+    ok(SearchResultDto { notes, blocks, total })
+}
+
+/// Up to 40 characters before and 60 after the first match, like `SearchService.getSnippet`.
+fn snippet(content: &str, needle: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let lower: Vec<char> = content.to_lowercase().chars().collect();
+    let needle: Vec<char> = needle.chars().collect();
+    let Some(index) = lower.windows(needle.len()).position(|window| window == needle.as_slice()) else {
+        return chars.iter().take(100).collect();
+    };
+    let start = index.saturating_sub(40);
+    let end = (index + 60).min(chars.len());
+    let mut snippet: String = chars[start..end].iter().collect();
+    if start > 0 {
+        snippet.insert_str(0, "...");
+    }
+    if end < chars.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+async fn create_session(State(state): Shared, headers: HeaderMap, Json(_): Json<CreateSessionDto>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let now = Timestamp::now();
+    let session = IChatSession {
+        id: store.id(),
+        user_id: user.id,
+        title: DEFAULT_SESSION_TITLE.to_owned(),
+        created_at: now,
+        updated_at: now,
+    };
+    store.sessions.push(session.clone());
+    created(session)
+}
+
+async fn list_sessions(State(state): Shared, headers: HeaderMap) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let store = state.store.lock().unwrap();
+    let mut sessions: Vec<&IChatSession> = store.sessions.iter().filter(|session| session.user_id == user.id).collect();
+    sessions.sort_by_key(|session| Reverse(session.updated_at));
+    ok(sessions)
+}
+
+async fn get_session(State(state): Shared, headers: HeaderMap, Path(id): Path<SessionId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    ok(store.session_mut(&id, &user.id).ok_or_else(|| not_found("Session"))?.clone())
+}
+
+async fn rename_session(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<SessionId>,
+    Json(dto): Json<UpdateSessionDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    let session = store.session_mut(&id, &user.id).ok_or_else(|| not_found("Session"))?;
+    session.title = dto.title;
+    session.updated_at = Timestamp::now();
+    ok(session.clone())
+}
+
+async fn delete_session(State(state): Shared, headers: HeaderMap, Path(id): Path<SessionId>) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    store.session_mut(&id, &user.id).ok_or_else(|| not_found("Session"))?;
+    store.sessions.retain(|session| session.id != id);
+    store.messages.retain(|message| message.session_id != id);
+    ok(SuccessResponse { success: true })
+}
+
+fn message_response(message: &IChatMessage) -> ChatMessageResponse {
+    ChatMessageResponse {
+        id: message.id.clone(),
+        session_id: message.session_id.clone(),
+        role: message.role.clone(),
+        content: message.content.clone(),
+        created_at: message.created_at,
+        parent_id: message.parent_id.clone(),
+    }
+}
+
+/// The newest `limit` messages older than `before`, oldest first.
+async fn list_messages(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<SessionId>,
+    Query(query): Query<GetMessagesDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    store.session_mut(&id, &user.id).ok_or_else(|| not_found("Session"))?;
+    let mut messages: Vec<&IChatMessage> = store.messages.iter().filter(|message| message.session_id == id).collect();
+    if let Some(before) = &query.before {
+        let end = messages.iter().position(|message| message.id == *before).unwrap_or(messages.len());
+        messages.truncate(end);
+    }
+    let limit = query.limit.unwrap_or(20).clamp(1, 100) as usize;
+    let start = messages.len().saturating_sub(limit);
+    let has_more = start > 0;
+    let page: Vec<ChatMessageResponse> = messages[start..].iter().map(|message| message_response(message)).collect();
+    let next_cursor = if has_more { page.first().map(|message| message.id.clone()) } else { None };
+    ok(GetMessagesResponse { messages: page, has_more, next_cursor })
+}
+
+/// Streams the answer as server-sent events, pausing `stream_delay` before each.
+async fn add_message(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path(id): Path<SessionId>,
+    Json(dto): Json<AddMessageDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    if !matches!(dto.role, None | Some(ChatRole::User)) {
+        return Err(error(StatusCode::BAD_REQUEST, "role must be one of the following values: user"));
+    }
+    let events = state.store.lock().unwrap().chat_turn(&user.id, &id, &dto.content, dto.parent_id.as_ref());
+    let events = events.ok_or_else(|| not_found("Session"))?;
+    let delay = state.stream_delay();
+    let body = stream::iter(events).then(move |event: ChatStreamEvent| async move {
+        tokio::time::sleep(delay).await;
+        let data = serde_json::to_string(&event).unwrap_or_default();
+        Ok::<_, Infallible>(format!("data: {data}\n\n"))
+    });
+    Ok(([(CONTENT_TYPE, "text/event-stream")], Body::from_stream(body)).into_response())
+}
+
+async fn update_message(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path((id, message_id)): Path<(SessionId, MessageId)>,
+    Json(dto): Json<UpdateMessageDto>,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let mut store = state.store.lock().unwrap();
+    store.session_mut(&id, &user.id).ok_or_else(|| not_found("Session"))?;
+    let message = store
+        .messages
+        .iter_mut()
+        .find(|message| message.id == message_id && message.session_id == id)
+        .ok_or_else(|| not_found("Message"))?;
+    message.content = dto.content;
+    ok(message_response(message))
+}
+
+async fn signed_url(State(state): Shared, headers: HeaderMap, Query(query): Query<GetSignedUrlQueryDto>) -> Reply {
+    authenticate(&state, &headers)?;
+    ok(state.sign(&query.path, query.expiration_seconds.unwrap_or(3600)))
+}
+
+async fn download(State(state): Shared, Path(path): Path<String>) -> Reply {
+    let store = state.store.lock().unwrap();
+    let (content_type, bytes) = store.files.get(&path).ok_or_else(|| not_found("File"))?;
+    Ok(([(CONTENT_TYPE, content_type.clone())], bytes.clone()).into_response())
+}
+
+/// The `file` field of a multipart body: name, content type and bytes.
+/// Stops with the server's error once `max_size` is exceeded.
+async fn read_file(multipart: &mut Multipart, max_size: Option<u64>) -> Result<(String, String, Vec<u8>), HttpError> {
+    let bad_request = |message: &str| error(StatusCode::BAD_REQUEST, message);
+    while let Some(mut field) = multipart.next_field().await.map_err(|err| bad_request(&err.body_text()))? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let name = field.file_name().unwrap_or("upload").to_owned();
+        let content_type = field.content_type().unwrap_or("application/octet-stream").to_owned();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(|err| bad_request(&err.body_text()))? {
+            bytes.extend_from_slice(&chunk);
+            if let Some(max) = max_size.filter(|max| bytes.len() as u64 > *max) {
+                return Err(bad_request(&format!("File too large. Maximum size: {}MB", max / (1024 * 1024))));
+            }
+        }
+        return Ok((name, content_type, bytes));
+    }
+    Err(bad_request("No file uploaded"))
+}
+
+fn store_file(
+    state: &MockState,
+    folder: &str,
+    name: String,
+    content_type: String,
+    bytes: Vec<u8>,
+) -> IFileUploadResponse {
+    let mut store = state.store.lock().unwrap();
+    let path = format!("{folder}/{}-{name}", store.uuid());
+    let size = bytes.len();
+    store.files.insert(path.clone(), (content_type.clone(), bytes));
+    drop(store);
+    let signed = state.sign(&path, 3600);
+    IFileUploadResponse {
+        success: true,
+        url: signed.url,
+        path,
+        expires_at: Some(signed.expires_at),
+        metadata: json!({ "filename": name, "size": size, "contentType": content_type }),
+    }
+}
+
+async fn upload_to_storage(
+    State(state): Shared,
+    headers: HeaderMap,
+    Query(query): Query<UploadFileQueryDto>,
+    mut multipart: Multipart,
+) -> Reply {
+    authenticate(&state, &headers)?;
+    let (name, content_type, bytes) = read_file(&mut multipart, None).await?;
+    let folder = query.folder.unwrap_or_else(|| "uploads".to_owned());
+    let module = query.module.and_then(|module| serde_json::to_value(module).ok());
+    let folder = match module.as_ref().and_then(Value::as_str) {
+        Some(module) => format!("{module}/{folder}"),
+        None => folder,
+    };
+    created(store_file(&state, &folder, name, content_type, bytes))
+}
+
+async fn upload_note_file(
+    State(state): Shared,
+    headers: HeaderMap,
+    Path((kind, note_id)): Path<(String, NoteId)>,
+    mut multipart: Multipart,
+) -> Reply {
+    let user = authenticate(&state, &headers)?;
+    let kind = [UploadKind::Image, UploadKind::Video, UploadKind::File]
+        .into_iter()
+        .find(|known| known.as_str() == kind)
+        .ok_or_else(|| error(StatusCode::NOT_FOUND, &format!("Cannot POST /upload/{kind}/{note_id}")))?;
+    let (name, content_type, bytes) = read_file(&mut multipart, Some(kind.max_size())).await?;
+    if !kind.accepts(&content_type) {
+        let message = format!("Invalid file type. Accepted types: {}", kind.accepted_mime_types().join(", "));
+        return Err(error(StatusCode::BAD_REQUEST, &message));
+    }
+    if state.store.lock().unwrap().note(&note_id, &user.id).is_none() {
+        return Err(error(StatusCode::FORBIDDEN, "Cannot edit this note"));
+    }
+    created(store_file(&state, &format!("notes/{kind}s"), name, content_type, bytes))
+}
+
+/// The socket.io gateways over a real WebSocket, for `WebSocketTransport`.
+async fn socket_io(State(state): Shared, upgrade: WebSocketUpgrade) -> Response {
+    upgrade.on_upgrade(move |socket| mock_gateway::serve(state, MockWebSocket::new(socket)))
+}
+
+impl MockState {
+    /// A URL serving `path` from the mock storage.
+    fn sign(&self, path: &str, expiration_seconds: i32) -> ISignedUrlResponse {
+        let lifetime = Duration::from_secs(expiration_seconds.max(1) as u64);
+        let now = Timestamp::now().with_format(TimestampFormat::EpochMillis);
+        let expires_at = now.checked_add(lifetime).unwrap_or(now);
+        let base = self.base_url.get().map(String::as_str).unwrap_or_default();
+        ISignedUrlResponse {
+            url: format!("{base}/storage/files/{path}?expires={}", expires_at.as_millis()),
+            path: path.to_owned(),
+            expires_at,
+            expires_in: lifetime.as_secs_f64(),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::io;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use crate::client::atlas_client::AtlasClient;
+use crate::client::auth_mode::AuthMode;
+use crate::client::client_error::ClientError;
+use crate::events::atlas_server_event::AtlasServerEvent;
+use crate::ids::note_id::NoteId;
+use crate::ids::user_id::UserId;
+use crate::interfaces::iuser::IUser;
+use crate::interfaces::token_response::TokenResponse;
+use crate::interfaces::ws_token_expiring_payload::WsTokenExpiringPayload;
+use crate::mock::mock_gateway;
+use crate::mock::mock_rest;
+use crate::mock::mock_state::{MockState, ScriptedFailure};
+use crate::socket::memory_transport::MemoryTransport;
+use crate::socket::socket_options::SocketOptions;
+
+/// Username of the user every mock server starts with.
+/// 模拟服务器初始用户的用户名。
+pub const DEFAULT_USERNAME: &str = "alice";
+
+/// Password of [`DEFAULT_USERNAME`].
+/// [`DEFAULT_USERNAME`] 的密码。
+pub const DEFAULT_PASSWORD: &str = "password";
+
+/// An Atlas backend on a local port, holding its data in memory.
+/// 监听本地端口、数据保存在内存中的 Atlas 后端。
+///
+/// REST routes are served at the root of [`MockServer::url`] (the client's
+/// base URL, without the `/api` prefix). The socket.io gateways answer
+/// WebSockets at `/socket.io/` on the same port
+/// ([`MockServer::websocket_options`]) and also run in-process through the
+/// connector of [`MockServer::socket_options`]. The server stops when dropped.
+/// REST 路由挂在 [`MockServer::url`] 根路径下（即客户端的基础 URL，不含 `/api`
+/// 前缀）。socket.io 网关在同一端口的 `/socket.io/` 上接受 WebSocket 连接
+/// （[`MockServer::websocket_options`]），也可通过 [`MockServer::socket_options`]
+/// 的连接器在进程内运行。服务器在被丢弃时停止。
+pub struct MockServer {
+    state: Arc<MockState>,
+    url: String,
+    user: IUser,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Binds `127.0.0.1` on a free port and seeds the default user.
+    /// 在 `127.0.0.1` 的空闲端口上监听，并创建默认用户。
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let state = Arc::new(MockState::new());
+        let _ = state.base_url.set(url.clone());
+        let user = state.store.lock().unwrap().add_user(DEFAULT_USERNAME, "alice@example.com", DEFAULT_PASSWORD);
+        let router = mock_rest::router(state.clone());
+        let task = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        Ok(MockServer { state, url, user, task })
+    }
+
+    /// Base URL for [`AtlasClient`].
+    /// [`AtlasClient`] 的基础 URL。
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The default user.
+    /// 默认用户。
+    pub fn user(&self) -> &IUser {
+        &self.user
+    }
+
+    /// A client for this server, not logged in.
+    /// 指向本服务器、尚未登录的客户端。
+    pub fn client(&self, mode: AuthMode) -> Result<AtlasClient, ClientError> {
+        AtlasClient::new(&self.url, mode)
+    }
+
+    pub fn add_user(&self, username: &str, password: &str) -> IUser {
+        let email = format!("{username}@example.com");
+        self.state.store.lock().unwrap().add_user(username, &email, password)
+    }
+
+    /// Issues a token pair without going through `/auth/login`.
+    /// 不经过 `/auth/login` 直接签发一对令牌。
+    pub fn issue_tokens(&self, user_id: &UserId) -> TokenResponse {
+        self.state.store.lock().unwrap().issue_tokens(user_id)
+    }
+
+    /// Socket options that connect to the in-process gateways with `token`.
+    /// 使用 `token` 连接进程内网关的 socket 选项。
+    pub fn socket_options(&self, token: impl Into<String>) -> SocketOptions {
+        let state = self.state.clone();
+        SocketOptions::new(&self.url, token).with_connector(move |_url| {
+            let (client, server) = MemoryTransport::pair();
+            tokio::spawn(mock_gateway::serve(state.clone(), server));
+            async move { Ok(client) }
+        })
+    }
+
+    /// Socket options that connect to `/socket.io/` over a real WebSocket
+    /// with `token`.
+    /// 通过真实 WebSocket 连接 `/socket.io/` 并使用 `token` 的 socket 选项。
+    pub fn websocket_options(&self, token: impl Into<String>) -> SocketOptions {
+        SocketOptions::new(&self.url, token)
+    }
+
+    /// Makes every access token issued so far expire: REST calls answer 401
+    /// and socket handshakes fail with code 4012.
+    /// 使目前签发的所有访问令牌过期：REST 调用返回 401，socket 握手以 4012 失败。
+    pub fn expire_tokens(&self) {
+        let mut store = self.state.store.lock().unwrap();
+        let issued: Vec<String> = store.access_tokens.keys().cloned().collect();
+        store.expired_tokens.extend(issued);
+    }
+
+    /// Rejects every refresh token issued so far.
+    /// 拒绝目前签发的所有刷新令牌。
+    pub fn revoke_refresh_tokens(&self) {
+        self.state.store.lock().unwrap().refresh_tokens.clear();
+    }
+
+    /// Closes every socket without a `DISCONNECT`, like a lost connection.
+    /// 不发送 `DISCONNECT` 直接关闭所有 socket，模拟连接中断。
+    pub fn drop_sockets(&self) {
+        self.state.peers.drop_all();
+    }
+
+    /// Number of authenticated sockets currently connected.
+    /// 当前已认证连接的 socket 数量。
+    pub fn connected_sockets(&self) -> usize {
+        self.state.peers.len()
+    }
+
+    /// Sends `auth:token-expiring` to every connected socket.
+    /// 向所有已连接的 socket 发送 `auth:token-expiring`。
+    pub fn send_token_expiring(&self, expires_in: Duration) {
+        let payload = WsTokenExpiringPayload {
+            expires_in: expires_in.as_secs_f64(),
+            action: Some("refresh".to_owned()),
+        };
+        self.state.peers.broadcast(&AtlasServerEvent::TokenExpiring(payload));
+    }
+
+    /// Editors allowed per note before `collaboration:limit` (default 5).
+    /// 每篇笔记允许的编辑者数量，超出时发送 `collaboration:limit`（默认 5）。
+    pub fn set_max_editors(&self, max: usize) {
+        self.state.max_editors.store(max, Ordering::Relaxed);
+    }
+
+    /// Pause before each chat stream event, over SSE and `chat:stream`.
+    /// SSE 与 `chat:stream` 中每个聊天流事件之前的停顿。
+    pub fn set_stream_delay(&self, delay: Duration) {
+        *self.state.stream_delay.lock().unwrap() = delay;
+    }
+
+    /// Answers the next request under `path` with a NestJS error instead of
+    /// handling it; a 429 carries `Retry-After: 1`.
+    /// 对 `path` 下的下一个请求直接返回 NestJS 错误；429 附带 `Retry-After: 1`。
+    pub fn fail_next(&self, path: impl Into<String>, status: u16, message: impl Into<String>) {
+        let failure = ScriptedFailure { path: path.into(), status, message: message.into() };
+        self.state.failures.lock().unwrap().push_back(failure);
+    }
+
+    /// Yjs state sent in `yjs:sync` when joining `note_id`.
+    /// 加入 `note_id` 时 `yjs:sync` 中发送的 Yjs 状态。
+    pub fn set_document(&self, note_id: &NoteId, state: Vec<u8>) {
+        self.state.store.lock().unwrap().documents.insert(note_id.clone(), state);
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.state.peers.drop_all();
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::mock::mock_gateway::Peers;
+use crate::mock::mock_store::MockStore;
+
+/// Editors allowed per note, as in `PresenceService`.
+pub(crate) const DEFAULT_MAX_EDITORS: usize = 5;
+
+/// State shared by the REST handlers and the gateway sessions.
+pub(crate) struct MockState {
+    pub(crate) store: Mutex<MockStore>,
+    pub(crate) peers: Peers,
+    pub(crate) base_url: OnceLock<String>,
+    pub(crate) stream_delay: Mutex<Duration>,
+    pub(crate) max_editors: AtomicUsize,
+    pub(crate) failures: Mutex<VecDeque<ScriptedFailure>>,
+}
+
+/// An error answered instead of the next request under `path`.
+pub(crate) struct ScriptedFailure {
+    pub(crate) path: String,
+    pub(crate) status: u16,
+    pub(crate) message: String,
+}
+
+impl MockState {
+    pub(crate) fn new() -> Self {
+        MockState {
+            store: Mutex::new(MockStore::default()),
+            peers: Peers::default(),
+            base_url: OnceLock::new(),
+            stream_delay: Mutex::new(Duration::ZERO),
+            max_editors: AtomicUsize::new(DEFAULT_MAX_EDITORS),
+            failures: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub(crate) fn stream_delay(&self) -> Duration {
+        *self.stream_delay.lock().unwrap()
+    }
+
+    /// Removes and returns the first scripted failure matching `path`.
+    pub(crate) fn take_failure(&self, path: &str) -> Option<ScriptedFailure> {
+        let mut failures = self.failures.lock().unwrap();
+        let index = failures.iter().position(|failure| {
+            path.strip_prefix(failure.path.trim_end_matches('/'))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })?;
+        failures.remove(index)
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+
+use crate::ids::block_id::BlockId;
+use crate::ids::message_id::MessageId;
+use crate::ids::note_id::NoteId;
+use crate::ids::session_id::SessionId;
+use crate::ids::user_id::UserId;
+use crate::interfaces::block_type::BlockType;
+use crate::interfaces::chat_role::ChatRole;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::interfaces::create_block_dto::CreateBlockDto;
+use crate::interfaces::create_note_dto::CreateNoteDto;
+use crate::interfaces::iblock::IBlock;
+use crate::interfaces::ichat_message::IChatMessage;
+use crate::interfaces::ichat_session::IChatSession;
+use crate::interfaces::inote::INote;
+use crate::interfaces::iuser::IUser;
+use crate::interfaces::token_response::TokenResponse;
+use crate::time::timestamp::Timestamp;
+
+/// Title of a session until its first message names it.
+pub(crate) const DEFAULT_SESSION_TITLE: &str = "New Chat";
+
+/// In-memory tables of the mock backend.
+#[derive(Default)]
+pub(crate) struct MockStore {
+    next_id: u64,
+    pub(crate) users: Vec<MockUser>,
+    /// Access token -> user id.
+    pub(crate) access_tokens: HashMap<String, UserId>,
+    /// Access tokens invalidated by `MockServer::expire_tokens`.
+    pub(crate) expired_tokens: HashSet<String>,
+    /// Refresh token -> user id.
+    pub(crate) refresh_tokens: HashMap<String, UserId>,
+    pub(crate) notes: Vec<INote>,
+    pub(crate) blocks: Vec<IBlock>,
+    /// `BlockVersion` entities, newest last.
+    pub(crate) block_versions: Vec<Value>,
+    /// `NoteSnapshot` entities, newest last.
+    pub(crate) snapshots: Vec<Value>,
+    pub(crate) sessions: Vec<IChatSession>,
+    pub(crate) messages: Vec<IChatMessage>,
+    /// Storage path -> (content type, bytes).
+    pub(crate) files: HashMap<String, (String, Vec<u8>)>,
+    /// Yjs state sent in `yjs:sync`, per note.
+    pub(crate) documents: HashMap<NoteId, Vec<u8>>,
+}
+
+pub(crate) struct MockUser {
+    pub(crate) user: IUser,
+    pub(crate) password: String,
+}
+
+impl MockStore {
+    /// A fresh version 4 UUID, counting up so ids sort by creation.
+    pub(crate) fn uuid(&mut self) -> String {
+        self.next_id += 1;
+        format!("00000000-0000-4000-8000-{:012x}", self.next_id)
+    }
+
+    pub(crate) fn id<T: FromStr>(&mut self) -> T {
+        match self.uuid().parse() {
+            Ok(id) => id,
+            Err(_) => unreachable!("generated ids are UUIDs"),
+        }
+    }
+
+    pub(crate) fn add_user(&mut self, username: &str, email: &str, password: &str) -> IUser {
+        let now = Timestamp::now();
+        let user = IUser {
+            id: self.id(),
+            username: username.to_owned(),
+            email: email.to_owned(),
+            avatar: None,
+            created_at: now,
+            updated_at: now,
+        };
+        self.users.push(MockUser { user: user.clone(), password: password.to_owned() });
+        user
+    }
+
+    pub(crate) fn user(&self, id: &UserId) -> Option<&IUser> {
+        self.users.iter().map(|entry| &entry.user).find(|user| user.id == *id)
+    }
+
+    pub(crate) fn issue_tokens(&mut self, user_id: &UserId) -> TokenResponse {
+        let tokens = TokenResponse {
+            access_token: format!("access-{}", self.uuid()),
+            refresh_token: format!("refresh-{}", self.uuid()),
+        };
+        self.access_tokens.insert(tokens.access_token.clone(), user_id.clone());
+        self.refresh_tokens.insert(tokens.refresh_token.clone(), user_id.clone());
+        tokens
+    }
+
+    /// The user an access token belongs to, if it is still valid.
+    pub(crate) fn authenticate(&self, access_token: &str) -> Option<IUser> {
+        if self.expired_tokens.contains(access_token) {
+            return None;
+        }
+        self.access_tokens.get(access_token).and_then(|user_id| self.user(user_id)).cloned()
+    }
+
+    /// Swaps a refresh token for a new pair; the old one is consumed.
+    pub(crate) fn rotate(&mut self, refresh_token: &str) -> Option<TokenResponse> {
+        let user_id = self.refresh_tokens.remove(refresh_token)?;
+        Some(self.issue_tokens(&user_id))
+    }
+
+    pub(crate) fn note(&self, id: &NoteId, user_id: &UserId) -> Option<&INote> {
+        self.notes.iter().find(|note| note.id == *id && note.user_id == *user_id && !note.is_deleted)
+    }
+
+    pub(crate) fn note_mut(&mut self, id: &NoteId, user_id: &UserId) -> Option<&mut INote> {
+        self.notes.iter_mut().find(|note| note.id == *id && note.user_id == *user_id && !note.is_deleted)
+    }
+
+    pub(crate) fn create_note(&mut self, user_id: &UserId, dto: &CreateNoteDto) -> INote {
+        let now = Timestamp::now();
+        let note = INote {
+            id: self.id(),
+            user_id: user_id.clone(),
+            title: dto.title.clone(),
+            cover_image: None,
+            icon: None,
+            parent_id: dto.parent_id.clone(),
+            has_children: Some(false),
+            template: None,
+            is_public: false,
+            is_deleted: false,
+            created_at: now,
+            updated_at: now,
+            last_edited_by: user_id.clone(),
+        };
+        if let Some(parent) = dto.parent_id.as_ref().and_then(|parent| self.note_mut(parent, user_id)) {
+            parent.has_children = Some(true);
+        }
+        self.notes.push(note.clone());
+        note
+    }
+
+    /// Blocks of a note ordered by position.
+    pub(crate) fn blocks_of(&self, note_id: &NoteId) -> Vec<IBlock> {
+        let mut blocks: Vec<IBlock> = self.blocks.iter().filter(|block| block.note_id == *note_id).cloned().collect();
+        blocks.sort_by(|a, b| a.position.total_cmp(&b.position));
+        blocks
+    }
+
+    /// A block together with the owner check on its note.
+    pub(crate) fn block_mut(&mut self, id: &BlockId, user_id: &UserId) -> Option<&mut IBlock> {
+        let note_id = self.blocks.iter().find(|block| block.id == *id)?.note_id.clone();
+        self.note(&note_id, user_id)?;
+        self.blocks.iter_mut().find(|block| block.id == *id)
+    }
+
+    pub(crate) fn create_block(&mut self, note_id: &NoteId, user_id: &UserId, dto: &CreateBlockDto) -> IBlock {
+        let now = Timestamp::now();
+        let position = dto.position.unwrap_or_else(|| {
+            self.blocks.iter().filter(|block| block.note_id == *note_id).map(|block| block.position).fold(0.0, f64::max)
+                + 1.0
+        });
+        let block = IBlock {
+            id: self.id(),
+            note_id: note_id.clone(),
+            r#type: dto.r#type.clone(),
+            content: dto.content.clone(),
+            metadata: dto.metadata.clone().unwrap_or_else(|| json!({})),
+            parent_block_id: dto.parent_block_id.clone(),
+            position,
+            created_at: now,
+            updated_at: now,
+            created_by: user_id.clone(),
+            last_edited_by: user_id.clone(),
+            children: None,
+            is_deleted: None,
+        };
+        self.blocks.push(block.clone());
+        self.record_version(&block, "created", user_id);
+        block
+    }
+
+    pub(crate) fn record_version(&mut self, block: &IBlock, change_type: &str, user_id: &UserId) {
+        let previous = self.block_versions.iter().filter(|version| version["blockId"] == block.id.as_str()).count();
+        let version = json!({
+            "id": self.uuid(),
+            "blockId": block.id,
+            "versionNumber": previous + 1,
+            "content": block.content,
+            "metadata": block.metadata,
+            "changeType": change_type,
+            "diff": null,
+            "createdAt": Timestamp::now(),
+            "createdBy": user_id,
+        });
+        self.block_versions.push(version);
+    }
+
+    pub(crate) fn create_snapshot(&mut self, note_id: &NoteId, user_id: &UserId) -> Option<Value> {
+        let note = self.note(note_id, user_id)?.clone();
+        let blocks: Vec<Value> = self
+            .blocks_of(note_id)
+            .into_iter()
+            .map(|block| {
+                json!({
+                    "id": block.id,
+                    "type": block.r#type,
+                    "content": block.content,
+                    "metadata": block.metadata,
+                    "parentBlockId": block.parent_block_id,
+                    "position": block.position,
+                })
+            })
+            .collect();
+        let snapshot = json!({
+            "id": self.uuid(),
+            "noteId": note_id,
+            "snapshotData": {
+                "title": note.title,
+                "icon": note.icon,
+                "coverImage": note.cover_image,
+                "blocks": blocks,
+            },
+            "createdAt": Timestamp::now(),
+            "createdBy": user_id,
+        });
+        self.snapshots.push(snapshot.clone());
+        Some(snapshot)
+    }
+
+    pub(crate) fn session_mut(&mut self, id: &SessionId, user_id: &UserId) -> Option<&mut IChatSession> {
+        self.sessions.iter_mut().find(|session| session.id == *id && session.user_id == *user_id)
+    }
+
+    pub(crate) fn add_message(
+        &mut self,
+        session_id: &SessionId,
+        role: ChatRole,
+        content: &str,
+        parent_id: MessageId,
+    ) -> IChatMessage {
+        let message = IChatMessage {
+            id: self.id(),
+            session_id: session_id.clone(),
+            role,
+            content: content.to_owned(),
+            created_at: Timestamp::now(),
+            parent_id,
+        };
+        self.messages.push(message.clone());
+        message
+    }
+
+    /// Stores a user message and the mock's answer (an echo), and returns
+    /// the events streamed for it. `None` if the session does not exist.
+    pub(crate) fn chat_turn(
+        &mut self,
+        user_id: &UserId,
+        session_id: &SessionId,
+        content: &str,
+        parent_id: Option<&MessageId>,
+    ) -> Option<Vec<ChatStreamEvent>> {
+        self.session_mut(session_id, user_id)?;
+        let parent_id = parent_id.cloned().unwrap_or_else(|| self.leaf(session_id));
+        let question = self.add_message(session_id, ChatRole::User, content, parent_id);
+        let answer = format!("Echo: {content}");
+        self.add_message(session_id, ChatRole::Assistant, &answer, question.id);
+        let session = self.session_mut(session_id, user_id)?;
+        if session.title == DEFAULT_SESSION_TITLE {
+            session.title = content.chars().take(30).collect();
+        }
+        session.updated_at = Timestamp::now();
+        let title = session.title.clone();
+
+        let mut events = vec![ChatStreamEvent::Thought { content: "Reading the message".to_owned() }];
+        for chunk in answer.split_inclusive(' ') {
+            events.push(ChatStreamEvent::AnswerChunk { content: chunk.to_owned() });
+        }
+        events.push(ChatStreamEvent::Done { title: Some(title) });
+        Some(events)
+    }
+
+    /// Id of the newest message of a session, or `ROOT`.
+    pub(crate) fn leaf(&self, session_id: &SessionId) -> MessageId {
+        self.messages
+            .iter()
+            .rev()
+            .find(|message| message.session_id == *session_id)
+            .map_or_else(MessageId::root, |message| message.id.clone())
+    }
+
+    /// The note rendered like `ExportService.exportToMarkdown`.
+    pub(crate) fn markdown(&self, note: &INote) -> String {
+        let mut markdown = format!("# {}\n\n", note.title);
+        for block in self.blocks_of(&note.id) {
+            markdown.push_str(&block_markdown(&block));
+            markdown.push_str("\n\n");
+        }
+        markdown
+    }
+}
+
+fn block_markdown(block: &IBlock) -> String {
+    let filename = |fallback: &str| {
+        block.media().and_then(|media| media.filename).unwrap_or_else(|| fallback.to_owned())
+    };
+    let content = &block.content;
+    match block.r#type {
+        BlockType::Heading1 => format!("# {content}"),
+        BlockType::Heading2 => format!("## {content}"),
+        BlockType::Heading3 => format!("### {content}"),
+        BlockType::BulletList => format!("- {content}"),
+        BlockType::NumberedList => format!("1. {content}"),
+        BlockType::TodoList | BlockType::TodoItem => {
+            format!("{} {content}", if block.is_checked() { "[x]" } else { "[ ]" })
+        }
+        BlockType::Code => format!("```{}\n{content}\n```", block.code_language().unwrap_or_default()),
+        BlockType::Quote => format!("> {content}"),
+        BlockType::Divider => "---".to_owned(),
+        BlockType::Image => format!("![{}]({content})", filename("image")),
+        BlockType::Video => format!("[Video: {}]({content})", filename("video")),
+        BlockType::File => format!("[File: {}]({content})", filename("attachment")),
+        BlockType::Callout => format!(":::info\n{content}\n:::"),
+        _ => content.clone(),
+    }
+}
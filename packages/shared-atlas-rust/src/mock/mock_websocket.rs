@@ -0,0 +1,66 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use axum::extract::ws::{Message, WebSocket};
+use futures_util::{Sink, Stream};
+
+use crate::protocol::frame::Frame;
+use crate::socket::socket_error::SocketError;
+
+/// Server end of a `/socket.io/` WebSocket, as a frame transport for the
+/// mock gateways.
+pub(crate) struct MockWebSocket {
+    inner: WebSocket,
+}
+
+impl MockWebSocket {
+    pub(crate) fn new(inner: WebSocket) -> Self {
+        MockWebSocket { inner }
+    }
+}
+
+impl Stream for MockWebSocket {
+    type Item = Result<Frame, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => Poll::Ready(Some(Ok(Frame::Text(text.to_string())))),
+                Poll::Ready(Some(Ok(Message::Binary(bytes)))) => Poll::Ready(Some(Ok(Frame::Binary(bytes.to_vec())))),
+                Poll::Ready(Some(Ok(Message::Close(_)))) => Poll::Ready(None),
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(transport_error(err)))),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            };
+        }
+    }
+}
+
+impl Sink<Frame> for MockWebSocket {
+    type Error = SocketError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Pin::new(&mut self.inner).poll_ready(cx).map_err(transport_error)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, frame: Frame) -> Result<(), SocketError> {
+        let message = match frame {
+            Frame::Text(text) => Message::text(text),
+            Frame::Binary(bytes) => Message::binary(bytes),
+        };
+        Pin::new(&mut self.inner).start_send(message).map_err(transport_error)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Pin::new(&mut self.inner).poll_flush(cx).map_err(transport_error)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Pin::new(&mut self.inner).poll_close(cx).map_err(transport_error)
+    }
+}
+
+fn transport_error(err: axum::Error) -> SocketError {
+    SocketError::Transport(err.to_string())
+}
@@ -0,0 +1,16 @@
+//! In-process mock of the Atlas backend for integration tests (feature
+//! `mock-server`).
+//! 用于集成测试的进程内 Atlas 后端模拟（`mock-server` 特性）。
+//!
+//! Serves the REST API and both socket.io gateways from in-memory state,
+//! using the crate's DTOs, with scriptable token expiry, dropped sockets,
+//! collaboration limits, slow streams and failing routes.
+//! 基于内存状态并使用本 crate 的 DTO 提供 REST API 与两个 socket.io 网关，
+//! 可编排令牌过期、socket 断开、协作人数上限、慢速流与失败路由。
+
+pub(crate) mod mock_gateway;
+pub(crate) mod mock_rest;
+pub mod mock_server;
+pub(crate) mod mock_state;
+pub(crate) mod mock_store;
+pub(crate) mod mock_websocket;
@@ -0,0 +1,117 @@
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::protocol::protocol_error::ProtocolError;
+
+/// Incremental decoder for the streamed body of
+/// `POST /api/chat/sessions/:id/messages`.
+/// `POST /api/chat/sessions/:id/messages` 流式响应体的增量解码器。
+///
+/// Accepts Server-Sent Events (`data: {...}` frames separated by a blank
+/// line) as well as chunked newline-delimited JSON (one event per line).
+/// Chunks may split a frame, a line or a UTF-8 sequence anywhere; incomplete
+/// input is buffered until the rest arrives.
+/// 同时支持 SSE（以空行分隔的 `data: {...}` 帧）与分块传输的换行分隔 JSON
+/// （每行一个事件）。分块可在任意位置切分帧、行或 UTF-8 字符，不完整的数据
+/// 会被缓存直到剩余部分到达。
+#[derive(Debug, Default)]
+pub struct ChatStreamDecoder {
+    buffer: Vec<u8>,
+    /// `data:` lines of the SSE frame being read.
+    data: Option<Vec<u8>>,
+    finished: bool,
+}
+
+impl ChatStreamDecoder {
+    pub fn new() -> Self {
+        ChatStreamDecoder::default()
+    }
+
+    /// Appends the next chunk of the response body.
+    /// 追加响应体的下一个分块。
+    pub fn feed(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Marks the end of the body, so a last line or frame without its
+    /// trailing newline is decoded too.
+    /// 标记响应体结束，使末尾缺少换行的行或帧也能被解码。
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Decodes the next complete event, or `None` until more input arrives.
+    /// SSE comments, non-`data` fields and the `[DONE]` sentinel are skipped.
+    /// 解码下一个完整事件；输入不足时返回 `None`。
+    /// SSE 注释、非 `data` 字段与 `[DONE]` 标记会被跳过。
+    pub fn next_event(&mut self) -> Result<Option<ChatStreamEvent>, ProtocolError> {
+        while let Some(line) = self.next_line() {
+            if line.is_empty() {
+                if let Some(event) = self.dispatch()? {
+                    return Ok(Some(event));
+                }
+            } else if line.starts_with(b"{") {
+                if let Some(event) = parse(&line)? {
+                    return Ok(Some(event));
+                }
+            } else if let Some(value) = sse_data(&line) {
+                let data = self.data.get_or_insert_with(Vec::new);
+                if !data.is_empty() {
+                    data.push(b'\n');
+                }
+                data.extend_from_slice(value);
+            }
+        }
+        if self.finished {
+            return self.dispatch();
+        }
+        Ok(None)
+    }
+
+    /// Whether undecoded input is buffered.
+    /// 是否有尚未解码的缓存数据。
+    pub fn has_pending(&self) -> bool {
+        !self.buffer.is_empty() || self.data.is_some()
+    }
+
+    /// Removes the next line (without its `\n` or `\r\n`) from the buffer.
+    fn next_line(&mut self) -> Option<Vec<u8>> {
+        let mut line = match self.buffer.iter().position(|&byte| byte == b'\n') {
+            Some(end) => {
+                let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+                line.pop();
+                line
+            }
+            None if self.finished && !self.buffer.is_empty() => std::mem::take(&mut self.buffer),
+            None => return None,
+        };
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        Some(line)
+    }
+
+    /// Decodes the buffered SSE frame, if any.
+    fn dispatch(&mut self) -> Result<Option<ChatStreamEvent>, ProtocolError> {
+        match self.data.take() {
+            Some(data) => parse(&data),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The value of an SSE `data` field; `None` for comments and other fields.
+fn sse_data(line: &[u8]) -> Option<&[u8]> {
+    let value = line.strip_prefix(b"data")?;
+    if value.is_empty() {
+        return Some(value);
+    }
+    let value = value.strip_prefix(b":")?;
+    Some(value.strip_prefix(b" ").unwrap_or(value))
+}
+
+fn parse(data: &[u8]) -> Result<Option<ChatStreamEvent>, ProtocolError> {
+    let data = data.trim_ascii();
+    if data.is_empty() || data == b"[DONE]" {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(data)?))
+}
@@ -0,0 +1,28 @@
+use serde::{Serialize, Deserialize};
+
+/// Payload of the Engine.IO `open` packet.
+/// Engine.IO `open` 数据包的内容。
+///
+/// Example / 示例:
+/// `0{"sid":"lv_VI97HAXpY6yYWAAAC","upgrades":[],"pingInterval":10000,"pingTimeout":20000,"maxPayload":1000000}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineHandshake {
+/// Engine.IO session id.
+/// Engine.IO 会话 ID。
+    pub sid: String,
+/// Transports the connection may upgrade to.
+/// 可升级到的传输方式。
+    #[serde(default)]
+    pub upgrades: Vec<String>,
+/// Server ping interval in milliseconds (10000 on the Atlas gateways).
+/// 服务端 ping 间隔（毫秒，Atlas 网关为 10000）。
+    pub ping_interval: u64,
+/// Time in milliseconds the server waits for a pong (20000 on the Atlas gateways).
+/// 服务端等待 pong 的时间（毫秒，Atlas 网关为 20000）。
+    pub ping_timeout: u64,
+/// Largest accepted payload in bytes.
+/// 可接受的最大负载字节数。
+    #[serde(default)]
+    pub max_payload: u64,
+}
@@ -0,0 +1,124 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+
+use crate::protocol::engine_handshake::EngineHandshake;
+use crate::protocol::frame::Frame;
+use crate::protocol::protocol_error::ProtocolError;
+
+/// Separator between packets in a long-polling payload.
+/// 长轮询负载中数据包之间的分隔符。
+pub const RECORD_SEPARATOR: char = '\u{1e}';
+
+/// An Engine.IO v4 packet.
+/// Engine.IO v4 数据包。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnginePacket {
+/// `0` - handshake data sent by the server.
+    Open(EngineHandshake),
+/// `1` - close the transport.
+    Close,
+/// `2` - heartbeat sent by the server; `2probe` during upgrade.
+    Ping(Option<String>),
+/// `3` - heartbeat answer; `3probe` during upgrade.
+    Pong(Option<String>),
+/// `4` - application data. Socket.IO packets travel as text messages,
+/// their binary attachments as binary messages.
+    Message(Frame),
+/// `5` - finish a transport upgrade.
+    Upgrade,
+/// `6` - no-op, used to end a polling cycle during upgrade.
+    Noop,
+}
+
+impl EnginePacket {
+    /// Encodes the packet as one WebSocket message.
+    /// 将数据包编码为一条 WebSocket 消息。
+    pub fn encode(&self) -> Frame {
+        match self {
+            EnginePacket::Message(Frame::Binary(bytes)) => Frame::Binary(bytes.clone()),
+            other => Frame::Text(other.encode_text()),
+        }
+    }
+
+    /// Decodes one WebSocket message.
+    /// 解码一条 WebSocket 消息。
+    ///
+    /// Binary messages are always `message` packets.
+    /// 二进制消息总是 `message` 数据包。
+    pub fn decode(frame: Frame) -> Result<Self, ProtocolError> {
+        match frame {
+            Frame::Binary(bytes) => Ok(EnginePacket::Message(Frame::Binary(bytes))),
+            Frame::Text(text) => EnginePacket::decode_text(&text),
+        }
+    }
+
+    /// Encodes packets as one long-polling payload.
+    /// 将多个数据包编码为一次长轮询负载。
+    ///
+    /// Binary messages become `b<base64>`.
+    /// 二进制消息编码为 `b<base64>`。
+    pub fn encode_payload(packets: &[EnginePacket]) -> String {
+        let mut payload = String::new();
+        for (index, packet) in packets.iter().enumerate() {
+            if index > 0 {
+                payload.push(RECORD_SEPARATOR);
+            }
+            match packet {
+                EnginePacket::Message(Frame::Binary(bytes)) => {
+                    payload.push('b');
+                    payload.push_str(&BASE64.encode(bytes));
+                }
+                other => payload.push_str(&other.encode_text()),
+            }
+        }
+        payload
+    }
+
+    /// Decodes one long-polling payload.
+    /// 解码一次长轮询负载。
+    pub fn decode_payload(payload: &str) -> Result<Vec<Self>, ProtocolError> {
+        if payload.is_empty() {
+            return Err(ProtocolError::Empty);
+        }
+        payload
+            .split(RECORD_SEPARATOR)
+            .map(|record| match record.strip_prefix('b') {
+                Some(encoded) => Ok(EnginePacket::Message(Frame::Binary(BASE64.decode(encoded)?))),
+                None => EnginePacket::decode_text(record),
+            })
+            .collect()
+    }
+
+    fn encode_text(&self) -> String {
+        match self {
+            EnginePacket::Open(handshake) => {
+                let json = serde_json::to_string(handshake).expect("handshake is always serializable");
+                format!("0{json}")
+            }
+            EnginePacket::Close => "1".to_owned(),
+            EnginePacket::Ping(data) => format!("2{}", data.as_deref().unwrap_or_default()),
+            EnginePacket::Pong(data) => format!("3{}", data.as_deref().unwrap_or_default()),
+            EnginePacket::Message(Frame::Text(text)) => format!("4{text}"),
+            EnginePacket::Message(Frame::Binary(bytes)) => format!("b{}", BASE64.encode(bytes)),
+            EnginePacket::Upgrade => "5".to_owned(),
+            EnginePacket::Noop => "6".to_owned(),
+        }
+    }
+
+    fn decode_text(text: &str) -> Result<Self, ProtocolError> {
+        let mut chars = text.chars();
+        let kind = chars.next().ok_or(ProtocolError::Empty)?;
+        let rest = chars.as_str();
+        let data = || (!rest.is_empty()).then(|| rest.to_owned());
+        match kind {
+            '0' => Ok(EnginePacket::Open(serde_json::from_str(rest)?)),
+            '1' => Ok(EnginePacket::Close),
+            '2' => Ok(EnginePacket::Ping(data())),
+            '3' => Ok(EnginePacket::Pong(data())),
+            '4' => Ok(EnginePacket::Message(Frame::Text(rest.to_owned()))),
+            '5' => Ok(EnginePacket::Upgrade),
+            '6' => Ok(EnginePacket::Noop),
+            other => Err(ProtocolError::UnknownPacketType(other)),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/// One unit handed to or received from a transport.
+/// 与传输层交换的单个数据单元。
+///
+/// For WebSocket this is one message; for long-polling, one request body.
+/// 对 WebSocket 而言是一条消息；对长轮询而言是一次请求体。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl From<String> for Frame {
+    fn from(text: String) -> Self {
+        Frame::Text(text)
+    }
+}
+
+impl From<&str> for Frame {
+    fn from(text: &str) -> Self {
+        Frame::Text(text.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for Frame {
+    fn from(bytes: Vec<u8>) -> Self {
+        Frame::Binary(bytes)
+    }
+}
@@ -0,0 +1,35 @@
+//! Engine.IO v4 / Socket.IO v5 wire codec.
+//! Engine.IO v4 / Socket.IO v5 协议编解码。
+//!
+//! Transport-agnostic: it turns packets into [`frame::Frame`]s (one WebSocket
+//! message, or one HTTP long-polling payload) and back. Connecting, pinging
+//! and reconnecting are left to the caller.
+//! 与传输层无关：只负责数据包与帧之间的转换，连接、心跳与重连由调用方处理。
+//!
+//! [`chat_stream_decoder`] decodes the streamed REST chat response.
+//! [`chat_stream_decoder`] 用于解码 REST 聊天接口的流式响应。
+
+pub mod chat_stream_decoder;
+pub mod engine_handshake;
+pub mod engine_packet;
+pub mod frame;
+pub mod protocol_error;
+pub mod socket_decoder;
+pub mod socket_packet;
+
+/// Engine.IO protocol revision spoken by the Atlas gateways (`EIO=4`).
+/// Atlas 网关使用的 Engine.IO 协议版本（`EIO=4`）。
+pub const ENGINE_IO_VERSION: u8 = 4;
+
+/// Default Engine.IO endpoint path.
+/// 默认的 Engine.IO 端点路径。
+pub const ENGINE_IO_PATH: &str = "/socket.io/";
+
+/// Namespace of `ChatGateway`.
+pub const CHAT_NAMESPACE: &str = "/api/chat";
+
+/// Namespace of `CollaborationGateway`.
+pub const COLLABORATION_NAMESPACE: &str = "/api/collaboration";
+
+/// Namespace of `ActivityGateway`.
+pub const ACTIVITY_NAMESPACE: &str = "/activity";
@@ -0,0 +1,63 @@
+use std::error::Error;
+use std::fmt;
+
+/// Malformed Engine.IO or Socket.IO input.
+/// 格式错误的 Engine.IO 或 Socket.IO 数据。
+#[derive(Debug)]
+pub enum ProtocolError {
+    /// The frame or packet was empty.
+    Empty,
+    /// The leading packet type is not defined by the protocol.
+    UnknownPacketType(char),
+    /// A binary frame arrived where a text packet was expected, or vice versa.
+    UnexpectedFrame,
+    /// The `<n>-` attachment count or the ack id is not a valid number.
+    InvalidNumber(String),
+    /// The packet payload is not valid JSON.
+    InvalidJson(serde_json::Error),
+    /// The payload has the wrong shape for its packet type.
+    InvalidPayload(&'static str),
+    /// A `b`-prefixed polling packet is not valid base64.
+    InvalidBase64(base64::DecodeError),
+    /// A binary packet was decoded without its attachments.
+    MissingAttachments(usize),
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtocolError::Empty => write!(f, "empty packet"),
+            ProtocolError::UnknownPacketType(kind) => write!(f, "unknown packet type {kind:?}"),
+            ProtocolError::UnexpectedFrame => write!(f, "unexpected frame kind"),
+            ProtocolError::InvalidNumber(raw) => write!(f, "invalid number {raw:?}"),
+            ProtocolError::InvalidJson(err) => write!(f, "invalid packet payload: {err}"),
+            ProtocolError::InvalidPayload(reason) => write!(f, "invalid packet payload: {reason}"),
+            ProtocolError::InvalidBase64(err) => write!(f, "invalid base64 packet: {err}"),
+            ProtocolError::MissingAttachments(count) => {
+                write!(f, "binary packet is missing {count} attachment(s)")
+            }
+        }
+    }
+}
+
+impl Error for ProtocolError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProtocolError::InvalidJson(err) => Some(err),
+            ProtocolError::InvalidBase64(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ProtocolError {
+    fn from(err: serde_json::Error) -> Self {
+        ProtocolError::InvalidJson(err)
+    }
+}
+
+impl From<base64::DecodeError> for ProtocolError {
+    fn from(err: base64::DecodeError) -> Self {
+        ProtocolError::InvalidBase64(err)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::protocol::frame::Frame;
+use crate::protocol::protocol_error::ProtocolError;
+use crate::protocol::socket_packet::SocketPacket;
+
+/// Reassembles Socket.IO packets from Engine.IO `message` payloads.
+/// 从 Engine.IO `message` 负载中重组 Socket.IO 数据包。
+///
+/// Text payloads decode directly; a `BINARY_EVENT`/`BINARY_ACK` header is
+/// held until its attachments arrive as binary payloads. A text payload that
+/// arrives first drops the unfinished packet and is decoded as usual.
+/// 文本负载直接解码；二进制数据包的头部会保留，直到所有附件到达。若附件到齐前收到
+/// 文本负载，则丢弃未完成的数据包并照常解码该文本。
+#[derive(Debug, Default)]
+pub struct SocketDecoder {
+    pending: Option<(SocketPacket, usize)>,
+}
+
+impl SocketDecoder {
+    pub fn new() -> Self {
+        SocketDecoder::default()
+    }
+
+    /// Feeds the payload of one Engine.IO `message` packet. Returns a packet
+    /// once it is complete.
+    /// 输入一个 Engine.IO `message` 数据包的负载，数据包完整时返回。
+    pub fn feed(&mut self, payload: Frame) -> Result<Option<SocketPacket>, ProtocolError> {
+        match payload {
+            Frame::Text(text) => {
+                // A text packet ends any unfinished binary packet; the server
+                // has moved on, so the stale one is dropped.
+                self.pending = None;
+                match SocketPacket::parse(&text)? {
+                    (packet, 0) => Ok(Some(packet)),
+                    (packet, expected) => {
+                        self.pending = Some((packet, expected));
+                        Ok(None)
+                    }
+                }
+            }
+            Frame::Binary(bytes) => {
+                let (mut packet, expected) = self.pending.take().ok_or(ProtocolError::UnexpectedFrame)?;
+                if let SocketPacket::BinaryEvent { attachments, .. } | SocketPacket::BinaryAck { attachments, .. } =
+                    &mut packet
+                {
+                    attachments.push(bytes);
+                    if attachments.len() == expected {
+                        return Ok(Some(packet));
+                    }
+                }
+                self.pending = Some((packet, expected));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Whether a binary packet is waiting for attachments.
+    /// 是否有二进制数据包正在等待附件。
+    pub fn is_reconstructing(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Drops a partially received binary packet, e.g. after a reconnect.
+    /// 丢弃未接收完整的二进制数据包（例如重连后）。
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+}
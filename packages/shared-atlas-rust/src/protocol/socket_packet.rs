@@ -0,0 +1,285 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::protocol::engine_packet::EnginePacket;
+use crate::protocol::frame::Frame;
+use crate::protocol::protocol_error::ProtocolError;
+
+/// Default (main) namespace.
+/// 默认（主）命名空间。
+pub const DEFAULT_NAMESPACE: &str = "/";
+
+/// A Socket.IO v5 packet.
+/// Socket.IO v5 数据包。
+///
+/// Text form: `<type>[<attachments>-][<namespace>,][<ack id>][<JSON data>]`,
+/// e.g. `2/api/chat,["chat:send",{...}]`. The namespace is omitted for `/`.
+/// 文本格式如上，命名空间为 `/` 时省略。
+#[derive(Debug, Clone, PartialEq)]
+pub enum SocketPacket {
+/// `0` - join a namespace. The client sends its auth payload
+/// (e.g. `{"token":"..."}`), the server answers with `{"sid":"..."}`.
+    Connect {
+        namespace: String,
+        data: Option<Value>,
+    },
+/// `1` - leave a namespace.
+    Disconnect {
+        namespace: String,
+    },
+/// `2` - an event: `["name", ...args]`. `id` requests an acknowledgement.
+    Event {
+        namespace: String,
+        id: Option<u64>,
+        data: Vec<Value>,
+    },
+/// `3` - acknowledgement of the event with the same `id`.
+    Ack {
+        namespace: String,
+        id: u64,
+        data: Vec<Value>,
+    },
+/// `4` - the server refused the namespace connection, e.g.
+/// `{"message":"Authentication error: Invalid token"}`.
+    ConnectError {
+        namespace: String,
+        data: Value,
+    },
+/// `5` - an event with binary arguments. `data` holds
+/// `{"_placeholder":true,"num":N}` objects pointing into `attachments`.
+    BinaryEvent {
+        namespace: String,
+        id: Option<u64>,
+        data: Vec<Value>,
+        attachments: Vec<Vec<u8>>,
+    },
+/// `6` - an acknowledgement with binary arguments.
+    BinaryAck {
+        namespace: String,
+        id: u64,
+        data: Vec<Value>,
+        attachments: Vec<Vec<u8>>,
+    },
+}
+
+impl SocketPacket {
+    /// `CONNECT` to `namespace`, optionally with an auth payload.
+    /// 连接到 `namespace`，可附带认证信息。
+    pub fn connect(namespace: &str, auth: Option<Value>) -> Self {
+        SocketPacket::Connect { namespace: namespace.to_owned(), data: auth }
+    }
+
+    /// `EVENT` from anything that serializes to `["name", ...args]`,
+    /// such as `AtlasClientEvent`.
+    /// 由可序列化为 `["name", ...args]` 的值（如 `AtlasClientEvent`）构建 `EVENT`。
+    pub fn event<E: Serialize>(namespace: &str, id: Option<u64>, event: &E) -> Result<Self, ProtocolError> {
+        match serde_json::to_value(event)? {
+            Value::Array(data) if is_event_array(&data) => {
+                Ok(SocketPacket::Event { namespace: namespace.to_owned(), id, data })
+            }
+            _ => Err(ProtocolError::InvalidPayload("event must be a [name, ...args] array")),
+        }
+    }
+
+    /// `ACK` for the event with `id`.
+    /// 为 `id` 对应的事件构建 `ACK`。
+    pub fn ack(namespace: &str, id: u64, data: Vec<Value>) -> Self {
+        SocketPacket::Ack { namespace: namespace.to_owned(), id, data }
+    }
+
+    /// The `{"_placeholder":true,"num":N}` marker for a binary attachment.
+    /// 二进制附件的占位对象。
+    pub fn placeholder(num: usize) -> Value {
+        serde_json::json!({ "_placeholder": true, "num": num })
+    }
+
+    pub fn namespace(&self) -> &str {
+        match self {
+            SocketPacket::Connect { namespace, .. }
+            | SocketPacket::Disconnect { namespace }
+            | SocketPacket::Event { namespace, .. }
+            | SocketPacket::Ack { namespace, .. }
+            | SocketPacket::ConnectError { namespace, .. }
+            | SocketPacket::BinaryEvent { namespace, .. }
+            | SocketPacket::BinaryAck { namespace, .. } => namespace,
+        }
+    }
+
+    /// Numeric packet type (`0`..=`6`).
+    /// 数据包类型数字（`0`..=`6`）。
+    pub fn packet_type(&self) -> u8 {
+        match self {
+            SocketPacket::Connect { .. } => 0,
+            SocketPacket::Disconnect { .. } => 1,
+            SocketPacket::Event { .. } => 2,
+            SocketPacket::Ack { .. } => 3,
+            SocketPacket::ConnectError { .. } => 4,
+            SocketPacket::BinaryEvent { .. } => 5,
+            SocketPacket::BinaryAck { .. } => 6,
+        }
+    }
+
+    /// Binary attachments of `BINARY_EVENT` / `BINARY_ACK`; empty otherwise.
+    /// `BINARY_EVENT` / `BINARY_ACK` 的二进制附件，其他类型为空。
+    pub fn attachments(&self) -> &[Vec<u8>] {
+        match self {
+            SocketPacket::BinaryEvent { attachments, .. } | SocketPacket::BinaryAck { attachments, .. } => {
+                attachments
+            }
+            _ => &[],
+        }
+    }
+
+    /// Encodes the text part of the packet.
+    /// 编码数据包的文本部分。
+    pub fn encode(&self) -> String {
+        let mut out = self.packet_type().to_string();
+        let attachments = self.attachments();
+        if matches!(self, SocketPacket::BinaryEvent { .. } | SocketPacket::BinaryAck { .. }) {
+            out.push_str(&attachments.len().to_string());
+            out.push('-');
+        }
+        let namespace = self.namespace();
+        if namespace != DEFAULT_NAMESPACE {
+            out.push_str(namespace);
+            out.push(',');
+        }
+        let (id, data) = match self {
+            SocketPacket::Connect { data, .. } => (None, data.clone()),
+            SocketPacket::Disconnect { .. } => (None, None),
+            SocketPacket::Event { id, data, .. } | SocketPacket::BinaryEvent { id, data, .. } => {
+                (*id, Some(Value::Array(data.clone())))
+            }
+            SocketPacket::Ack { id, data, .. } | SocketPacket::BinaryAck { id, data, .. } => {
+                (Some(*id), Some(Value::Array(data.clone())))
+            }
+            SocketPacket::ConnectError { data, .. } => (None, Some(data.clone())),
+        };
+        if let Some(id) = id {
+            out.push_str(&id.to_string());
+        }
+        if let Some(data) = data {
+            out.push_str(&data.to_string());
+        }
+        out
+    }
+
+    /// Encodes the packet as Engine.IO `message` packets: the text part
+    /// followed by one binary message per attachment.
+    /// 编码为 Engine.IO `message` 数据包：文本部分加上每个附件一个二进制消息。
+    pub fn to_engine_packets(&self) -> Vec<EnginePacket> {
+        let mut packets = vec![EnginePacket::Message(Frame::Text(self.encode()))];
+        packets.extend(
+            self.attachments()
+                .iter()
+                .map(|bytes| EnginePacket::Message(Frame::Binary(bytes.clone()))),
+        );
+        packets
+    }
+
+    /// Decodes a packet that has no binary attachments.
+    /// 解码不含二进制附件的数据包。
+    ///
+    /// Use `SocketDecoder` for streams that may carry `BINARY_EVENT`s.
+    /// 可能包含 `BINARY_EVENT` 的数据流请使用 `SocketDecoder`。
+    pub fn decode(text: &str) -> Result<Self, ProtocolError> {
+        match SocketPacket::parse(text)? {
+            (packet, 0) => Ok(packet),
+            (_, missing) => Err(ProtocolError::MissingAttachments(missing)),
+        }
+    }
+
+    /// Parses the text part; returns the packet and how many binary
+    /// attachments must follow.
+    pub(crate) fn parse(text: &str) -> Result<(Self, usize), ProtocolError> {
+        let kind = text.chars().next().ok_or(ProtocolError::Empty)?;
+        if !matches!(kind, '0'..='6') {
+            return Err(ProtocolError::UnknownPacketType(kind));
+        }
+        let kind = kind as u8;
+        let bytes = text.as_bytes();
+        let mut i = 1;
+
+        let mut expected = 0;
+        if kind == b'5' || kind == b'6' {
+            let dash = text[i..]
+                .find('-')
+                .map(|offset| i + offset)
+                .ok_or_else(|| ProtocolError::InvalidNumber(text.to_owned()))?;
+            let raw = &text[i..dash];
+            expected = raw.parse().map_err(|_| ProtocolError::InvalidNumber(raw.to_owned()))?;
+            i = dash + 1;
+        }
+
+        let mut namespace = DEFAULT_NAMESPACE.to_owned();
+        if bytes.get(i) == Some(&b'/') {
+            let end = text[i..].find(',').map_or(text.len(), |offset| i + offset);
+            namespace = text[i..end].to_owned();
+            i = (end + 1).min(text.len());
+        }
+
+        let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let id = if digits > 0 {
+            let raw = &text[i..i + digits];
+            i += digits;
+            Some(raw.parse::<u64>().map_err(|_| ProtocolError::InvalidNumber(raw.to_owned()))?)
+        } else {
+            None
+        };
+
+        let data: Option<Value> = if i < text.len() { Some(serde_json::from_str(&text[i..])?) } else { None };
+
+        let packet = match kind {
+            b'0' => match data {
+                None | Some(Value::Object(_)) => SocketPacket::Connect { namespace, data },
+                Some(_) => return Err(ProtocolError::InvalidPayload("CONNECT payload must be an object")),
+            },
+            b'1' => match data {
+                None => SocketPacket::Disconnect { namespace },
+                Some(_) => return Err(ProtocolError::InvalidPayload("DISCONNECT carries no payload")),
+            },
+            b'2' => SocketPacket::Event { namespace, id, data: event_array(data)? },
+            b'3' => SocketPacket::Ack { namespace, id: ack_id(id)?, data: ack_array(data)? },
+            b'4' => match data {
+                Some(data @ (Value::Object(_) | Value::String(_))) => SocketPacket::ConnectError { namespace, data },
+                _ => return Err(ProtocolError::InvalidPayload("CONNECT_ERROR payload must be an object")),
+            },
+            b'5' => SocketPacket::BinaryEvent {
+                namespace,
+                id,
+                data: event_array(data)?,
+                attachments: Vec::new(),
+            },
+            b'6' => SocketPacket::BinaryAck {
+                namespace,
+                id: ack_id(id)?,
+                data: ack_array(data)?,
+                attachments: Vec::new(),
+            },
+            other => return Err(ProtocolError::UnknownPacketType(other as char)),
+        };
+        Ok((packet, expected))
+    }
+}
+
+fn is_event_array(data: &[Value]) -> bool {
+    matches!(data.first(), Some(Value::String(_)) | Some(Value::Number(_)))
+}
+
+fn event_array(data: Option<Value>) -> Result<Vec<Value>, ProtocolError> {
+    match data {
+        Some(Value::Array(data)) if is_event_array(&data) => Ok(data),
+        _ => Err(ProtocolError::InvalidPayload("EVENT payload must be a [name, ...args] array")),
+    }
+}
+
+fn ack_array(data: Option<Value>) -> Result<Vec<Value>, ProtocolError> {
+    match data {
+        Some(Value::Array(data)) => Ok(data),
+        _ => Err(ProtocolError::InvalidPayload("ACK payload must be an array")),
+    }
+}
+
+fn ack_id(id: Option<u64>) -> Result<u64, ProtocolError> {
+    id.ok_or(ProtocolError::InvalidPayload("ACK packet without id"))
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+/// A request was refused by the [`SafetyCeiling`](crate::scheduler::safety_ceiling::SafetyCeiling)
+/// and not sent.
+/// 请求被 `SafetyCeiling` 拒绝，未发送。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeilingReached {
+    /// Time until the oldest counted request leaves the window.
+    /// 距最早计入的请求移出窗口的时间。
+    pub retry_in: Duration,
+}
+
+impl fmt::Display for CeilingReached {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request ceiling reached, retry in {:?}", self.retry_in)
+    }
+}
+
+impl Error for CeilingReached {}
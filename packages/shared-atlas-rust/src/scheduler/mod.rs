@@ -0,0 +1,7 @@
+pub mod ceiling_reached;
+pub mod rate_limit;
+pub mod request_scheduler;
+pub mod safety_ceiling;
+pub mod scheduler_options;
+pub mod throttled;
+pub(crate) mod token_bucket;
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+/// A token-bucket limit: `points` requests per `per`, refilled continuously,
+/// with up to `burst` sent back to back.
+/// 令牌桶限流：每 `per` 时间 `points` 个请求，持续补充，最多连续发送 `burst` 个。
+///
+/// Mirrors the server's `@RateLimit(points, duration)`. The server counts
+/// fixed windows, so a full burst followed by the steady rate can still
+/// exceed a window; lower `burst` for routes where a 429 is costly.
+/// 对应服务端的 `@RateLimit(points, duration)`。服务端按固定窗口计数，
+/// 满额突发后再按稳定速率发送仍可能超出窗口；429 代价高的路由应调低 `burst`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub points: u32,
+    pub per: Duration,
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// `points` per `per`, with a burst of `points`.
+    /// 每 `per` 时间 `points` 个请求，突发上限为 `points`。
+    pub fn new(points: u32, per: Duration) -> Self {
+        RateLimit { points: points.max(1), per, burst: points.max(1) }
+    }
+
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Tokens added per second.
+    /// 每秒补充的令牌数。
+    pub fn rate(&self) -> f64 {
+        f64::from(self.points) / self.per.as_secs_f64().max(f64::EPSILON)
+    }
+}
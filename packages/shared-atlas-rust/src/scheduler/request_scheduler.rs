@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{Mutex as AsyncMutex, Semaphore};
+use tokio::time::Instant;
+
+use crate::scheduler::ceiling_reached::CeilingReached;
+use crate::scheduler::rate_limit::RateLimit;
+use crate::scheduler::safety_ceiling::SafetyCeiling;
+use crate::scheduler::scheduler_options::SchedulerOptions;
+use crate::scheduler::throttled::Throttled;
+use crate::scheduler::token_bucket::TokenBucket;
+
+/// Longest pause honoured after a 429, whatever `Retry-After` says.
+/// 收到 429 后最长的暂停时间，无论 `Retry-After` 为何值。
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// Client-side throttling for Atlas requests, so batch jobs stay under the
+/// server's rate limits instead of being blocked.
+/// Atlas 请求的客户端限流，使批量任务保持在服务端限制之内而不被封禁。
+///
+/// Wrap each request in [`RequestScheduler::schedule`]; it works with any
+/// HTTP stack whose result implements [`Throttled`]. Clones share state.
+/// 用 [`RequestScheduler::schedule`] 包装每个请求；适用于结果实现了
+/// [`Throttled`] 的任意 HTTP 库。克隆共享状态。
+///
+/// - Requests wait, in order, for a token of their route's bucket.
+/// - A 429 empties the bucket until `Retry-After` (or one window of the
+///   route's limit) has passed, for at most an hour, and the request is
+///   sent again.
+/// - At most `max_queued` requests wait; further callers wait for a slot.
+/// - Requests over the [`SafetyCeiling`] are refused, not delayed.
+/// - 请求按顺序等待所属路由令牌桶的令牌。
+/// - 收到 429 时清空令牌桶，直到 `Retry-After`（或该路由的一个限流窗口）过去（最多一小时）后重发。
+/// - 最多 `max_queued` 个请求排队，其余调用方等待空位。
+/// - 超过 [`SafetyCeiling`] 的请求直接拒绝而非延迟。
+#[derive(Clone)]
+pub struct RequestScheduler {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    default_route: Route,
+    routes: Vec<(String, Route)>,
+    ceiling: SafetyCeiling,
+    sent: Mutex<VecDeque<Instant>>,
+    queue: Semaphore,
+    max_queued: usize,
+    max_retries: u32,
+}
+
+struct Route {
+    limit: RateLimit,
+    bucket: AsyncMutex<TokenBucket>,
+}
+
+impl Route {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Route { limit, bucket: AsyncMutex::new(TokenBucket::new(limit, now)) }
+    }
+
+    /// Waits for a token; the bucket lock keeps waiters in order.
+    async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        while let Err(wait) = bucket.try_take(Instant::now()) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl RequestScheduler {
+    pub fn new(options: SchedulerOptions) -> Self {
+        let now = Instant::now();
+        let mut routes: Vec<(String, Route)> =
+            options.routes.into_iter().map(|(prefix, limit)| (prefix, Route::new(limit, now))).collect();
+        // Longest prefix first, so the first match is the most specific.
+        routes.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        RequestScheduler {
+            inner: Arc::new(Inner {
+                default_route: Route::new(options.default_limit, now),
+                routes,
+                ceiling: options.ceiling,
+                sent: Mutex::new(VecDeque::new()),
+                queue: Semaphore::new(options.max_queued),
+                max_queued: options.max_queued,
+                max_retries: options.max_retries,
+            }),
+        }
+    }
+
+    /// Runs `request` for `path` (e.g. `/notes/n1`, a query string is
+    /// ignored) once a token is available, sending it again after a 429 up
+    /// to `max_retries` times. The last outcome is returned as is.
+    /// 在有令牌时为 `path`（例如 `/notes/n1`，忽略查询串）执行 `request`，
+    /// 收到 429 后最多重发 `max_retries` 次，并原样返回最后一次结果。
+    pub async fn schedule<F, Fut, O>(&self, path: &str, mut request: F) -> Result<O, CeilingReached>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = O>,
+        O: Throttled,
+    {
+        let _slot = self.inner.queue.acquire().await.expect("scheduler queue is never closed");
+        let route = self.inner.route(path);
+        let mut attempt = 0;
+        loop {
+            route.acquire().await;
+            self.inner.count_sent()?;
+            let outcome = request().await;
+            if !outcome.is_throttled() || attempt == self.inner.max_retries {
+                return Ok(outcome);
+            }
+            attempt += 1;
+            let wait = outcome.retry_after().unwrap_or(route.limit.per).min(MAX_RETRY_AFTER);
+            let now = Instant::now();
+            route.bucket.lock().await.pause_until(now.checked_add(wait).unwrap_or(now));
+        }
+    }
+
+    /// Requests currently queued or in flight.
+    /// 当前排队或执行中的请求数。
+    pub fn queued(&self) -> usize {
+        self.inner.max_queued - self.inner.queue.available_permits()
+    }
+}
+
+impl Inner {
+    fn route(&self, path: &str) -> &Route {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        self.routes
+            .iter()
+            .find(|(prefix, _)| {
+                let prefix = prefix.trim_end_matches('/');
+                path.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .map_or(&self.default_route, |(_, route)| route)
+    }
+
+    /// Records a send, or refuses it if the ceiling's window is full.
+    fn count_sent(&self) -> Result<(), CeilingReached> {
+        let now = Instant::now();
+        let mut sent = self.sent.lock().unwrap();
+        while sent.front().is_some_and(|at| now.duration_since(*at) >= self.ceiling.window) {
+            sent.pop_front();
+        }
+        if sent.len() >= self.ceiling.max_requests as usize {
+            let oldest = sent.front().copied().unwrap_or(now);
+            return Err(CeilingReached { retry_in: (oldest + self.ceiling.window).saturating_duration_since(now) });
+        }
+        sent.push_back(now);
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for RequestScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestScheduler")
+            .field("routes", &self.inner.routes.iter().map(|(prefix, _)| prefix).collect::<Vec<_>>())
+            .field("ceiling", &self.inner.ceiling)
+            .field("queued", &self.queued())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+/// Requests after which the server blocks the caller for an hour
+/// (`RateLimitService.BLOCK_THRESHOLD`).
+/// 服务端封禁调用方一小时的请求数阈值（`RateLimitService.BLOCK_THRESHOLD`）。
+pub const BLOCK_THRESHOLD: u32 = 200;
+
+/// A hard cap on requests sent in any sliding `window`, across all routes.
+/// 任意滑动 `window` 内（所有路由合计）发送请求数的硬上限。
+///
+/// Unlike a [`RateLimit`](crate::scheduler::rate_limit::RateLimit), which
+/// delays requests, a request over the ceiling is refused with
+/// [`CeilingReached`](crate::scheduler::ceiling_reached::CeilingReached), so a
+/// runaway caller stops before the server's block threshold.
+/// 与会延迟请求的 `RateLimit` 不同，超过上限的请求直接以 `CeilingReached` 拒绝，
+/// 使失控的调用方在触及服务端封禁阈值前停下。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyCeiling {
+    pub max_requests: u32,
+    pub window: Duration,
+}
+
+impl SafetyCeiling {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        SafetyCeiling { max_requests, window }
+    }
+}
+
+impl Default for SafetyCeiling {
+    /// 180 requests per minute, a margin below [`BLOCK_THRESHOLD`].
+    /// 每分钟 180 个请求，低于 [`BLOCK_THRESHOLD`] 并留有余量。
+    fn default() -> Self {
+        SafetyCeiling::new(BLOCK_THRESHOLD - 20, Duration::from_secs(60))
+    }
+}
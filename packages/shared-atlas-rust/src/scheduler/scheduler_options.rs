@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use crate::scheduler::rate_limit::RateLimit;
+use crate::scheduler::safety_ceiling::SafetyCeiling;
+
+/// Settings of a [`RequestScheduler`](crate::scheduler::request_scheduler::RequestScheduler).
+/// `RequestScheduler` 的设置。
+///
+/// The defaults follow the server: 60 requests per minute (the
+/// `ThrottlerModule` limit) and 5 per minute for `/auth/login`.
+/// 默认值与服务端一致：每分钟 60 个请求（`ThrottlerModule` 限制），
+/// `/auth/login` 每分钟 5 个。
+#[derive(Debug, Clone)]
+pub struct SchedulerOptions {
+    /// Limit shared by all requests that match no route.
+    /// 未匹配任何路由的请求共用的限制。
+    pub default_limit: RateLimit,
+    /// Path prefixes with their own limit; the longest match wins.
+    /// 拥有独立限制的路径前缀，取最长匹配。
+    pub routes: Vec<(String, RateLimit)>,
+    pub ceiling: SafetyCeiling,
+    /// Requests allowed to wait for a token; further callers wait for a
+    /// free slot before queueing.
+    /// 允许等待令牌的请求数；更多调用方需先等待空位再排队。
+    pub max_queued: usize,
+    /// Times a request rejected with 429 is sent again.
+    /// 被 429 拒绝的请求的重发次数。
+    pub max_retries: u32,
+}
+
+impl SchedulerOptions {
+    pub fn new(default_limit: RateLimit) -> Self {
+        SchedulerOptions {
+            default_limit,
+            routes: Vec::new(),
+            ceiling: SafetyCeiling::default(),
+            max_queued: 64,
+            max_retries: 3,
+        }
+    }
+
+    /// Gives requests under `prefix` (e.g. `/auth/login`) their own bucket.
+    /// 为 `prefix`（例如 `/auth/login`）下的请求设置独立的令牌桶。
+    pub fn with_route(mut self, prefix: impl Into<String>, limit: RateLimit) -> Self {
+        let prefix = prefix.into();
+        self.routes.retain(|(known, _)| *known != prefix);
+        self.routes.push((prefix, limit));
+        self
+    }
+
+    pub fn with_ceiling(mut self, ceiling: SafetyCeiling) -> Self {
+        self.ceiling = ceiling;
+        self
+    }
+
+    pub fn with_max_queued(mut self, max_queued: usize) -> Self {
+        self.max_queued = max_queued.max(1);
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+impl Default for SchedulerOptions {
+    fn default() -> Self {
+        let minute = Duration::from_secs(60);
+        SchedulerOptions::new(RateLimit::new(60, minute)).with_route("/auth/login", RateLimit::new(5, minute))
+    }
+}
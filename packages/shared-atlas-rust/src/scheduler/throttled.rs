@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use crate::error::api_error::ApiError;
+
+/// Outcome of a request that may have been rejected with 429.
+/// 可能被 429 拒绝的请求结果。
+///
+/// Implemented for [`ApiError`] and, with the `client` feature, for
+/// `ClientError` and `reqwest::Response`; other HTTP stacks implement it for
+/// their own error or response type.
+/// 已为 `ApiError` 实现，启用 `client` 特性时也为 `ClientError` 与
+/// `reqwest::Response` 实现；其他 HTTP 库可为自己的错误或响应类型实现。
+pub trait Throttled {
+    fn is_throttled(&self) -> bool;
+
+    /// The `Retry-After` delay, if the server sent one.
+    /// 服务端发送的 `Retry-After` 等待时间。
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl<T, E: Throttled> Throttled for Result<T, E> {
+    fn is_throttled(&self) -> bool {
+        matches!(self, Err(err) if err.is_throttled())
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        self.as_ref().err().and_then(Throttled::retry_after)
+    }
+}
+
+impl Throttled for ApiError {
+    fn is_throttled(&self) -> bool {
+        matches!(self, ApiError::RateLimited { .. })
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ApiError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl Throttled for crate::client::client_error::ClientError {
+    fn is_throttled(&self) -> bool {
+        matches!(self, crate::client::client_error::ClientError::Api(err) if err.is_throttled())
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            crate::client::client_error::ClientError::Api(err) => err.retry_after(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl Throttled for reqwest::Response {
+    fn is_throttled(&self) -> bool {
+        self.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        let value = self.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+        ApiError::parse_retry_after(value)
+    }
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::scheduler::rate_limit::RateLimit;
+
+/// Token state of one route.
+pub(crate) struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl TokenBucket {
+    /// A full bucket.
+    pub(crate) fn new(limit: RateLimit, now: Instant) -> Self {
+        TokenBucket { limit, tokens: f64::from(limit.burst), updated: now, paused_until: None }
+    }
+
+    /// Takes a token, or returns how long until one is available.
+    pub(crate) fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        if let Some(until) = self.paused_until {
+            if now < until {
+                return Err(until - now);
+            }
+            self.paused_until = None;
+            self.updated = until;
+        }
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.rate()).min(f64::from(self.limit.burst));
+        self.updated = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.limit.rate()))
+        }
+    }
+
+    /// Empties the bucket and hands out nothing before `until`, after a 429.
+    pub(crate) fn pause_until(&mut self, until: Instant) {
+        self.tokens = 0.0;
+        self.paused_until = Some(self.paused_until.map_or(until, |paused| paused.max(until)));
+    }
+}
@@ -0,0 +1,63 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_util::Stream;
+use tokio::sync::mpsc;
+
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::socket::chat_socket::ChatRouter;
+
+/// The `chat:stream` response to one `chat:send`.
+/// 一次 `chat:send` 对应的 `chat:stream` 回复流。
+///
+/// Yields events until (and including) `done` or `error`. Dropping it early
+/// stops delivery; the gateway has no cancel event, so the rest of the
+/// response is discarded as it arrives.
+/// 产出事件直到 `done` 或 `error`（包含该事件）。提前丢弃会停止接收；
+/// 网关没有取消事件，剩余回复到达时会被丢弃。
+pub struct ChatEventStream {
+    id: u64,
+    rx: mpsc::UnboundedReceiver<ChatStreamEvent>,
+    router: Arc<ChatRouter>,
+    finished: bool,
+}
+
+impl ChatEventStream {
+    pub(crate) fn new(id: u64, rx: mpsc::UnboundedReceiver<ChatStreamEvent>, router: Arc<ChatRouter>) -> Self {
+        ChatEventStream { id, rx, router, finished: false }
+    }
+}
+
+impl Stream for ChatEventStream {
+    type Item = ChatStreamEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<ChatStreamEvent>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+        match self.rx.poll_recv(cx) {
+            Poll::Ready(Some(event)) => {
+                self.finished = event.is_terminal();
+                Poll::Ready(Some(event))
+            }
+            Poll::Ready(None) => {
+                self.finished = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for ChatEventStream {
+    fn drop(&mut self) {
+        self.router.detach(self.id, self.finished);
+    }
+}
+
+impl std::fmt::Debug for ChatEventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChatEventStream").field("id", &self.id).field("finished", &self.finished).finish()
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{mpsc, Notify};
+use tokio::time;
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::events::atlas_server_event::AtlasServerEvent;
+use crate::interfaces::chat_send_payload::ChatSendPayload;
+use crate::interfaces::chat_stream_event::ChatStreamEvent;
+use crate::protocol::CHAT_NAMESPACE;
+use crate::socket::chat_event_stream::ChatEventStream;
+use crate::socket::connection::{self, Dispatch};
+use crate::socket::socket_error::SocketError;
+use crate::socket::socket_handle::SocketHandle;
+use crate::socket::socket_options::SocketOptions;
+
+/// Client for the `/api/chat` gateway.
+/// `/api/chat` 网关客户端。
+///
+/// `chat:stream` events carry no session id, so one response is streamed at a
+/// time: [`send`](ChatSocket::send) fails with `StreamInProgress` while a
+/// previous [`ChatEventStream`] is still alive.
+/// `chat:stream` 事件不带会话 ID，因此同一时间只能接收一个回复。
+pub struct ChatSocket {
+    handle: SocketHandle,
+    router: Arc<ChatRouter>,
+    drain_timeout: Duration,
+}
+
+impl ChatSocket {
+    /// Connects and authenticates with `options.credentials`.
+    /// 使用 `options.credentials` 连接并认证。
+    pub async fn connect(options: SocketOptions) -> Result<Self, SocketError> {
+        let router = Arc::new(ChatRouter::default());
+        let drain_timeout = options.drain_timeout;
+        let handle = connection::open(options, CHAT_NAMESPACE, router.clone()).await?;
+        Ok(ChatSocket { handle, router, drain_timeout })
+    }
+
+    /// Sends `chat:send` and returns the `chat:stream` response.
+    /// 发送 `chat:send` 并返回 `chat:stream` 回复流。
+    ///
+    /// The stream ends after a `done` or `error` event; a `chat:error` or a
+    /// lost connection is reported as a final `ChatStreamEvent::Error`.
+    /// 流在 `done` 或 `error` 事件后结束；`chat:error` 或连接断开会作为最后一个
+    /// `ChatStreamEvent::Error` 返回。
+    ///
+    /// If the previous stream was dropped before it finished, this waits
+    /// until the server has finished that response, for at most
+    /// `SocketOptions::drain_timeout`; after that the old response is
+    /// abandoned and the message is sent anyway.
+    /// 如果上一个流在结束前被丢弃，会等待服务端完成该回复，最多等待
+    /// `SocketOptions::drain_timeout`；超时后放弃旧回复并照常发送。
+    pub async fn send(&self, payload: ChatSendPayload) -> Result<ChatEventStream, SocketError> {
+        let mut payload = Some(payload);
+        loop {
+            let drained = {
+                let mut state = self.router.state.lock().unwrap();
+                if state.closed {
+                    return Err(SocketError::Closed);
+                }
+                if state.active.is_some() {
+                    return Err(SocketError::StreamInProgress);
+                }
+                if !state.draining {
+                    if let Some(payload) = payload.take() {
+                        self.handle.emit(AtlasClientEvent::ChatSend(payload))?;
+                    }
+                    state.next_id += 1;
+                    let (tx, rx) = mpsc::unbounded_channel();
+                    state.active = Some(ActiveStream { id: state.next_id, tx });
+                    return Ok(ChatEventStream::new(state.next_id, rx, self.router.clone()));
+                }
+                self.router.drained.notified()
+            };
+            if time::timeout(self.drain_timeout, drained).await.is_err() {
+                self.router.abandon_drain();
+            }
+        }
+    }
+
+    pub fn handle(&self) -> &SocketHandle {
+        &self.handle
+    }
+
+    /// Leaves the namespace; an active stream ends with an error event.
+    /// 离开命名空间；正在进行的流会以错误事件结束。
+    pub fn close(&self) {
+        self.handle.close();
+    }
+}
+
+pub(crate) struct ActiveStream {
+    id: u64,
+    tx: mpsc::UnboundedSender<ChatStreamEvent>,
+}
+
+#[derive(Default)]
+pub(crate) struct ChatState {
+    next_id: u64,
+    active: Option<ActiveStream>,
+    /// A dropped stream's response is still arriving and must be discarded.
+    draining: bool,
+    closed: bool,
+}
+
+/// Routes `chat:stream` events to the active [`ChatEventStream`].
+#[derive(Default)]
+pub(crate) struct ChatRouter {
+    state: Mutex<ChatState>,
+    drained: Notify,
+}
+
+impl ChatRouter {
+    fn deliver(&self, event: ChatStreamEvent) {
+        let terminal = event.is_terminal();
+        let mut state = self.state.lock().unwrap();
+        if let Some(active) = &state.active {
+            let _ = active.tx.send(event);
+        }
+        if terminal {
+            state.active = None;
+            if state.draining {
+                state.draining = false;
+                self.drained.notify_waiters();
+            }
+        }
+    }
+
+    /// Stops waiting for a dropped stream's response that never finished.
+    fn abandon_drain(&self) {
+        self.state.lock().unwrap().draining = false;
+    }
+
+    /// Called when a stream is dropped.
+    pub(crate) fn detach(&self, id: u64, finished: bool) {
+        let mut state = self.state.lock().unwrap();
+        if state.active.as_ref().is_some_and(|active| active.id == id) {
+            state.active = None;
+            state.draining = !finished && !state.closed;
+        }
+    }
+}
+
+impl Dispatch for ChatRouter {
+    fn dispatch(&self, event: AtlasServerEvent) {
+        match event {
+            AtlasServerEvent::ChatStream(event) => self.deliver(event),
+            AtlasServerEvent::ChatError(payload) => self.deliver(ChatStreamEvent::Error { message: payload.error }),
+            // A rejected `chat:send` (e.g. failed validation) surfaces as a gateway `error`.
+            AtlasServerEvent::Error(response) => self.deliver(ChatStreamEvent::Error { message: response.message }),
+            _ => {}
+        }
+    }
+
+    fn closed(&self, error: Option<&SocketError>) {
+        let message = error.map_or_else(|| SocketError::Closed.to_string(), ToString::to_string);
+        self.deliver(ChatStreamEvent::Error { message });
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        state.draining = false;
+        self.drained.notify_waiters();
+    }
+
+    /// The server does not resume a response on a new connection.
+    fn interrupted(&self, error: &SocketError) {
+        self.deliver(ChatStreamEvent::Error { message: error.to_string() });
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::events::atlas_server_event::AtlasServerEvent;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::cursor_update_payload::CursorUpdatePayload;
+use crate::interfaces::note_join_payload::NoteJoinPayload;
+use crate::interfaces::yjs_update_payload::YjsUpdatePayload;
+use crate::protocol::COLLABORATION_NAMESPACE;
+use crate::socket::connection::{self, Dispatch};
+use crate::socket::note_event::NoteEvent;
+use crate::socket::note_subscription::NoteSubscription;
+use crate::socket::socket_error::SocketError;
+use crate::socket::socket_handle::SocketHandle;
+use crate::socket::socket_options::SocketOptions;
+
+/// Client for the `/api/collaboration` gateway.
+/// `/api/collaboration` 网关客户端。
+///
+/// Each [`join`](CollaborationSocket::join) returns a [`NoteSubscription`]
+/// streaming that note's events; dropping it sends `note:leave`.
+/// 每次 `join` 返回一个 `NoteSubscription`，产出该笔记的事件；丢弃时发送 `note:leave`。
+pub struct CollaborationSocket {
+    handle: SocketHandle,
+    router: Arc<CollaborationRouter>,
+}
+
+impl CollaborationSocket {
+    /// Connects and authenticates with `options.credentials`.
+    /// 使用 `options.credentials` 连接并认证。
+    pub async fn connect(options: SocketOptions) -> Result<Self, SocketError> {
+        let router = Arc::new(CollaborationRouter::default());
+        let handle = connection::open(options, COLLABORATION_NAMESPACE, router.clone()).await?;
+        Ok(CollaborationSocket { handle, router })
+    }
+
+    /// Sends `note:join` and subscribes to the note's events. The first event
+    /// is `Sync`, or `Limit`/`Rejected` if the server refuses the join.
+    /// 发送 `note:join` 并订阅该笔记的事件。第一个事件是 `Sync`，
+    /// 若服务端拒绝则为 `Limit`/`Rejected`。
+    ///
+    /// With reconnection enabled, joined notes are rejoined after a reconnect
+    /// and receive a fresh `Sync`.
+    /// 启用重连时，重连后会重新加入已加入的笔记，并收到新的 `Sync`。
+    pub fn join(&self, note_id: &NoteId) -> Result<NoteSubscription, SocketError> {
+        let (id, rx) = self.router.subscribe(note_id, &self.handle)?;
+        Ok(NoteSubscription::new(id, note_id.clone(), rx, self.handle.clone(), self.router.clone()))
+    }
+
+    /// Broadcasts a Y.js update (raw bytes) to the other editors of a note.
+    /// 向笔记的其他编辑者广播 Y.js 更新（原始字节）。
+    pub fn push_update(&self, note_id: &NoteId, update: &[u8]) -> Result<(), SocketError> {
+        self.handle.emit(AtlasClientEvent::YjsUpdate(YjsUpdatePayload {
+            note_id: note_id.clone(),
+            update: BASE64.encode(update),
+        }))
+    }
+
+    /// Broadcasts the local cursor position and selection.
+    /// 广播本地光标位置与选区。
+    pub fn move_cursor(
+        &self,
+        note_id: &NoteId,
+        position: Option<Value>,
+        selection: Option<Value>,
+    ) -> Result<(), SocketError> {
+        self.handle.emit(AtlasClientEvent::CursorUpdate(CursorUpdatePayload {
+            note_id: note_id.clone(),
+            position,
+            selection,
+            user_id: None,
+        }))
+    }
+
+    pub fn handle(&self) -> &SocketHandle {
+        &self.handle
+    }
+
+    /// Leaves the namespace; every subscription ends with `Disconnected`.
+    /// 离开命名空间；所有订阅以 `Disconnected` 结束。
+    pub fn close(&self) {
+        self.handle.close();
+    }
+}
+
+struct Subscriber {
+    id: u64,
+    tx: mpsc::UnboundedSender<NoteEvent>,
+}
+
+#[derive(Default)]
+struct CollaborationState {
+    next_id: u64,
+    notes: HashMap<NoteId, Subscriber>,
+    /// Joins still waiting for `yjs:sync`, oldest first. `collaboration:limit`
+    /// and `collaboration:error` carry no note id and answer the oldest one.
+    pending: VecDeque<NoteId>,
+    /// The note that received the latest `yjs:sync`; `presence:list` follows it.
+    last_synced: Option<NoteId>,
+    /// The connection was lost; joins are sent once it is re-established.
+    interrupted: bool,
+    closed: bool,
+}
+
+/// Routes gateway events to the subscription of their note.
+#[derive(Default)]
+pub(crate) struct CollaborationRouter {
+    state: Mutex<CollaborationState>,
+}
+
+impl CollaborationRouter {
+    /// Registers a subscription and sends `note:join`.
+    fn subscribe(
+        &self,
+        note_id: &NoteId,
+        handle: &SocketHandle,
+    ) -> Result<(u64, mpsc::UnboundedReceiver<NoteEvent>), SocketError> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return Err(SocketError::Closed);
+        }
+        if state.notes.contains_key(note_id) {
+            return Err(SocketError::AlreadyJoined(note_id.clone()));
+        }
+        if !state.interrupted {
+            handle.emit(AtlasClientEvent::NoteJoin(NoteJoinPayload { note_id: note_id.clone() }))?;
+        }
+        state.next_id += 1;
+        let id = state.next_id;
+        let (tx, rx) = mpsc::unbounded_channel();
+        state.notes.insert(note_id.clone(), Subscriber { id, tx });
+        state.pending.push_back(note_id.clone());
+        Ok((id, rx))
+    }
+
+    /// Removes the subscription; returns whether it was still joined.
+    pub(crate) fn unsubscribe(&self, note_id: &NoteId, id: u64) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.notes.get(note_id).is_none_or(|subscriber| subscriber.id != id) {
+            return false;
+        }
+        state.notes.remove(note_id);
+        state.pending.retain(|pending| pending != note_id);
+        if state.last_synced.as_ref() == Some(note_id) {
+            state.last_synced = None;
+        }
+        true
+    }
+
+    fn send(state: &CollaborationState, note_id: &NoteId, event: NoteEvent) {
+        if let Some(subscriber) = state.notes.get(note_id) {
+            let _ = subscriber.tx.send(event);
+        }
+    }
+
+    /// Ends the oldest pending join with a refusal.
+    fn reject(state: &mut CollaborationState, event: NoteEvent) {
+        if let Some(note_id) = state.pending.pop_front() {
+            if let Some(subscriber) = state.notes.remove(&note_id) {
+                let _ = subscriber.tx.send(event);
+            }
+        }
+    }
+}
+
+impl Dispatch for CollaborationRouter {
+    fn dispatch(&self, event: AtlasServerEvent) {
+        let mut state = self.state.lock().unwrap();
+        match event {
+            AtlasServerEvent::YjsSync(payload) => {
+                let note_id = payload.note_id.clone();
+                state.pending.retain(|pending| *pending != note_id);
+                Self::send(&state, &note_id, NoteEvent::Sync(payload));
+                state.last_synced = Some(note_id);
+            }
+            AtlasServerEvent::YjsUpdate(payload) => {
+                let note_id = payload.note_id.clone();
+                Self::send(&state, &note_id, NoteEvent::Update(payload));
+            }
+            AtlasServerEvent::CursorUpdate(payload) => {
+                let note_id = payload.note_id.clone();
+                Self::send(&state, &note_id, NoteEvent::Cursor(payload));
+            }
+            AtlasServerEvent::PresenceList(collaborators) => {
+                if let Some(note_id) = state.last_synced.clone() {
+                    Self::send(&state, &note_id, NoteEvent::Collaborators(collaborators));
+                }
+            }
+            AtlasServerEvent::PresenceJoin(payload) => {
+                for subscriber in state.notes.values() {
+                    let _ = subscriber.tx.send(NoteEvent::PresenceJoin(payload.clone()));
+                }
+            }
+            AtlasServerEvent::PresenceLeave(payload) => {
+                for subscriber in state.notes.values() {
+                    let _ = subscriber.tx.send(NoteEvent::PresenceLeave(payload.clone()));
+                }
+            }
+            AtlasServerEvent::CollaborationLimit(payload) => Self::reject(&mut state, NoteEvent::Limit(payload)),
+            AtlasServerEvent::CollaborationError(payload) => Self::reject(&mut state, NoteEvent::Rejected(payload)),
+            _ => {}
+        }
+    }
+
+    fn closed(&self, error: Option<&SocketError>) {
+        let message = error.map_or_else(|| SocketError::Closed.to_string(), ToString::to_string);
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        state.pending.clear();
+        for (_, subscriber) in state.notes.drain() {
+            let _ = subscriber.tx.send(NoteEvent::Disconnected(message.clone()));
+        }
+    }
+
+    fn interrupted(&self, _error: &SocketError) {
+        let mut state = self.state.lock().unwrap();
+        state.interrupted = true;
+        state.pending.clear();
+        state.last_synced = None;
+    }
+
+    /// Rejoins every subscribed note; each subscription then receives a
+    /// fresh `Sync`.
+    fn reconnected(&self) -> Vec<AtlasClientEvent> {
+        let mut state = self.state.lock().unwrap();
+        state.interrupted = false;
+        let notes: Vec<NoteId> = state.notes.keys().cloned().collect();
+        state.pending = notes.iter().cloned().collect();
+        notes.into_iter().map(|note_id| AtlasClientEvent::NoteJoin(NoteJoinPayload { note_id })).collect()
+    }
+}
@@ -0,0 +1,417 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::time::{self, Instant};
+
+use crate::dto::refresh_token_dto::RefreshTokenDto;
+use crate::error::atlas_ws_error::AtlasWsError;
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::events::atlas_server_event::AtlasServerEvent;
+use crate::interfaces::error_category::ErrorCategory;
+use crate::interfaces::web_socket_error_code::{ErrorDisposition, WebSocketErrorCode};
+use crate::interfaces::ws_error_response::WsErrorResponse;
+use crate::protocol::engine_packet::EnginePacket;
+use crate::protocol::socket_decoder::SocketDecoder;
+use crate::protocol::socket_packet::SocketPacket;
+use crate::socket::connection_event::ConnectionEvent;
+use crate::socket::connection_machine::ConnectionMachine;
+use crate::socket::connection_state::ConnectionState;
+use crate::socket::incoming_event::IncomingEvent;
+use crate::socket::middleware_context::MiddlewareContext;
+use crate::socket::socket_error::SocketError;
+use crate::socket::socket_handle::{Command, SocketHandle};
+use crate::socket::socket_middleware::MiddlewareFlow;
+use crate::socket::socket_options::SocketOptions;
+use crate::socket::transport::BoxTransport;
+
+/// Upper bound for the heartbeat the server announces in its OPEN packet,
+/// so absurd `pingInterval`/`pingTimeout` values cannot overflow a deadline.
+const MAX_HEARTBEAT: Duration = Duration::from_secs(60 * 60);
+
+/// Receives the events of one namespace connection.
+pub(crate) trait Dispatch: Send + Sync + 'static {
+    fn dispatch(&self, event: AtlasServerEvent);
+
+    /// The connection ended; `error` is `None` after a local `close()`.
+    fn closed(&self, error: Option<&SocketError>);
+
+    /// The connection was lost and will be re-established.
+    fn interrupted(&self, _error: &SocketError) {}
+
+    /// The connection was re-established; returns events to send before any
+    /// queued ones, e.g. to rejoin rooms.
+    fn reconnected(&self) -> Vec<AtlasClientEvent> {
+        Vec::new()
+    }
+}
+
+/// Connects to `namespace` and spawns the task driving the connection.
+/// Resolves once the namespace `CONNECT` has been acknowledged.
+pub(crate) async fn open(
+    options: SocketOptions,
+    namespace: &'static str,
+    dispatch: Arc<dyn Dispatch>,
+) -> Result<SocketHandle, SocketError> {
+    let (commands_tx, commands) = mpsc::unbounded_channel();
+    let (ready_tx, ready) = oneshot::channel();
+    let (state_tx, state) = watch::channel(ConnectionState::Disconnected);
+    let handle = commands_tx.downgrade();
+    let machine = ConnectionMachine::new(options.reconnect.map_or(0, |policy| policy.max_retries));
+    let connection = Connection {
+        options,
+        namespace,
+        dispatch,
+        commands,
+        handle,
+        machine,
+        state_tx,
+        state: state.clone(),
+        backlog: Vec::new(),
+    };
+    tokio::spawn(connection.run(ready_tx));
+    ready.await.unwrap_or(Err(SocketError::Closed))?;
+    Ok(SocketHandle::new(commands_tx, state))
+}
+
+struct Connection {
+    options: SocketOptions,
+    namespace: &'static str,
+    dispatch: Arc<dyn Dispatch>,
+    commands: mpsc::UnboundedReceiver<Command>,
+    /// Lets middleware emit without keeping the connection alive.
+    handle: mpsc::WeakUnboundedSender<Command>,
+    machine: ConnectionMachine,
+    state_tx: watch::Sender<ConnectionState>,
+    state: watch::Receiver<ConnectionState>,
+    /// Events emitted while waiting to reconnect.
+    backlog: Vec<AtlasClientEvent>,
+}
+
+struct Session {
+    transport: BoxTransport,
+    decoder: SocketDecoder,
+    /// Longest silence tolerated from the server (`pingInterval + pingTimeout`).
+    heartbeat: Duration,
+    /// Last `error` frame, reported if the server then drops the connection.
+    gateway_error: Option<AtlasWsError>,
+}
+
+impl Connection {
+    async fn run(mut self, ready: oneshot::Sender<Result<(), SocketError>>) {
+        self.transition(ConnectionEvent::Connect);
+        let mut session = match self.handshake().await {
+            Ok(session) => session,
+            Err(err) => {
+                self.fail(&err);
+                self.transition(ConnectionEvent::Disconnect);
+                let _ = ready.send(Err(err));
+                return;
+            }
+        };
+        let _ = ready.send(Ok(()));
+        let result = loop {
+            let err = match self.serve(session).await {
+                Ok(()) => break Ok(()),
+                Err(err) => err,
+            };
+            match self.reconnect(err).await {
+                Ok(Some(next)) => session = next,
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+        self.dispatch.closed(result.err().as_ref());
+        self.transition(ConnectionEvent::Disconnect);
+    }
+
+    fn transition(&mut self, event: ConnectionEvent) {
+        let result = self.machine.transition(event);
+        debug_assert!(result.is_ok(), "{result:?}");
+        if let Ok(state) = result {
+            self.state_tx.send_replace(state);
+        }
+    }
+
+    /// Moves to `Error`, as an auth failure if the token was refused during
+    /// the handshake.
+    fn fail(&mut self, err: &SocketError) {
+        let refused = matches!(err, SocketError::ConnectRefused(_))
+            || err.disposition() == ErrorDisposition::Reauthenticate;
+        let event = if refused && self.machine.state() != ConnectionState::Ready {
+            ConnectionEvent::AuthFailed(err.to_string())
+        } else {
+            ConnectionEvent::FatalError(err.to_string())
+        };
+        self.transition(event);
+    }
+
+    /// How to react to `err`; auth errors are fatal without a token provider.
+    fn disposition(&self, err: &SocketError) -> ErrorDisposition {
+        match err.disposition() {
+            ErrorDisposition::Reauthenticate if self.options.token_provider.is_none() => ErrorDisposition::Fatal,
+            disposition => disposition,
+        }
+    }
+
+    /// Re-establishes a lost connection according to `options.reconnect`.
+    /// Returns `None` if the client closed the connection meanwhile.
+    async fn reconnect(&mut self, mut err: SocketError) -> Result<Option<Session>, SocketError> {
+        self.fail(&err);
+        let Some(policy) = self.options.reconnect else {
+            return Err(err);
+        };
+        if self.disposition(&err) == ErrorDisposition::Fatal || !self.machine.can_retry() {
+            return Err(err);
+        }
+        self.dispatch.interrupted(&err);
+        loop {
+            let disposition = self.disposition(&err);
+            if disposition == ErrorDisposition::Fatal || !self.machine.can_retry() {
+                return Err(err);
+            }
+            if !self.wait(policy.delay(self.machine.retry_count())).await {
+                return Ok(None);
+            }
+            self.transition(ConnectionEvent::Retry);
+            let attempt = async {
+                if disposition == ErrorDisposition::Reauthenticate {
+                    self.reauthenticate().await?;
+                }
+                let mut session = self.handshake().await?;
+                for event in self.dispatch.reconnected().into_iter().chain(self.backlog.drain(..)) {
+                    session.emit(self.namespace, &event).await?;
+                }
+                Ok(session)
+            };
+            match attempt.await {
+                Ok(session) => return Ok(Some(session)),
+                Err(next) => {
+                    self.fail(&next);
+                    err = next;
+                }
+            }
+        }
+    }
+
+    /// Sleeps for `delay`, queueing emitted events. Returns `false` if the
+    /// client closed the connection meanwhile.
+    async fn wait(&mut self, delay: Duration) -> bool {
+        let sleep = time::sleep(delay);
+        tokio::pin!(sleep);
+        loop {
+            tokio::select! {
+                _ = &mut sleep => return true,
+                command = self.commands.recv() => match command {
+                    Some(Command::Emit(event)) => self.backlog.push(event),
+                    Some(Command::Close) | None => return false,
+                },
+            }
+        }
+    }
+
+    async fn reauthenticate(&self) -> Result<(), SocketError> {
+        let Some(provider) = &self.options.token_provider else {
+            return Ok(());
+        };
+        let credentials = &self.options.credentials;
+        let request = RefreshTokenDto { refresh_token: credentials.refresh_token() };
+        let tokens = provider.refresh(request).await.map_err(|err| SocketError::TokenRefresh(err.to_string()))?;
+        credentials.update(&tokens);
+        Ok(())
+    }
+
+    /// Runs the Engine.IO and namespace handshakes, from `Connecting` to `Ready`.
+    async fn handshake(&mut self) -> Result<Session, SocketError> {
+        let timeout = self.options.connect_timeout;
+        let handshake = async {
+            let transport = (self.options.connector)(self.options.engine_url()).await?;
+            let mut session = Session {
+                transport,
+                decoder: SocketDecoder::new(),
+                heartbeat: Duration::ZERO,
+                gateway_error: None,
+            };
+            match session.next_packet().await? {
+                EnginePacket::Open(open) => {
+                    let heartbeat = Duration::from_millis(open.ping_interval.saturating_add(open.ping_timeout));
+                    session.heartbeat = heartbeat.min(MAX_HEARTBEAT);
+                }
+                _ => return Err(SocketError::ConnectRefused("expected Engine.IO open packet".to_owned())),
+            }
+            self.transition(ConnectionEvent::ConnectionEstablished);
+            self.transition(ConnectionEvent::Connect);
+            let auth = json!({ "token": self.options.credentials.access_token() });
+            session.send(SocketPacket::connect(self.namespace, Some(auth))).await?;
+            loop {
+                match session.next_packet().await? {
+                    EnginePacket::Ping(data) => session.send_engine(EnginePacket::Pong(data)).await?,
+                    EnginePacket::Close => return Err(SocketError::Closed),
+                    EnginePacket::Message(frame) => match session.decoder.feed(frame)? {
+                        Some(SocketPacket::Connect { namespace, .. }) if namespace == self.namespace => {
+                            self.transition(ConnectionEvent::AuthSuccess);
+                            return Ok(session);
+                        }
+                        Some(SocketPacket::ConnectError { namespace, data }) if namespace == self.namespace => {
+                            return Err(SocketError::ConnectRefused(connect_error_message(&data)));
+                        }
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+        };
+        time::timeout(timeout, handshake).await.map_err(|_| SocketError::Timeout)?
+    }
+
+    async fn serve(&mut self, mut session: Session) -> Result<(), SocketError> {
+        let deadline = time::sleep(session.heartbeat);
+        tokio::pin!(deadline);
+        loop {
+            tokio::select! {
+                frame = session.transport.next() => {
+                    deadline.as_mut().reset(Instant::now() + session.heartbeat);
+                    let packet = match frame {
+                        Some(frame) => EnginePacket::decode(frame?)?,
+                        None => return Err(session.lost()),
+                    };
+                    match packet {
+                        EnginePacket::Ping(data) => session.send_engine(EnginePacket::Pong(data)).await?,
+                        EnginePacket::Close => return Err(session.lost()),
+                        EnginePacket::Message(frame) => {
+                            if let Some(packet) = session.decoder.feed(frame)? {
+                                self.handle(&mut session, packet).await?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                command = self.commands.recv() => match command {
+                    Some(Command::Emit(event)) => {
+                        session.emit(self.namespace, &event).await?;
+                        if matches!(event, AtlasClientEvent::TokenRefreshed(_))
+                            && self.machine.state() == ConnectionState::Ready
+                        {
+                            self.transition(ConnectionEvent::TokenRefreshed);
+                        }
+                    }
+                    Some(Command::Close) | None => {
+                        let _ = session.send(SocketPacket::Disconnect { namespace: self.namespace.to_owned() }).await;
+                        let _ = session.transport.close().await;
+                        return Ok(());
+                    }
+                },
+                _ = &mut deadline => return Err(SocketError::Timeout),
+            }
+        }
+    }
+
+    async fn handle(&mut self, session: &mut Session, packet: SocketPacket) -> Result<(), SocketError> {
+        if packet.namespace() != self.namespace {
+            return Ok(());
+        }
+        match packet {
+            SocketPacket::Event { id, data, .. } | SocketPacket::BinaryEvent { id, data, .. } => {
+                if let Some(id) = id {
+                    session.send(SocketPacket::ack(self.namespace, id, vec![])).await?;
+                }
+                let incoming = IncomingEvent {
+                    event: decode_event(data.clone()),
+                    args: data.into_iter().skip(1).collect(),
+                };
+                let authenticating = self.machine.state() == ConnectionState::Authenticating;
+                match &incoming.event {
+                    AtlasServerEvent::Error(response) => {
+                        session.gateway_error = Some(AtlasWsError::new(response.clone()));
+                    }
+                    AtlasServerEvent::TokenRenewed(_) if authenticating => self.transition(ConnectionEvent::AuthSuccess),
+                    _ => {}
+                }
+                let ctx = MiddlewareContext::new(self.namespace, &self.handle, &self.state);
+                for middleware in &self.options.middleware {
+                    if middleware.on_event(&incoming, &ctx) == MiddlewareFlow::Drop {
+                        return Ok(());
+                    }
+                }
+                // The old token expires soon after a refused refresh; start over.
+                let rejected = match &incoming.event {
+                    AtlasServerEvent::AuthError(payload) if authenticating => Some(payload.message.clone()),
+                    _ => None,
+                };
+                self.dispatch.dispatch(incoming.event);
+                match rejected {
+                    Some(message) => Err(SocketError::Gateway(AtlasWsError::new(WsErrorResponse {
+                        code: WebSocketErrorCode::AuthTokenInvalid,
+                        message,
+                        category: ErrorCategory::Auth,
+                        details: None,
+                        timestamp: None,
+                    }))),
+                    None => Ok(()),
+                }
+            }
+            SocketPacket::Disconnect { .. } => Err(session.lost()),
+            SocketPacket::ConnectError { data, .. } => Err(SocketError::ConnectRefused(connect_error_message(&data))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Session {
+    async fn next_packet(&mut self) -> Result<EnginePacket, SocketError> {
+        match self.transport.next().await {
+            Some(frame) => Ok(EnginePacket::decode(frame?)?),
+            None => Err(SocketError::Closed),
+        }
+    }
+
+    async fn send_engine(&mut self, packet: EnginePacket) -> Result<(), SocketError> {
+        self.transport.send(packet.encode()).await
+    }
+
+    async fn send(&mut self, packet: SocketPacket) -> Result<(), SocketError> {
+        for packet in packet.to_engine_packets() {
+            self.send_engine(packet).await?;
+        }
+        Ok(())
+    }
+
+    async fn emit(&mut self, namespace: &str, event: &AtlasClientEvent) -> Result<(), SocketError> {
+        self.send(SocketPacket::event(namespace, None, event)?).await
+    }
+
+    /// Error reported when the server goes away.
+    fn lost(&mut self) -> SocketError {
+        self.gateway_error.take().map_or(SocketError::Closed, SocketError::Gateway)
+    }
+}
+
+/// Decodes `["name", ...args]`, keeping payloads this crate cannot parse as
+/// `Unknown` instead of dropping them.
+fn decode_event(data: Vec<Value>) -> AtlasServerEvent {
+    match serde_json::from_value(Value::Array(data.clone())) {
+        Ok(event) => event,
+        Err(_) => {
+            let mut args = data.into_iter();
+            let name = match args.next() {
+                Some(Value::String(name)) => name,
+                Some(other) => other.to_string(),
+                None => String::new(),
+            };
+            AtlasServerEvent::Unknown { name, args: args.collect() }
+        }
+    }
+}
+
+fn connect_error_message(data: &Value) -> String {
+    match data {
+        Value::String(message) => message.clone(),
+        Value::Object(map) => match map.get("message") {
+            Some(Value::String(message)) => message.clone(),
+            _ => data.to_string(),
+        },
+        _ => data.to_string(),
+    }
+}
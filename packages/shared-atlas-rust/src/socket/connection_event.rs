@@ -0,0 +1,51 @@
+/// Input of the [`ConnectionMachine`], mirroring the server's
+/// `WebSocketEventTypes`.
+/// [`ConnectionMachine`] 的输入事件，与服务端 `WebSocketEventTypes` 对应。
+///
+/// [`ConnectionMachine`]: crate::socket::connection_machine::ConnectionMachine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Open a transport (from `Disconnected`), or send the namespace
+    /// `CONNECT` with the token once the Engine.IO session is open.
+    /// 打开传输（自 `Disconnected`），或在 Engine.IO 会话建立后发送带令牌的命名空间 `CONNECT`。
+    Connect,
+    /// The Engine.IO handshake completed.
+    /// Engine.IO 握手完成。
+    ConnectionEstablished,
+    /// The server accepted the token.
+    /// 服务端接受了令牌。
+    AuthSuccess,
+    /// The server rejected the token.
+    /// 服务端拒绝了令牌。
+    AuthFailed(String),
+    /// A new token was sent with `auth:token-refreshed`.
+    /// 已通过 `auth:token-refreshed` 发送新令牌。
+    TokenRefreshed,
+    /// The connection was closed, locally or for good.
+    /// 连接已关闭（本地关闭或不再重试）。
+    Disconnect,
+    /// Reconnect after a backoff delay.
+    /// 退避等待后重新连接。
+    Retry,
+    /// The connection failed; `Retry` or `Disconnect` follows depending on
+    /// the error's disposition.
+    /// 连接失败；根据错误的处理方式随后为 `Retry` 或 `Disconnect`。
+    FatalError(String),
+}
+
+impl ConnectionEvent {
+    /// Wire name used by the server (`"AUTH_SUCCESS"`, `"RETRY"`, ...).
+    /// 服务端使用的事件名（`"AUTH_SUCCESS"`、`"RETRY"` 等）。
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConnectionEvent::Connect => "CONNECT",
+            ConnectionEvent::ConnectionEstablished => "CONNECTION_ESTABLISHED",
+            ConnectionEvent::AuthSuccess => "AUTH_SUCCESS",
+            ConnectionEvent::AuthFailed(_) => "AUTH_FAILED",
+            ConnectionEvent::TokenRefreshed => "TOKEN_REFRESHED",
+            ConnectionEvent::Disconnect => "DISCONNECT",
+            ConnectionEvent::Retry => "RETRY",
+            ConnectionEvent::FatalError(_) => "FATAL_ERROR",
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::socket::connection_event::ConnectionEvent;
+use crate::socket::connection_state::ConnectionState;
+use crate::socket::invalid_transition::InvalidTransition;
+
+/// Retries allowed by the server's state machine (`MAX_RETRIES`).
+/// 服务端状态机允许的重试次数（`MAX_RETRIES`）。
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Client-side copy of the server's WebSocket state machine.
+/// 服务端 WebSocket 状态机的客户端实现。
+///
+/// ```text
+/// disconnected --CONNECT--> connecting --CONNECTION_ESTABLISHED--> connected
+/// connected --CONNECT--> authenticating --AUTH_SUCCESS--> ready
+/// ready --TOKEN_REFRESHED--> authenticating
+/// connecting | connected | authenticating --AUTH_FAILED--> error
+/// connecting | connected | authenticating | ready --FATAL_ERROR--> error
+/// error --RETRY (while retries remain)--> connecting
+/// any but disconnected --DISCONNECT--> disconnected
+/// ```
+///
+/// The server moves through `connecting`/`connected` and out of `error` on
+/// its own; the client drives those steps explicitly since it has to wait for
+/// the network and the backoff delay.
+/// 服务端自动经过 `connecting`/`connected` 并离开 `error`；客户端需要等待网络与
+/// 退避延迟，因此显式驱动这些步骤。
+#[derive(Debug, Clone)]
+pub struct ConnectionMachine {
+    state: ConnectionState,
+    retry_count: u32,
+    max_retries: u32,
+    last_error: Option<String>,
+}
+
+impl ConnectionMachine {
+    pub fn new(max_retries: u32) -> Self {
+        ConnectionMachine { state: ConnectionState::Disconnected, retry_count: 0, max_retries, last_error: None }
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Retries since the connection was last ready.
+    /// 自上次就绪以来的重试次数。
+    pub fn retry_count(&self) -> u32 {
+        self.retry_count
+    }
+
+    /// Message of the latest `AUTH_FAILED`/`FATAL_ERROR`, cleared on `AUTH_SUCCESS`.
+    /// 最近一次 `AUTH_FAILED`/`FATAL_ERROR` 的消息，`AUTH_SUCCESS` 时清除。
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn can_retry(&self) -> bool {
+        self.retry_count < self.max_retries
+    }
+
+    /// Applies `event`, returning the new state, or an error leaving the
+    /// machine unchanged if the event is not allowed.
+    /// 应用事件并返回新状态；若事件不被允许则返回错误且状态不变。
+    pub fn transition(&mut self, event: ConnectionEvent) -> Result<ConnectionState, InvalidTransition> {
+        use ConnectionEvent as E;
+        use ConnectionState as S;
+
+        let next = match (self.state, &event) {
+            (S::Disconnected, E::Connect) => S::Connecting,
+            (S::Connecting, E::ConnectionEstablished) => S::Connected,
+            (S::Connected, E::Connect) => S::Authenticating,
+            (S::Authenticating, E::AuthSuccess) => S::Ready,
+            (S::Ready, E::TokenRefreshed) => S::Authenticating,
+            (S::Connecting | S::Connected | S::Authenticating, E::AuthFailed(_)) => S::Error,
+            (S::Connecting | S::Connected | S::Authenticating | S::Ready, E::FatalError(_)) => S::Error,
+            (S::Error, E::Retry) if self.can_retry() => S::Connecting,
+            (state, E::Disconnect) if state != S::Disconnected => S::Disconnected,
+            (from, event) => return Err(InvalidTransition { from, event: event.name() }),
+        };
+        match event {
+            E::Connect if self.state == S::Disconnected => {
+                self.retry_count = 0;
+                self.last_error = None;
+            }
+            E::AuthSuccess => {
+                self.retry_count = 0;
+                self.last_error = None;
+            }
+            E::AuthFailed(error) | E::FatalError(error) => self.last_error = Some(error),
+            E::Retry => self.retry_count += 1,
+            _ => {}
+        }
+        self.state = next;
+        Ok(next)
+    }
+}
+
+impl Default for ConnectionMachine {
+    fn default() -> Self {
+        ConnectionMachine::new(DEFAULT_MAX_RETRIES)
+    }
+}
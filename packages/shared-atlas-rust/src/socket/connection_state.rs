@@ -0,0 +1,51 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle state of a gateway connection, mirroring the server's
+/// `WebSocketStates`.
+/// 网关连接的生命周期状态，与服务端 `WebSocketStates` 对应。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionState {
+    /// No transport; the initial and final state.
+    /// 无传输连接；初始与最终状态。
+    Disconnected,
+    /// Opening the transport and waiting for the Engine.IO handshake.
+    /// 正在打开传输并等待 Engine.IO 握手。
+    Connecting,
+    /// The Engine.IO session is open.
+    /// Engine.IO 会话已建立。
+    Connected,
+    /// The token was sent (namespace `CONNECT` or `auth:token-refreshed`)
+    /// and awaits the server's answer.
+    /// 已发送令牌（命名空间 `CONNECT` 或 `auth:token-refreshed`），等待服务端答复。
+    Authenticating,
+    /// Authenticated; events flow in both directions.
+    /// 已认证，可双向收发事件。
+    Ready,
+    /// The connection failed; it is either retried or closed.
+    /// 连接失败，随后重试或关闭。
+    Error,
+}
+
+impl ConnectionState {
+    /// Wire name used by the server (`"ready"`, `"error"`, ...).
+    /// 服务端使用的状态名（`"ready"`、`"error"` 等）。
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ConnectionState::Disconnected => "disconnected",
+            ConnectionState::Connecting => "connecting",
+            ConnectionState::Connected => "connected",
+            ConnectionState::Authenticating => "authenticating",
+            ConnectionState::Ready => "ready",
+            ConnectionState::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,44 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::{self, BoxStream};
+use futures_util::{Stream, StreamExt};
+use tokio::sync::watch;
+
+use crate::socket::connection_state::ConnectionState;
+
+/// Stream of [`ConnectionState`] changes, starting with the current state.
+/// [`ConnectionState`] 变化流，首先产出当前状态。
+///
+/// Intermediate states may be skipped if the consumer falls behind; the
+/// latest state is always delivered. Ends when the connection task exits.
+/// 消费过慢时可能跳过中间状态，但总会产出最新状态。连接任务结束后流结束。
+pub struct ConnectionStateStream {
+    inner: BoxStream<'static, ConnectionState>,
+}
+
+impl ConnectionStateStream {
+    pub(crate) fn new(mut state: watch::Receiver<ConnectionState>) -> Self {
+        let current = *state.borrow_and_update();
+        let changes = stream::unfold(state, |mut state| async move {
+            state.changed().await.ok()?;
+            let next = *state.borrow_and_update();
+            Some((next, state))
+        });
+        ConnectionStateStream { inner: Box::pin(stream::iter([current]).chain(changes)) }
+    }
+}
+
+impl Stream for ConnectionStateStream {
+    type Item = ConnectionState;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<ConnectionState>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl std::fmt::Debug for ConnectionStateStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionStateStream").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::{Arc, Mutex};
+
+use crate::interfaces::token_response::TokenResponse;
+
+/// Tokens used to authenticate gateway connections, shared between the
+/// connection and the token lifecycle middleware.
+/// 用于网关连接认证的令牌，由连接与令牌生命周期中间件共享。
+///
+/// Clones share the same tokens, so a refresh is picked up by the next
+/// (re)connect.
+/// 克隆体共享同一份令牌，刷新后的令牌会在下一次（重新）连接时使用。
+#[derive(Clone, Default)]
+pub struct Credentials {
+    inner: Arc<Mutex<Tokens>>,
+}
+
+#[derive(Default)]
+struct Tokens {
+    access_token: String,
+    refresh_token: Option<String>,
+}
+
+impl Credentials {
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Credentials {
+            inner: Arc::new(Mutex::new(Tokens { access_token: access_token.into(), refresh_token: None })),
+        }
+    }
+
+    /// Access token (JWT) sent as the `auth.token` of the namespace `CONNECT`.
+    /// 作为命名空间 `CONNECT` 的 `auth.token` 发送的访问令牌（JWT）。
+    pub fn access_token(&self) -> String {
+        self.inner.lock().unwrap().access_token.clone()
+    }
+
+    /// Refresh token passed to the `TokenProvider`; `None` when the provider
+    /// relies on cookies.
+    /// 传给 `TokenProvider` 的刷新令牌；使用 Cookie 刷新时为 `None`。
+    pub fn refresh_token(&self) -> Option<String> {
+        self.inner.lock().unwrap().refresh_token.clone()
+    }
+
+    pub fn set_access_token(&self, access_token: impl Into<String>) {
+        self.inner.lock().unwrap().access_token = access_token.into();
+    }
+
+    pub fn set_refresh_token(&self, refresh_token: Option<String>) {
+        self.inner.lock().unwrap().refresh_token = refresh_token;
+    }
+
+    /// Stores the tokens returned by a refresh.
+    /// 保存刷新接口返回的令牌。
+    pub fn update(&self, tokens: &TokenResponse) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.access_token = tokens.access_token.clone();
+        inner.refresh_token = Some(tokens.refresh_token.clone());
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.lock().unwrap();
+        f.debug_struct("Credentials")
+            .field("access_token", &"<redacted>")
+            .field("refresh_token", &inner.refresh_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
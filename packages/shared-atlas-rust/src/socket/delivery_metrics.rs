@@ -0,0 +1,56 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters kept by `ReliableDelivery`.
+/// `ReliableDelivery` 维护的计数器。
+#[derive(Debug, Default)]
+pub struct DeliveryMetrics {
+    received: AtomicU64,
+    duplicates: AtomicU64,
+    acks_sent: AtomicU64,
+    ack_failures: AtomicU64,
+}
+
+impl DeliveryMetrics {
+    /// Reliable events (carrying `messageId`) received, duplicates included.
+    /// 收到的可靠事件（带 `messageId`）数量，包括重复事件。
+    pub fn received(&self) -> u64 {
+        self.received.load(Ordering::Relaxed)
+    }
+
+    /// Resent events that were dropped because they had already been seen.
+    /// 因已处理过而丢弃的重发事件数量。
+    pub fn duplicates(&self) -> u64 {
+        self.duplicates.load(Ordering::Relaxed)
+    }
+
+    /// Reliable events passed on to the client (`received - duplicates`).
+    /// 传递给客户端的可靠事件数量（`received - duplicates`）。
+    pub fn delivered(&self) -> u64 {
+        self.received().saturating_sub(self.duplicates())
+    }
+
+    /// `message:ack` events queued for the server.
+    /// 已排队发送的 `message:ack` 数量。
+    pub fn acks_sent(&self) -> u64 {
+        self.acks_sent.load(Ordering::Relaxed)
+    }
+
+    /// Acks that could not be queued because the connection was gone.
+    /// 因连接已断开而未能发送的 ack 数量。
+    pub fn ack_failures(&self) -> u64 {
+        self.ack_failures.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_received(&self) {
+        self.received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_duplicate(&self) {
+        self.duplicates.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_ack(&self, sent: bool) {
+        let counter = if sent { &self.acks_sent } else { &self.ack_failures };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
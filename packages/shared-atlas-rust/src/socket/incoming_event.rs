@@ -0,0 +1,28 @@
+use serde_json::Value;
+
+use crate::events::atlas_server_event::AtlasServerEvent;
+
+/// A server event as seen by middleware: the typed event plus its raw
+/// arguments, which keep fields the typed payload drops (such as `messageId`).
+/// 中间件看到的服务端事件：强类型事件及其原始参数（保留 `messageId` 等强类型
+/// 负载中没有的字段）。
+#[derive(Debug, Clone)]
+pub struct IncomingEvent {
+    pub event: AtlasServerEvent,
+    /// Arguments after the event name, as sent.
+    /// 事件名之后的原始参数。
+    pub args: Vec<Value>,
+}
+
+impl IncomingEvent {
+    pub fn name(&self) -> &str {
+        self.event.name()
+    }
+
+    /// The `messageId` that `ReliableMessageService` merges into reliable
+    /// payloads; the server resends the event until it is acknowledged.
+    /// `ReliableMessageService` 合并到可靠消息中的 `messageId`；在确认前服务端会重发。
+    pub fn message_id(&self) -> Option<&str> {
+        self.args.first()?.get("messageId")?.as_str()
+    }
+}
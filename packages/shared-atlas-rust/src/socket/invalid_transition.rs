@@ -0,0 +1,20 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::socket::connection_state::ConnectionState;
+
+/// An event that is not allowed in the current [`ConnectionState`].
+/// 当前 [`ConnectionState`] 下不允许的事件。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTransition {
+    pub from: ConnectionState,
+    pub event: &'static str,
+}
+
+impl fmt::Display for InvalidTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not allowed in state {}", self.event, self.from)
+    }
+}
+
+impl Error for InvalidTransition {}
@@ -0,0 +1,62 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{Sink, Stream};
+use tokio::sync::mpsc;
+
+use crate::protocol::frame::Frame;
+use crate::socket::socket_error::SocketError;
+
+/// One end of an in-process transport, for fake gateways in tests.
+/// 进程内传输通道的一端，用于测试中的模拟网关。
+#[derive(Debug)]
+pub struct MemoryTransport {
+    tx: Option<mpsc::UnboundedSender<Frame>>,
+    rx: mpsc::UnboundedReceiver<Frame>,
+}
+
+impl MemoryTransport {
+    /// Two connected ends: frames sent on one are received by the other.
+    /// 一对相连的端点：一端发送的帧由另一端接收。
+    pub fn pair() -> (MemoryTransport, MemoryTransport) {
+        let (client_tx, server_rx) = mpsc::unbounded_channel();
+        let (server_tx, client_rx) = mpsc::unbounded_channel();
+        (
+            MemoryTransport { tx: Some(client_tx), rx: client_rx },
+            MemoryTransport { tx: Some(server_tx), rx: server_rx },
+        )
+    }
+}
+
+impl Stream for MemoryTransport {
+    type Item = Result<Frame, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx).map(|frame| frame.map(Ok))
+    }
+}
+
+impl Sink<Frame> for MemoryTransport {
+    type Error = SocketError;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, frame: Frame) -> Result<(), SocketError> {
+        match &self.tx {
+            Some(tx) => tx.send(frame).map_err(|_| SocketError::Closed),
+            None => Err(SocketError::Closed),
+        }
+        .inspect_err(|_| self.tx = None)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        self.tx = None;
+        Poll::Ready(Ok(()))
+    }
+}
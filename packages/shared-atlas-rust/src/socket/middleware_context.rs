@@ -0,0 +1,41 @@
+use tokio::sync::{mpsc, watch};
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::socket::connection_state::ConnectionState;
+use crate::socket::socket_error::SocketError;
+use crate::socket::socket_handle::{Command, SocketHandle};
+
+/// Lets middleware talk back to the server.
+/// 允许中间件向服务端发送事件。
+pub struct MiddlewareContext<'a> {
+    namespace: &'a str,
+    commands: &'a mpsc::WeakUnboundedSender<Command>,
+    state: &'a watch::Receiver<ConnectionState>,
+}
+
+impl<'a> MiddlewareContext<'a> {
+    pub(crate) fn new(
+        namespace: &'a str,
+        commands: &'a mpsc::WeakUnboundedSender<Command>,
+        state: &'a watch::Receiver<ConnectionState>,
+    ) -> Self {
+        MiddlewareContext { namespace, commands, state }
+    }
+
+    pub fn namespace(&self) -> &str {
+        self.namespace
+    }
+
+    /// Queues an event for the server, after the current one is handled.
+    /// 在当前事件处理完后向服务端发送事件。
+    pub fn emit(&self, event: AtlasClientEvent) -> Result<(), SocketError> {
+        self.handle().ok_or(SocketError::Closed)?.emit(event)
+    }
+
+    /// A handle for async work spawned by the middleware. `None` once the
+    /// client owning the connection has been dropped.
+    /// 供中间件派生的异步任务使用的句柄；拥有连接的客户端被丢弃后为 `None`。
+    pub fn handle(&self) -> Option<SocketHandle> {
+        self.commands.upgrade().map(|commands| SocketHandle::new(commands, self.state.clone()))
+    }
+}
@@ -0,0 +1,35 @@
+//! Async socket.io clients for the Atlas gateways (feature `socket`).
+//! Atlas 网关的异步 socket.io 客户端（`socket` 特性）。
+//!
+//! Built on [`crate::protocol`] and [`crate::events`]; the transport is
+//! pluggable so clients can run against an in-process fake gateway.
+//! 基于 [`crate::protocol`] 与 [`crate::events`]，传输层可替换，便于在进程内
+//! 模拟网关上运行。
+
+pub mod chat_event_stream;
+pub mod chat_socket;
+pub mod collaboration_socket;
+pub(crate) mod connection;
+pub mod connection_event;
+pub mod connection_machine;
+pub mod connection_state;
+pub mod connection_state_stream;
+pub mod credentials;
+pub mod delivery_metrics;
+pub mod incoming_event;
+pub mod invalid_transition;
+pub mod memory_transport;
+pub mod middleware_context;
+pub mod note_event;
+pub mod note_subscription;
+pub mod reconnect_policy;
+pub mod reliable_delivery;
+pub mod socket_error;
+pub mod socket_handle;
+pub mod socket_middleware;
+pub mod socket_options;
+pub mod token_event;
+pub mod token_lifecycle;
+pub mod token_provider;
+pub mod transport;
+pub mod websocket_transport;
@@ -0,0 +1,50 @@
+use crate::interfaces::collaboration_error_payload::CollaborationErrorPayload;
+use crate::interfaces::collaboration_limit_payload::CollaborationLimitPayload;
+use crate::interfaces::cursor_update_payload::CursorUpdatePayload;
+use crate::interfaces::icollaborator::ICollaborator;
+use crate::interfaces::presence_join_payload::PresenceJoinPayload;
+use crate::interfaces::presence_leave_payload::PresenceLeavePayload;
+use crate::interfaces::yjs_sync_payload::YjsSyncPayload;
+use crate::interfaces::yjs_update_payload::YjsUpdatePayload;
+
+/// An event of one joined note, yielded by a `NoteSubscription`.
+/// 已加入笔记的事件，由 `NoteSubscription` 产出。
+#[derive(Debug, Clone)]
+pub enum NoteEvent {
+/// Full document state, sent once after `note:join`.
+/// 加入后发送一次的完整文档状态。
+    Sync(YjsSyncPayload),
+/// An incremental Y.js update from another editor.
+/// 其他编辑者的增量 Y.js 更新。
+    Update(YjsUpdatePayload),
+/// Another editor moved their cursor or selection.
+/// 其他编辑者移动了光标或选区。
+    Cursor(CursorUpdatePayload),
+/// Editors already on the note, sent after `Sync`.
+/// 已在笔记上的编辑者列表，在 `Sync` 之后发送。
+    Collaborators(Vec<ICollaborator>),
+/// An editor joined. The gateway does not say which note; it is delivered
+/// to every subscription.
+/// 有编辑者加入。网关不区分笔记，会投递给所有订阅。
+    PresenceJoin(PresenceJoinPayload),
+/// An editor left. Delivered to every subscription, like `PresenceJoin`.
+/// 有编辑者离开。与 `PresenceJoin` 一样投递给所有订阅。
+    PresenceLeave(PresenceLeavePayload),
+/// The join was refused because the note has too many editors. Final event.
+/// 编辑者过多，加入被拒绝。最后一个事件。
+    Limit(CollaborationLimitPayload),
+/// The join was refused, e.g. "No edit permission". Final event.
+/// 加入被拒绝，例如没有编辑权限。最后一个事件。
+    Rejected(CollaborationErrorPayload),
+/// The connection was closed. Final event.
+/// 连接已关闭。最后一个事件。
+    Disconnected(String),
+}
+
+impl NoteEvent {
+    /// Whether the subscription ends after this event.
+    /// 该事件之后订阅是否结束。
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, NoteEvent::Limit(_) | NoteEvent::Rejected(_) | NoteEvent::Disconnected(_))
+    }
+}
@@ -0,0 +1,95 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use futures_util::Stream;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::cursor_update_payload::CursorUpdatePayload;
+use crate::interfaces::note_leave_payload::NoteLeavePayload;
+use crate::interfaces::yjs_update_payload::YjsUpdatePayload;
+use crate::socket::collaboration_socket::CollaborationRouter;
+use crate::socket::note_event::NoteEvent;
+use crate::socket::socket_error::SocketError;
+use crate::socket::socket_handle::SocketHandle;
+
+/// Events of one joined note, as an async stream.
+/// 已加入笔记的事件流。
+///
+/// Ends after a terminal event (`Limit`, `Rejected`, `Disconnected`).
+/// Dropping it sends `note:leave`.
+/// 在终止事件后结束；丢弃时发送 `note:leave`。
+pub struct NoteSubscription {
+    id: u64,
+    note_id: NoteId,
+    rx: mpsc::UnboundedReceiver<NoteEvent>,
+    handle: SocketHandle,
+    router: Arc<CollaborationRouter>,
+}
+
+impl NoteSubscription {
+    pub(crate) fn new(
+        id: u64,
+        note_id: NoteId,
+        rx: mpsc::UnboundedReceiver<NoteEvent>,
+        handle: SocketHandle,
+        router: Arc<CollaborationRouter>,
+    ) -> Self {
+        NoteSubscription { id, note_id, rx, handle, router }
+    }
+
+    pub fn note_id(&self) -> &NoteId {
+        &self.note_id
+    }
+
+    /// Broadcasts a Y.js update (raw bytes) to the other editors.
+    /// 向其他编辑者广播 Y.js 更新（原始字节）。
+    pub fn push_update(&self, update: &[u8]) -> Result<(), SocketError> {
+        self.handle.emit(AtlasClientEvent::YjsUpdate(YjsUpdatePayload {
+            note_id: self.note_id.clone(),
+            update: BASE64.encode(update),
+        }))
+    }
+
+    /// Broadcasts the local cursor position and selection.
+    /// 广播本地光标位置与选区。
+    pub fn move_cursor(&self, position: Option<Value>, selection: Option<Value>) -> Result<(), SocketError> {
+        self.handle.emit(AtlasClientEvent::CursorUpdate(CursorUpdatePayload {
+            note_id: self.note_id.clone(),
+            position,
+            selection,
+            user_id: None,
+        }))
+    }
+
+    /// Leaves the note. Same as dropping the subscription.
+    /// 离开笔记，与丢弃订阅相同。
+    pub fn leave(self) {}
+}
+
+impl Stream for NoteSubscription {
+    type Item = NoteEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<NoteEvent>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl Drop for NoteSubscription {
+    fn drop(&mut self) {
+        if self.router.unsubscribe(&self.note_id, self.id) {
+            let _ = self.handle.emit(AtlasClientEvent::NoteLeave(NoteLeavePayload { note_id: self.note_id.clone() }));
+        }
+    }
+}
+
+impl std::fmt::Debug for NoteSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NoteSubscription").field("note_id", &self.note_id).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+use crate::socket::connection_machine::DEFAULT_MAX_RETRIES;
+
+/// When and how often a lost gateway connection is re-established.
+/// 网关连接断开后的重连时机与次数。
+///
+/// The delay before retry `n` (from 0) is `min(initial_delay * 2^n, max_delay)`,
+/// the server's formula, with "equal jitter": half of it is fixed and the
+/// other half random, so clients dropped together do not reconnect together.
+/// 第 `n` 次（从 0 开始）重试前的延迟为 `min(initial_delay * 2^n, max_delay)`，
+/// 与服务端一致；其中一半固定、一半随机，避免同时断开的客户端同时重连。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    /// Retries after each loss of a ready connection.
+    /// 每次就绪连接断开后的重试次数。
+    pub max_retries: u32,
+}
+
+impl ReconnectPolicy {
+    /// Delay without jitter before retry `attempt`.
+    /// 第 `attempt` 次重试前不含抖动的延迟。
+    pub fn base_delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        self.initial_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    /// Delay before retry `attempt`, between half and all of `base_delay`.
+    /// 第 `attempt` 次重试前的延迟，介于 `base_delay` 的一半与全部之间。
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let base = self.base_delay(attempt);
+        let half = base / 2;
+        half + jitter(base - half)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+}
+
+/// Uniform-ish random duration in `[0, bound]`, seeded by `RandomState`'s
+/// per-instance keys to avoid a `rand` dependency.
+fn jitter(bound: Duration) -> Duration {
+    let bound = bound.as_nanos() as u64;
+    if bound == 0 {
+        return Duration::ZERO;
+    }
+    let mut hasher = RandomState::new().build_hasher();
+    if let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(now.as_nanos());
+    }
+    Duration::from_nanos(hasher.finish() % (bound + 1))
+}
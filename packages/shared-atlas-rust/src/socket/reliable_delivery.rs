@@ -0,0 +1,95 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::interfaces::ws_message_ack_payload::WsMessageAckPayload;
+use crate::socket::delivery_metrics::DeliveryMetrics;
+use crate::socket::incoming_event::IncomingEvent;
+use crate::socket::middleware_context::MiddlewareContext;
+use crate::socket::socket_middleware::{MiddlewareFlow, SocketMiddleware};
+
+/// Default number of message ids remembered for de-duplication.
+/// 默认用于去重的消息 ID 数量。
+pub const DEFAULT_SEEN_CAPACITY: usize = 1024;
+
+/// Client side of the server's `ReliableMessageService`.
+/// 服务端 `ReliableMessageService` 的客户端实现。
+///
+/// Acknowledges every event carrying a `messageId` with `message:ack` and
+/// drops events whose id was already seen, since the server resends
+/// unacknowledged messages (up to 3 times) after a reconnect.
+/// 对每个带 `messageId` 的事件回复 `message:ack`，并丢弃已处理过的事件；
+/// 服务端会在重连后重发未确认的消息（最多 3 次）。
+///
+/// Duplicates are acknowledged again, so the server stops resending them.
+/// 重复事件同样会被确认，以便服务端停止重发。
+pub struct ReliableDelivery {
+    seen: Mutex<SeenSet>,
+    metrics: Arc<DeliveryMetrics>,
+}
+
+impl ReliableDelivery {
+    /// Remembers the last `capacity` message ids.
+    /// 记录最近 `capacity` 个消息 ID。
+    pub fn new(capacity: usize) -> Self {
+        ReliableDelivery {
+            seen: Mutex::new(SeenSet::new(capacity)),
+            metrics: Arc::new(DeliveryMetrics::default()),
+        }
+    }
+
+    pub fn metrics(&self) -> Arc<DeliveryMetrics> {
+        self.metrics.clone()
+    }
+}
+
+impl Default for ReliableDelivery {
+    fn default() -> Self {
+        ReliableDelivery::new(DEFAULT_SEEN_CAPACITY)
+    }
+}
+
+impl SocketMiddleware for ReliableDelivery {
+    fn on_event(&self, event: &IncomingEvent, ctx: &MiddlewareContext<'_>) -> MiddlewareFlow {
+        let Some(message_id) = event.message_id() else {
+            return MiddlewareFlow::Continue;
+        };
+        self.metrics.record_received();
+        let ack = AtlasClientEvent::MessageAck(WsMessageAckPayload { message_id: message_id.to_owned() });
+        self.metrics.record_ack(ctx.emit(ack).is_ok());
+        if self.seen.lock().unwrap().insert(message_id) {
+            MiddlewareFlow::Continue
+        } else {
+            self.metrics.record_duplicate();
+            MiddlewareFlow::Drop
+        }
+    }
+}
+
+/// Insertion-ordered set that forgets its oldest entry when full.
+struct SeenSet {
+    capacity: usize,
+    order: VecDeque<String>,
+    ids: HashSet<String>,
+}
+
+impl SeenSet {
+    fn new(capacity: usize) -> Self {
+        SeenSet { capacity: capacity.max(1), order: VecDeque::new(), ids: HashSet::new() }
+    }
+
+    /// Returns `false` if `id` was already present.
+    fn insert(&mut self, id: &str) -> bool {
+        if self.ids.contains(id) {
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.order.push_back(id.to_owned());
+        self.ids.insert(id.to_owned());
+        true
+    }
+}
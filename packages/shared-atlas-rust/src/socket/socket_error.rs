@@ -0,0 +1,97 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::error::atlas_ws_error::AtlasWsError;
+use crate::ids::note_id::NoteId;
+use crate::interfaces::web_socket_error_code::ErrorDisposition;
+use crate::protocol::protocol_error::ProtocolError;
+
+/// Failure of a gateway connection.
+/// 网关连接失败。
+#[derive(Debug)]
+pub enum SocketError {
+    /// The underlying transport failed (TCP, TLS, WebSocket handshake...).
+    Transport(String),
+    /// The peer sent something that is not valid Engine.IO / Socket.IO.
+    Protocol(ProtocolError),
+    /// The server refused the namespace `CONNECT` (`CONNECT_ERROR` packet).
+    ConnectRefused(String),
+    /// The gateway sent an `error` frame and closed the connection.
+    Gateway(AtlasWsError),
+    /// The server stopped answering pings, or the handshake took too long.
+    Timeout,
+    /// The connection is closed; no more events can be sent.
+    Closed,
+    /// Another `chat:stream` response is still being received.
+    StreamInProgress,
+    /// The note is already joined on this connection.
+    AlreadyJoined(NoteId),
+    /// An outgoing payload could not be serialized.
+    Json(serde_json::Error),
+    /// The `TokenProvider` could not refresh the access token.
+    TokenRefresh(String),
+}
+
+impl SocketError {
+    /// Whether a lost connection that failed with this error is worth
+    /// re-establishing. Gateway errors follow their `WebSocketErrorCode`.
+    /// 因此错误断开的连接是否值得重连；网关错误按其 `WebSocketErrorCode` 判断。
+    pub fn disposition(&self) -> ErrorDisposition {
+        match self {
+            SocketError::Transport(_) | SocketError::Timeout | SocketError::Closed => ErrorDisposition::Retry,
+            SocketError::Gateway(err) => err.disposition(),
+            SocketError::TokenRefresh(_) => ErrorDisposition::Reauthenticate,
+            SocketError::Protocol(_)
+            | SocketError::ConnectRefused(_)
+            | SocketError::StreamInProgress
+            | SocketError::AlreadyJoined(_)
+            | SocketError::Json(_) => ErrorDisposition::Fatal,
+        }
+    }
+}
+
+impl fmt::Display for SocketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SocketError::Transport(reason) => write!(f, "transport error: {reason}"),
+            SocketError::Protocol(err) => write!(f, "protocol error: {err}"),
+            SocketError::ConnectRefused(reason) => write!(f, "connection refused: {reason}"),
+            SocketError::Gateway(err) => write!(f, "{err}"),
+            SocketError::Timeout => write!(f, "connection timed out"),
+            SocketError::Closed => write!(f, "connection closed"),
+            SocketError::StreamInProgress => write!(f, "a chat stream is already in progress"),
+            SocketError::AlreadyJoined(note_id) => write!(f, "note {note_id} is already joined"),
+            SocketError::Json(err) => write!(f, "invalid payload: {err}"),
+            SocketError::TokenRefresh(reason) => write!(f, "token refresh failed: {reason}"),
+        }
+    }
+}
+
+impl Error for SocketError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SocketError::Protocol(err) => Some(err),
+            SocketError::Gateway(err) => Some(err),
+            SocketError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProtocolError> for SocketError {
+    fn from(err: ProtocolError) -> Self {
+        SocketError::Protocol(err)
+    }
+}
+
+impl From<AtlasWsError> for SocketError {
+    fn from(err: AtlasWsError) -> Self {
+        SocketError::Gateway(err)
+    }
+}
+
+impl From<serde_json::Error> for SocketError {
+    fn from(err: serde_json::Error) -> Self {
+        SocketError::Json(err)
+    }
+}
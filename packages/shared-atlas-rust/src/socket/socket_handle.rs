@@ -0,0 +1,62 @@
+use tokio::sync::{mpsc, watch};
+
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::socket::connection_state::ConnectionState;
+use crate::socket::connection_state_stream::ConnectionStateStream;
+use crate::socket::socket_error::SocketError;
+
+pub(crate) enum Command {
+    Emit(AtlasClientEvent),
+    Close,
+}
+
+/// Cheap, cloneable sender for a running gateway connection.
+/// 正在运行的网关连接的轻量、可克隆发送端。
+#[derive(Debug, Clone)]
+pub struct SocketHandle {
+    commands: mpsc::UnboundedSender<Command>,
+    state: watch::Receiver<ConnectionState>,
+}
+
+impl SocketHandle {
+    pub(crate) fn new(commands: mpsc::UnboundedSender<Command>, state: watch::Receiver<ConnectionState>) -> Self {
+        SocketHandle { commands, state }
+    }
+
+    /// Queues an event for the server.
+    /// 将事件加入发送队列。
+    pub fn emit(&self, event: AtlasClientEvent) -> Result<(), SocketError> {
+        self.commands.send(Command::Emit(event)).map_err(|_| SocketError::Closed)
+    }
+
+    /// Leaves the namespace and closes the transport.
+    /// 离开命名空间并关闭传输。
+    pub fn close(&self) {
+        let _ = self.commands.send(Command::Close);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.commands.is_closed()
+    }
+
+    /// Current state of the connection.
+    /// 连接的当前状态。
+    pub fn state(&self) -> ConnectionState {
+        *self.state.borrow()
+    }
+
+    /// Observes state changes, e.g. for a status indicator or health check.
+    /// 监听状态变化，例如用于状态指示或健康检查。
+    pub fn state_changes(&self) -> ConnectionStateStream {
+        ConnectionStateStream::new(self.state.clone())
+    }
+}
+
+impl std::fmt::Debug for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Emit(event) => write!(f, "Emit({})", event.name()),
+            Command::Close => write!(f, "Close"),
+        }
+    }
+}
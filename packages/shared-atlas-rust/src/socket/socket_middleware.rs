@@ -0,0 +1,25 @@
+use crate::socket::incoming_event::IncomingEvent;
+use crate::socket::middleware_context::MiddlewareContext;
+
+/// What happens to an event after a middleware has seen it.
+/// 中间件处理后事件的去向。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiddlewareFlow {
+    /// Pass the event on to the next middleware and then to the client.
+    Continue,
+    /// Swallow the event.
+    Drop,
+}
+
+/// Hook into every event a gateway connection receives, before it reaches
+/// the chat or collaboration client. Registered with
+/// `SocketOptions::with_middleware` and run in registration order.
+/// 在事件到达聊天或协作客户端之前拦截连接收到的每个事件。
+/// 通过 `SocketOptions::with_middleware` 注册，按注册顺序执行。
+///
+/// Runs on the connection task, so it must not block; long work should be
+/// spawned with a handle from [`MiddlewareContext::handle`].
+/// 在连接任务中运行，不可阻塞；耗时操作应通过 `MiddlewareContext::handle` 派生任务处理。
+pub trait SocketMiddleware: Send + Sync + 'static {
+    fn on_event(&self, event: &IncomingEvent, ctx: &MiddlewareContext<'_>) -> MiddlewareFlow;
+}
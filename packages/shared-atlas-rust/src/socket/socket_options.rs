@@ -0,0 +1,156 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::protocol::{ENGINE_IO_PATH, ENGINE_IO_VERSION};
+use crate::socket::credentials::Credentials;
+use crate::socket::socket_error::SocketError;
+use crate::socket::reconnect_policy::ReconnectPolicy;
+use crate::socket::socket_middleware::SocketMiddleware;
+use crate::socket::token_lifecycle::TokenLifecycle;
+use crate::socket::token_provider::TokenProvider;
+use crate::socket::transport::{connector, Connector, Transport};
+use crate::socket::websocket_transport::WebSocketTransport;
+
+/// Settings shared by the gateway clients.
+/// 各网关客户端共用的连接设置。
+#[derive(Clone)]
+pub struct SocketOptions {
+    /// Server origin, e.g. `https://atlas.example.com`.
+    /// 服务端地址。
+    pub url: String,
+    /// Tokens sent as the `auth.token` of the namespace `CONNECT`; shared with
+    /// `TokenLifecycle` so refreshed tokens are used on reconnect.
+    /// 作为命名空间 `CONNECT` 的 `auth.token` 发送的令牌；与 `TokenLifecycle`
+    /// 共享，重连时使用刷新后的令牌。
+    pub credentials: Credentials,
+    /// Engine.IO path (default `/socket.io/`).
+    /// Engine.IO 路径（默认 `/socket.io/`）。
+    pub path: String,
+    /// Time allowed for the Engine.IO and namespace handshakes.
+    /// Engine.IO 与命名空间握手的超时时间。
+    pub connect_timeout: Duration,
+    /// How long `ChatSocket::send` waits for the rest of a dropped stream's
+    /// response before giving up on it.
+    /// `ChatSocket::send` 等待已丢弃流的剩余回复的最长时间，超时后放弃该回复。
+    pub drain_timeout: Duration,
+    /// Re-establishes lost connections when set (default: off).
+    /// 设置后自动重建断开的连接（默认关闭）。
+    pub reconnect: Option<ReconnectPolicy>,
+    pub(crate) connector: Connector,
+    pub(crate) middleware: Vec<Arc<dyn SocketMiddleware>>,
+    pub(crate) token_provider: Option<Arc<dyn TokenProvider>>,
+}
+
+impl SocketOptions {
+    pub fn new(url: impl Into<String>, token: impl Into<String>) -> Self {
+        SocketOptions {
+            url: url.into(),
+            credentials: Credentials::new(token),
+            path: ENGINE_IO_PATH.to_owned(),
+            connect_timeout: Duration::from_secs(20),
+            drain_timeout: Duration::from_secs(30),
+            reconnect: None,
+            connector: connector(WebSocketTransport::connect),
+            middleware: Vec::new(),
+            token_provider: None,
+        }
+    }
+
+    /// Replaces the WebSocket transport, e.g. with an in-process fake gateway.
+    /// 替换 WebSocket 传输，例如使用进程内的模拟网关。
+    pub fn with_connector<F, Fut, T>(mut self, connect: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<T, SocketError>> + Send + 'static,
+        T: Transport,
+    {
+        self.connector = connector(connect);
+        self
+    }
+
+    /// Adds a middleware; middleware runs in the order it was added.
+    /// 添加中间件，按添加顺序执行。
+    pub fn with_middleware(mut self, middleware: impl SocketMiddleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the refresh token handed to the `TokenProvider`.
+    /// 设置传给 `TokenProvider` 的刷新令牌。
+    pub fn with_refresh_token(self, refresh_token: impl Into<String>) -> Self {
+        self.credentials.set_refresh_token(Some(refresh_token.into()));
+        self
+    }
+
+    /// Refreshes tokens through `provider`: answers `auth:token-expiring`
+    /// (see [`TokenLifecycle`]) and, with reconnection enabled, refreshes
+    /// before reconnecting after an auth error.
+    /// 通过 `provider` 刷新令牌：响应 `auth:token-expiring`（见 [`TokenLifecycle`]），
+    /// 并在启用重连时于认证错误后先刷新再重连。
+    ///
+    /// Use [`SocketOptions::with_token_lifecycle`] instead to subscribe to
+    /// the refresh events.
+    /// 如需订阅刷新事件，请改用 [`SocketOptions::with_token_lifecycle`]。
+    pub fn with_token_provider(self, provider: impl TokenProvider) -> Self {
+        let lifecycle = TokenLifecycle::with_provider(Arc::new(provider), self.credentials.clone());
+        self.with_token_lifecycle(lifecycle)
+    }
+
+    /// Like [`SocketOptions::with_token_provider`], with a lifecycle built by
+    /// the caller from these options' `credentials`, e.g. to
+    /// [`subscribe`](TokenLifecycle::subscribe) to it first.
+    /// 同 [`SocketOptions::with_token_provider`]，但使用调用方以本设置的
+    /// `credentials` 构建的 lifecycle，例如先对其 `subscribe`。
+    pub fn with_token_lifecycle(mut self, lifecycle: TokenLifecycle) -> Self {
+        self.token_provider = Some(lifecycle.provider());
+        self.middleware.push(Arc::new(lifecycle));
+        self
+    }
+
+    /// Enables reconnection after the connection is lost.
+    /// 启用断线重连。
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    pub fn with_drain_timeout(mut self, timeout: Duration) -> Self {
+        self.drain_timeout = timeout;
+        self
+    }
+
+    /// The Engine.IO WebSocket URL, e.g.
+    /// `https://host/socket.io/?EIO=4&transport=websocket`.
+    /// Engine.IO WebSocket 连接地址。
+    pub fn engine_url(&self) -> String {
+        let path = self.path.trim_start_matches('/');
+        format!(
+            "{}/{path}?EIO={ENGINE_IO_VERSION}&transport=websocket",
+            self.url.trim_end_matches('/')
+        )
+    }
+}
+
+impl std::fmt::Debug for SocketOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SocketOptions")
+            .field("url", &self.url)
+            .field("credentials", &self.credentials)
+            .field("path", &self.path)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("drain_timeout", &self.drain_timeout)
+            .field("reconnect", &self.reconnect)
+            .field("middleware", &self.middleware.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,22 @@
+/// Progress of a token refresh driven by `TokenLifecycle`.
+/// `TokenLifecycle` 驱动的令牌刷新进度。
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenEvent {
+    /// The server announced `auth:token-expiring`; seconds left on the token.
+    /// 服务端发送了 `auth:token-expiring`，值为令牌剩余秒数。
+    Expiring { expires_in: f64 },
+    /// A new token was obtained and sent as `auth:token-refreshed`.
+    /// 已获取新令牌并通过 `auth:token-refreshed` 发送。
+    Refreshed,
+    /// The server accepted the new token (`auth:token-renewed`).
+    /// 服务端已接受新令牌（`auth:token-renewed`）。
+    Renewed,
+    /// The `TokenProvider` failed; the connection will end with
+    /// `AuthTokenExpired` (4012) unless a later refresh succeeds.
+    /// `TokenProvider` 刷新失败；除非之后刷新成功，否则连接将以
+    /// `AuthTokenExpired`（4012）结束。
+    RefreshFailed(String),
+    /// The server rejected the new token (`auth:error`).
+    /// 服务端拒绝了新令牌（`auth:error`）。
+    Rejected(String),
+}
@@ -0,0 +1,153 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tokio::sync::broadcast;
+
+use crate::dto::refresh_token_dto::RefreshTokenDto;
+use crate::events::atlas_client_event::AtlasClientEvent;
+use crate::events::atlas_server_event::AtlasServerEvent;
+use crate::interfaces::ws_token_refreshed_payload::WsTokenRefreshedPayload;
+use crate::socket::connection_state::ConnectionState;
+use crate::socket::credentials::Credentials;
+use crate::socket::incoming_event::IncomingEvent;
+use crate::socket::middleware_context::MiddlewareContext;
+use crate::socket::socket_middleware::{MiddlewareFlow, SocketMiddleware};
+use crate::socket::token_event::TokenEvent;
+use crate::socket::token_provider::TokenProvider;
+
+/// Default time to wait for `auth:token-renewed` or `auth:error` before
+/// another `auth:token-expiring` may start a new refresh.
+/// 等待 `auth:token-renewed` 或 `auth:error` 的默认时长，超时后新的
+/// `auth:token-expiring` 可再次触发刷新。
+pub const DEFAULT_RENEWAL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Client side of the server's `TokenLifecycleService`.
+/// 服务端 `TokenLifecycleService` 的客户端实现。
+///
+/// On `auth:token-expiring` (sent 5 minutes before the JWT expires) the
+/// provider is asked for a new token pair, the shared [`Credentials`] are
+/// updated and the new access token is sent as `auth:token-refreshed`. The
+/// server answers with `auth:token-renewed` or `auth:error`.
+/// 收到 `auth:token-expiring`（JWT 过期前 5 分钟发送）后，通过 provider 获取新
+/// 令牌对，更新共享的 [`Credentials`]，并以 `auth:token-refreshed` 发送新的访问
+/// 令牌；服务端回复 `auth:token-renewed` 或 `auth:error`。
+///
+/// Only one refresh runs at a time; warnings arriving meanwhile are ignored.
+/// A refresh whose answer never arrives, because the connection dropped or
+/// the renewal timeout passed, no longer blocks the next one.
+/// 同一时间只进行一次刷新，期间收到的提醒会被忽略。若连接断开或超过续期超时仍未
+/// 收到答复，该次刷新不再阻止下一次刷新。
+pub struct TokenLifecycle {
+    provider: Arc<dyn TokenProvider>,
+    credentials: Credentials,
+    /// Id of the running refresh, `0` when idle.
+    in_flight: Arc<AtomicU64>,
+    attempts: AtomicU64,
+    renewal_timeout: Duration,
+    events: broadcast::Sender<TokenEvent>,
+}
+
+impl TokenLifecycle {
+    /// Pass the credentials of the `SocketOptions` the middleware is added to,
+    /// so reconnects use the refreshed token.
+    /// 应传入所属 `SocketOptions` 的凭据，使重连时使用刷新后的令牌。
+    pub fn new(provider: impl TokenProvider, credentials: Credentials) -> Self {
+        TokenLifecycle::with_provider(Arc::new(provider), credentials)
+    }
+
+    pub(crate) fn with_provider(provider: Arc<dyn TokenProvider>, credentials: Credentials) -> Self {
+        let (events, _) = broadcast::channel(16);
+        TokenLifecycle {
+            provider,
+            credentials,
+            in_flight: Arc::new(AtomicU64::new(0)),
+            attempts: AtomicU64::new(0),
+            renewal_timeout: DEFAULT_RENEWAL_TIMEOUT,
+            events,
+        }
+    }
+
+    /// How long to wait for the server to answer `auth:token-refreshed`.
+    /// 等待服务端答复 `auth:token-refreshed` 的时长。
+    pub fn with_renewal_timeout(mut self, timeout: Duration) -> Self {
+        self.renewal_timeout = timeout;
+        self
+    }
+
+    /// Receives the [`TokenEvent`]s published from now on.
+    /// 订阅此后发布的 [`TokenEvent`]。
+    pub fn subscribe(&self) -> broadcast::Receiver<TokenEvent> {
+        self.events.subscribe()
+    }
+
+    pub(crate) fn provider(&self) -> Arc<dyn TokenProvider> {
+        self.provider.clone()
+    }
+
+    fn refresh(&self, ctx: &MiddlewareContext<'_>) {
+        let Some(handle) = ctx.handle() else {
+            return;
+        };
+        let attempt = self.attempts.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.in_flight.compare_exchange(0, attempt, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            return;
+        }
+        let provider = self.provider.clone();
+        let credentials = self.credentials.clone();
+        let in_flight = self.in_flight.clone();
+        let renewal_timeout = self.renewal_timeout;
+        let events = self.events.clone();
+        tokio::spawn(async move {
+            let request = RefreshTokenDto { refresh_token: credentials.refresh_token() };
+            let result = match provider.refresh(request).await {
+                Ok(tokens) => {
+                    credentials.update(&tokens);
+                    let refreshed = WsTokenRefreshedPayload { new_token: tokens.access_token };
+                    handle.emit(AtlasClientEvent::TokenRefreshed(refreshed)).map_err(|err| err.to_string())
+                }
+                Err(err) => Err(err.to_string()),
+            };
+            match result {
+                Ok(()) => {
+                    let _ = events.send(TokenEvent::Refreshed);
+                    // Only the state is watched from here on, so this task
+                    // does not keep a dropped client's connection open. Any
+                    // change but the switch to `Authenticating` our emit causes
+                    // means the refresh was answered or the connection dropped.
+                    let states = handle.state_changes().skip(1);
+                    drop(handle);
+                    let settled = states.filter(|state| std::future::ready(*state != ConnectionState::Authenticating));
+                    let _ = tokio::time::timeout(renewal_timeout, std::pin::pin!(settled).next()).await;
+                    let _ = in_flight.compare_exchange(attempt, 0, Ordering::AcqRel, Ordering::Acquire);
+                }
+                Err(message) => {
+                    in_flight.store(0, Ordering::Release);
+                    let _ = events.send(TokenEvent::RefreshFailed(message));
+                }
+            }
+        });
+    }
+}
+
+impl SocketMiddleware for TokenLifecycle {
+    fn on_event(&self, event: &IncomingEvent, ctx: &MiddlewareContext<'_>) -> MiddlewareFlow {
+        match &event.event {
+            AtlasServerEvent::TokenExpiring(payload) => {
+                let _ = self.events.send(TokenEvent::Expiring { expires_in: payload.expires_in });
+                self.refresh(ctx);
+            }
+            AtlasServerEvent::TokenRenewed(_) => {
+                self.in_flight.store(0, Ordering::Release);
+                let _ = self.events.send(TokenEvent::Renewed);
+            }
+            AtlasServerEvent::AuthError(payload) => {
+                self.in_flight.store(0, Ordering::Release);
+                let _ = self.events.send(TokenEvent::Rejected(payload.message.clone()));
+            }
+            _ => {}
+        }
+        MiddlewareFlow::Continue
+    }
+}
@@ -0,0 +1,28 @@
+use std::future::Future;
+
+use crate::dto::refresh_token_dto::RefreshTokenDto;
+use crate::interfaces::token_response::TokenResponse;
+use crate::socket::transport::BoxFuture;
+
+/// Error returned by a [`TokenProvider`].
+/// [`TokenProvider`] 返回的错误。
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Obtains a new token pair, usually by calling `POST /auth/refresh`.
+/// 获取新的令牌对，通常通过调用 `POST /auth/refresh` 实现。
+///
+/// Implemented for async closures taking a [`RefreshTokenDto`].
+/// 接收 [`RefreshTokenDto`] 的异步闭包自动实现该 trait。
+pub trait TokenProvider: Send + Sync + 'static {
+    fn refresh(&self, request: RefreshTokenDto) -> BoxFuture<Result<TokenResponse, BoxError>>;
+}
+
+impl<F, Fut> TokenProvider for F
+where
+    F: Fn(RefreshTokenDto) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<TokenResponse, BoxError>> + Send + 'static,
+{
+    fn refresh(&self, request: RefreshTokenDto) -> BoxFuture<Result<TokenResponse, BoxError>> {
+        Box::pin(self(request))
+    }
+}
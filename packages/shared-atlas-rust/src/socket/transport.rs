@@ -0,0 +1,53 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures_util::{Sink, Stream};
+
+use crate::protocol::frame::Frame;
+use crate::socket::socket_error::SocketError;
+
+/// A bidirectional frame channel to an Engine.IO server.
+/// 与 Engine.IO 服务端之间的双向帧通道。
+///
+/// Implemented for anything that is both a `Stream` of incoming frames and a
+/// `Sink` of outgoing ones, e.g. [`WebSocketTransport`] or [`MemoryTransport`].
+/// 任何同时实现了入站帧 `Stream` 与出站帧 `Sink` 的类型都自动实现该 trait。
+///
+/// [`WebSocketTransport`]: crate::socket::websocket_transport::WebSocketTransport
+/// [`MemoryTransport`]: crate::socket::memory_transport::MemoryTransport
+pub trait Transport:
+    Stream<Item = Result<Frame, SocketError>> + Sink<Frame, Error = SocketError> + Send + Unpin + 'static
+{
+}
+
+impl<T> Transport for T where
+    T: Stream<Item = Result<Frame, SocketError>> + Sink<Frame, Error = SocketError> + Send + Unpin + 'static
+{
+}
+
+pub type BoxTransport = Box<dyn Transport>;
+
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// Opens a transport to the given Engine.IO URL
+/// (`ws://host/socket.io/?EIO=4&transport=websocket`).
+/// 打开到指定 Engine.IO URL 的传输通道。
+///
+/// Called again for every reconnect.
+/// 每次重连都会重新调用。
+pub type Connector = Arc<dyn Fn(String) -> BoxFuture<Result<BoxTransport, SocketError>> + Send + Sync>;
+
+/// Wraps an async function as a [`Connector`].
+/// 将异步函数包装为 [`Connector`]。
+pub fn connector<F, Fut, T>(connect: F) -> Connector
+where
+    F: Fn(String) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, SocketError>> + Send + 'static,
+    T: Transport,
+{
+    Arc::new(move |url| {
+        let fut = connect(url);
+        Box::pin(async move { fut.await.map(|transport| Box::new(transport) as BoxTransport) })
+    })
+}
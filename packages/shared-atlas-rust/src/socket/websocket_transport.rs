@@ -0,0 +1,81 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{Sink, Stream};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::protocol::frame::Frame;
+use crate::socket::socket_error::SocketError;
+
+/// Engine.IO over a WebSocket connection (`transport=websocket`).
+/// 基于 WebSocket 连接的 Engine.IO 传输。
+pub struct WebSocketTransport {
+    inner: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl WebSocketTransport {
+    /// Opens a WebSocket to an Engine.IO URL. `http(s)://` is mapped to `ws(s)://`.
+    /// 打开到 Engine.IO URL 的 WebSocket 连接，`http(s)://` 会转换为 `ws(s)://`。
+    pub async fn connect(url: String) -> Result<Self, SocketError> {
+        let url = if let Some(rest) = url.strip_prefix("http://") {
+            format!("ws://{rest}")
+        } else if let Some(rest) = url.strip_prefix("https://") {
+            format!("wss://{rest}")
+        } else {
+            url
+        };
+        let (inner, _) = tokio_tungstenite::connect_async(url)
+            .await
+            .map_err(|err| SocketError::Transport(err.to_string()))?;
+        Ok(WebSocketTransport { inner })
+    }
+}
+
+impl Stream for WebSocketTransport {
+    type Item = Result<Frame, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => Poll::Ready(Some(Ok(Frame::Text(text.to_string())))),
+                Poll::Ready(Some(Ok(Message::Binary(bytes)))) => Poll::Ready(Some(Ok(Frame::Binary(bytes.to_vec())))),
+                Poll::Ready(Some(Ok(Message::Close(_)))) => Poll::Ready(None),
+                // WebSocket-level ping/pong is answered by tungstenite itself.
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(SocketError::Transport(err.to_string())))),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            };
+        }
+    }
+}
+
+impl Sink<Frame> for WebSocketTransport {
+    type Error = SocketError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Pin::new(&mut self.inner).poll_ready(cx).map_err(transport_error)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, frame: Frame) -> Result<(), SocketError> {
+        let message = match frame {
+            Frame::Text(text) => Message::text(text),
+            Frame::Binary(bytes) => Message::binary(bytes),
+        };
+        Pin::new(&mut self.inner).start_send(message).map_err(transport_error)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Pin::new(&mut self.inner).poll_flush(cx).map_err(transport_error)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SocketError>> {
+        Pin::new(&mut self.inner).poll_close(cx).map_err(transport_error)
+    }
+}
+
+fn transport_error(err: tokio_tungstenite::tungstenite::Error) -> SocketError {
+    SocketError::Transport(err.to_string())
+}
@@ -0,0 +1,11 @@
+//! Points in time as exchanged with the Atlas API.
+//! 与 Atlas API 交换的时间点。
+//!
+//! The server sends entity dates as ISO-8601 strings and a few fields as
+//! epoch milliseconds. [`timestamp::Timestamp`] accepts both and writes each
+//! value back in the form it was read.
+//! 服务端以 ISO-8601 字符串发送实体日期，少数字段使用纪元毫秒。
+//! [`timestamp::Timestamp`] 同时接受两种形式，并按读取时的形式写回。
+
+pub mod timestamp;
+pub mod timestamp_format;
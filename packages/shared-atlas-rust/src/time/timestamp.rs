@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::time::timestamp_format::TimestampFormat;
+
+/// A point in time, read from an ISO-8601 string or from epoch milliseconds.
+/// 时间点，可从 ISO-8601 字符串或纪元毫秒读取。
+///
+/// Serializes back in the [`TimestampFormat`] it was read in. Comparisons,
+/// ordering and hashing only look at the instant, not at the format.
+/// 序列化时使用读取时的 [`TimestampFormat`]。比较、排序与哈希只看时间点，不看格式。
+#[derive(Debug, Clone, Copy)]
+pub struct Timestamp {
+    at: DateTime<Utc>,
+    format: TimestampFormat,
+}
+
+impl Timestamp {
+    pub fn now() -> Self {
+        Timestamp::from(Utc::now())
+    }
+
+    /// `millis` after the Unix epoch, serialized as a number.
+    /// 纪元后 `millis` 毫秒，序列化为数字。
+    pub fn from_millis(millis: i64) -> Option<Self> {
+        let at = DateTime::from_timestamp_millis(millis)?;
+        Some(Timestamp { at, format: TimestampFormat::EpochMillis })
+    }
+
+    /// The same instant, serialized as `format`.
+    /// 相同时间点，以 `format` 序列化。
+    pub fn with_format(mut self, format: TimestampFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn format(&self) -> TimestampFormat {
+        self.format
+    }
+
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        self.at
+    }
+
+    /// Milliseconds since the Unix epoch.
+    /// 自 Unix 纪元起的毫秒数。
+    pub fn as_millis(&self) -> i64 {
+        self.at.timestamp_millis()
+    }
+
+    /// Time from `earlier` to `self`; `None` if `earlier` is later.
+    /// 从 `earlier` 到 `self` 的时长；`earlier` 更晚时返回 `None`。
+    pub fn duration_since(&self, earlier: &Timestamp) -> Option<Duration> {
+        (self.at - earlier.at).to_std().ok()
+    }
+
+    /// Time passed since `self`; zero if it is in the future.
+    /// 自 `self` 起经过的时长；若在未来则为零。
+    pub fn elapsed(&self) -> Duration {
+        Timestamp::now().duration_since(self).unwrap_or_default()
+    }
+
+    /// Time left until `self`; zero if it has passed.
+    /// 距 `self` 的剩余时长；已过去则为零。
+    pub fn remaining(&self) -> Duration {
+        self.duration_since(&Timestamp::now()).unwrap_or_default()
+    }
+
+    pub fn is_past(&self) -> bool {
+        self.at <= Utc::now()
+    }
+
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let at = self.at.checked_add_signed(chrono::Duration::from_std(duration).ok()?)?;
+        Some(Timestamp { at, format: self.format })
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let at = self.at.checked_sub_signed(chrono::Duration::from_std(duration).ok()?)?;
+        Some(Timestamp { at, format: self.format })
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(at: DateTime<Utc>) -> Self {
+        Timestamp { at, format: TimestampFormat::Iso8601 }
+    }
+}
+
+impl From<Timestamp> for DateTime<Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.at
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.at == other.at
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.at.cmp(&other.at)
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.at.hash(state);
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.at.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.format {
+            TimestampFormat::Iso8601 => serializer.collect_str(self),
+            TimestampFormat::EpochMillis => serializer.serialize_i64(self.as_millis()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Millis(f64),
+            Iso(String),
+        }
+        match Raw::deserialize(deserializer)? {
+            Raw::Millis(millis) => Timestamp::from_millis(millis.round() as i64)
+                .ok_or_else(|| de::Error::custom(format!("timestamp {millis} is out of range"))),
+            Raw::Iso(text) => DateTime::parse_from_rfc3339(&text)
+                .map(|at| Timestamp::from(at.with_timezone(&Utc)))
+                .map_err(|err| de::Error::custom(format!("invalid timestamp {text:?}: {err}"))),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// Wire form of a [`Timestamp`](crate::time::timestamp::Timestamp).
+/// [`Timestamp`](crate::time::timestamp::Timestamp) 的传输形式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TimestampFormat {
+    /// `"2026-01-01T00:00:00.000Z"`, as written by `Date.prototype.toISOString`.
+    /// 与 `Date.prototype.toISOString` 输出一致的字符串。
+    #[default]
+    Iso8601,
+    /// Milliseconds since the Unix epoch, as returned by `Date.now()`.
+    /// 自 Unix 纪元起的毫秒数，与 `Date.now()` 一致。
+    EpochMillis,
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use serde_json::json;
+
+use shared_atlas_rust::error::api_error::ApiError;
+
+#[test]
+fn decodes_validation_messages_into_fields() {
+    let body = json!({
+        "statusCode": 400,
+        "message": ["email must be an email", "password should not be empty", "email should not be empty", "property foo should not exist"],
+        "error": "Bad Request"
+    })
+    .to_string();
+    let err = ApiError::from_response(400, None, body.as_bytes());
+    let ApiError::Validation { fields, body: decoded } = &err else { panic!("expected validation, got {err:?}") };
+    assert_eq!(fields, &["email", "password", "foo"]);
+    assert_eq!(decoded.messages.len(), 4);
+    assert_eq!(decoded.error.as_deref(), Some("Bad Request"));
+    assert_eq!(decoded.raw, body);
+    assert!(err.message().starts_with("email must be an email; password"));
+}
+
+#[test]
+fn classifies_statuses() {
+    let nest = |status: u16, message: &str| json!({ "statusCode": status, "message": message }).to_string();
+
+    let limited = ApiError::from_response(429, Some("30"), nest(429, "ThrottlerException: Too Many Requests").as_bytes());
+    assert!(matches!(limited, ApiError::RateLimited { retry_after: Some(d), .. } if d == Duration::from_secs(30)));
+    assert!(limited.is_retryable());
+
+    assert!(matches!(ApiError::from_response(401, None, nest(401, "Unauthorized").as_bytes()), ApiError::Unauthorized(_)));
+    assert!(matches!(ApiError::from_response(403, None, nest(403, "Forbidden").as_bytes()), ApiError::Forbidden(_)));
+    assert!(matches!(ApiError::from_response(400, None, nest(400, "No file uploaded").as_bytes()), ApiError::Other(_)));
+
+    let gateway = ApiError::from_response(502, None, b"<html>Bad Gateway</html>");
+    assert!(matches!(gateway, ApiError::Server(_)));
+    assert_eq!(gateway.message(), "<html>Bad Gateway</html>");
+}
+
+#[test]
+fn decodes_stream_frames() {
+    let frame = json!({ "statusCode": 404, "message": "Session not found", "error": "Not Found" });
+    let err = ApiError::from_stream_frame(&frame).unwrap();
+    assert!(matches!(err, ApiError::NotFound(_)));
+    assert_eq!(err.to_string(), "server returned 404: Session not found");
+
+    assert!(ApiError::from_stream_frame(&json!({ "type": "done" })).is_none());
+}
+
+#[test]
+fn reads_retry_after_as_seconds_or_http_date() {
+    assert_eq!(ApiError::parse_retry_after(" 120 "), Some(Duration::from_secs(120)));
+    assert_eq!(ApiError::parse_retry_after("18446744073709551615"), Some(Duration::from_secs(u64::MAX)));
+    assert_eq!(ApiError::parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+    assert_eq!(ApiError::parse_retry_after("soon"), None);
+    assert_eq!(ApiError::parse_retry_after("-5"), None);
+
+    let date = (Utc::now() + chrono::Duration::seconds(120)).format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+    let limited = ApiError::from_response(429, Some(&date), b"Too Many Requests");
+    let ApiError::RateLimited { retry_after: Some(wait), .. } = limited else { panic!("expected a delay, got {limited:?}") };
+    assert!(wait > Duration::from_secs(115) && wait <= Duration::from_secs(120), "{wait:?}");
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use axum::http::header::{AUTHORIZATION, COOKIE, SET_COOKIE};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{AppendHeaders, IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{json, Value};
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::client_error::ClientError;
+use shared_atlas_rust::client::social_login_outcome::SocialLoginOutcome;
+use shared_atlas_rust::dto::google_login_dto::GoogleLoginDto;
+use shared_atlas_rust::dto::login_dto::LoginDto;
+use shared_atlas_rust::error::api_error::ApiError;
+
+use mock_http::{nest_error, serve, user, USER_ID};
+
+fn bearer(headers: &HeaderMap) -> bool {
+    headers.get("x-auth-mode").is_some_and(|mode| mode == "bearer")
+}
+
+fn set_tokens(access: &str, refresh: &str, body: Value) -> Response {
+    let cookies = AppendHeaders([
+        (SET_COOKIE, format!("access_token={access}; Path=/; HttpOnly")),
+        (SET_COOKIE, format!("refresh_token={refresh}; Path=/; HttpOnly")),
+    ]);
+    (cookies, Json(body)).into_response()
+}
+
+/// Whether the request carries `token` as bearer header or `access_token` cookie.
+fn presents(headers: &HeaderMap, token: &str) -> bool {
+    let bearer = headers.get(AUTHORIZATION).is_some_and(|value| value == format!("Bearer {token}").as_str());
+    let cookie = headers
+        .get(COOKIE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|cookies| cookies.split("; ").any(|cookie| cookie == format!("access_token={token}")));
+    bearer || cookie
+}
+
+fn api() -> Router {
+    Router::new()
+        .route(
+            "/auth/login",
+            post(|headers: HeaderMap, Json(body): Json<Value>| async move {
+                if body["password"] != "secret" {
+                    return (StatusCode::CREATED, Json(json!({ "message": "Invalid credentials" }))).into_response();
+                }
+                let tokens = json!({ "access_token": "a1", "refresh_token": "r1" });
+                let body = if bearer(&headers) { json!({ "user": user(), "tokens": tokens }) } else { user() };
+                set_tokens("a1", "r1", body)
+            }),
+        )
+        .route(
+            "/auth/refresh",
+            post(|headers: HeaderMap, Json(body): Json<Value>| async move {
+                let cookie = headers.get(COOKIE).and_then(|v| v.to_str().ok()).unwrap_or_default().to_owned();
+                if body["refreshToken"] != "r1" && !cookie.contains("refresh_token=r1") {
+                    return (StatusCode::UNAUTHORIZED, Json(nest_error(401, "Unauthorized"))).into_response();
+                }
+                let body = if bearer(&headers) {
+                    json!({ "tokens": { "access_token": "a2", "refresh_token": "r2" } })
+                } else {
+                    json!({ "message": "Tokens refreshed successfully" })
+                };
+                set_tokens("a2", "r2", body)
+            }),
+        )
+        .route(
+            "/auth/profile",
+            get(|headers: HeaderMap| async move {
+                if presents(&headers, "a1") || presents(&headers, "a2") {
+                    Json(user()).into_response()
+                } else {
+                    (StatusCode::UNAUTHORIZED, Json(nest_error(401, "Unauthorized"))).into_response()
+                }
+            }),
+        )
+        .route(
+            "/auth/google",
+            post(|| async {
+                Json(json!({ "requiresInvite": true, "email": "bob@example.com", "signupToken": "st" }))
+            }),
+        )
+        .route("/auth/logout", post(|| async { Json(json!({ "message": "Logged out successfully" })) }))
+}
+
+fn credentials(password: &str) -> LoginDto {
+    LoginDto { username: "alice".to_owned(), password: password.to_owned() }
+}
+
+#[tokio::test]
+async fn bearer_mode_uses_tokens_from_the_body() {
+    let client = AtlasClient::new(serve(api()).await, AuthMode::Bearer).unwrap();
+
+    let session = client.login(&credentials("secret")).await.unwrap();
+    assert_eq!(session.user.username, "alice");
+    assert_eq!(session.tokens.access_token, "a1");
+    assert_eq!(client.profile().await.unwrap().id, USER_ID);
+
+    let tokens = client.refresh().await.unwrap();
+    assert_eq!((tokens.access_token.as_str(), tokens.refresh_token.as_str()), ("a2", "r2"));
+    assert_eq!(client.tokens().unwrap().access_token, "a2");
+
+    assert_eq!(client.logout().await.unwrap().message, "Logged out successfully");
+    assert!(client.tokens().is_none());
+}
+
+#[tokio::test]
+async fn bearer_mode_ignores_cookies_the_server_sets() {
+    let client = AtlasClient::new(serve(api()).await, AuthMode::Bearer).unwrap();
+    client.login(&credentials("secret")).await.unwrap();
+
+    client.set_tokens(None);
+    let result = client.profile().await;
+    assert!(matches!(result, Err(ClientError::Api(ApiError::Unauthorized(_)))), "{result:?}");
+}
+
+#[tokio::test]
+async fn cookie_mode_keeps_the_session_in_cookies() {
+    let client = AtlasClient::new(serve(api()).await, AuthMode::Cookie).unwrap();
+    assert!(matches!(client.profile().await, Err(ClientError::SessionExpired)));
+
+    let session = client.login(&credentials("secret")).await.unwrap();
+    assert_eq!(session.tokens.refresh_token, "r1");
+    assert_eq!(client.profile().await.unwrap().email, "alice@example.com");
+
+    client.set_tokens(None);
+    assert_eq!(client.refresh().await.unwrap().access_token, "a2");
+}
+
+#[tokio::test]
+async fn reports_login_failures_and_social_signup() {
+    let client = AtlasClient::new(serve(api()).await, AuthMode::Bearer).unwrap();
+    match client.login(&credentials("wrong")).await {
+        Err(ClientError::InvalidCredentials(message)) => assert_eq!(message, "Invalid credentials"),
+        other => panic!("expected InvalidCredentials, got {other:?}"),
+    }
+
+    let google = GoogleLoginDto { code: Some("code".to_owned()), id_token: None };
+    match client.google_login(&google).await.unwrap() {
+        SocialLoginOutcome::SignupRequired(required) => {
+            assert_eq!(required.signup_token, "st");
+            assert_eq!(required.email.as_deref(), Some("bob@example.com"));
+        }
+        other => panic!("expected SignupRequired, got {other:?}"),
+    }
+}
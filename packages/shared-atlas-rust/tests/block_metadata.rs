@@ -0,0 +1,68 @@
+use serde_json::json;
+
+use shared_atlas_rust::interfaces::block_metadata::BlockMetadata;
+use shared_atlas_rust::interfaces::block_type::BlockType;
+use shared_atlas_rust::interfaces::code_block_metadata::CodeBlockMetadata;
+use shared_atlas_rust::interfaces::create_block_dto::CreateBlockDto;
+use shared_atlas_rust::interfaces::iblock::IBlock;
+
+fn block(r#type: &str, metadata: serde_json::Value) -> IBlock {
+    serde_json::from_value(json!({
+        "id": "00000000-0000-4000-8000-000000000001",
+        "noteId": "00000000-0000-4000-8000-000000000002",
+        "type": r#type,
+        "content": "https://storage.example.com/notes/images/a.png",
+        "metadata": metadata,
+        "parentBlockId": null,
+        "position": 1,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "updatedAt": "2026-01-01T00:00:00.000Z",
+        "createdBy": "00000000-0000-4000-8000-000000000003",
+        "lastEditedBy": "00000000-0000-4000-8000-000000000003"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn metadata_is_selected_by_block_type() {
+    let code = json!({ "language": "rust", "wrap": true });
+    match BlockMetadata::from_value(&BlockType::Code, code.clone()) {
+        BlockMetadata::Code(metadata) => {
+            assert_eq!(metadata.language.as_deref(), Some("rust"));
+            assert_eq!(metadata.extra["wrap"], true);
+            assert_eq!(BlockMetadata::Code(metadata).to_value(), code);
+        }
+        other => panic!("expected code metadata, got {other:?}"),
+    }
+    let table = BlockMetadata::from_value(&BlockType::Table, json!({ "rows": 2, "columns": 3 }));
+    assert!(matches!(table, BlockMetadata::Table(ref metadata) if metadata.columns == Some(3)));
+}
+
+#[test]
+fn unexpected_shapes_are_kept_raw() {
+    let text = BlockMetadata::from_value(&BlockType::Text, json!({ "color": "red" }));
+    assert_eq!(text, BlockMetadata::Other(json!({ "color": "red" })));
+    let toggle = BlockMetadata::from_value(&BlockType::Toggle, json!({ "open": "yes" }));
+    assert_eq!(toggle, BlockMetadata::Other(json!({ "open": "yes" })));
+    assert_eq!(BlockMetadata::from_value(&BlockType::Callout, json!(null)), BlockMetadata::Other(json!(null)));
+}
+
+#[test]
+fn block_accessors_read_and_write_metadata() {
+    let image = block("IMAGE", json!({ "filename": "a.png", "size": 2048, "width": 320 }));
+    let media = image.media().unwrap();
+    assert_eq!(media.filename.as_deref(), Some("a.png"));
+    assert_eq!(media.width, Some(320.0));
+    assert!(!image.is_checked());
+
+    let mut todo = block("TODO_ITEM", json!({}));
+    assert!(!todo.is_checked());
+    todo.metadata = json!({ "checked": true });
+    assert!(todo.is_checked());
+
+    let mut code = block("CODE", json!({}));
+    code.set_metadata(BlockMetadata::Code(CodeBlockMetadata { language: Some("sql".to_owned()), ..Default::default() }));
+    assert_eq!(code.code_language().as_deref(), Some("sql"));
+    let dto = CreateBlockDto::new(BlockType::Code, "SELECT 1").with_metadata(code.typed_metadata());
+    assert_eq!(dto.metadata, Some(json!({ "language": "sql" })));
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "blocking", feature = "mock-server"))]
+
+use tokio::runtime::Runtime;
+
+use shared_atlas_rust::blocking::blocking_client::BlockingClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::upload_error::UploadError;
+use shared_atlas_rust::client::upload_file::UploadFile;
+use shared_atlas_rust::client::upload_options::UploadOptions;
+use shared_atlas_rust::dto::login_dto::LoginDto;
+use shared_atlas_rust::dto::search_query::SearchQuery;
+use shared_atlas_rust::interfaces::add_message_dto::AddMessageDto;
+use shared_atlas_rust::interfaces::block_type::BlockType;
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::interfaces::create_block_dto::CreateBlockDto;
+use shared_atlas_rust::interfaces::create_note_dto::CreateNoteDto;
+use shared_atlas_rust::interfaces::create_session_dto::CreateSessionDto;
+use shared_atlas_rust::mock::mock_server::{MockServer, DEFAULT_PASSWORD, DEFAULT_USERNAME};
+
+/// The mock server runs on its own runtime; the tests themselves are sync.
+fn start() -> (Runtime, MockServer, BlockingClient) {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start()).unwrap();
+    let client = BlockingClient::new(server.url(), AuthMode::Bearer).unwrap();
+    let login = LoginDto { username: DEFAULT_USERNAME.to_owned(), password: DEFAULT_PASSWORD.to_owned() };
+    client.login(&login).unwrap();
+    (runtime, server, client)
+}
+
+#[test]
+fn notes_blocks_and_search() {
+    let (_runtime, _server, client) = start();
+    let note = client.create_note(&CreateNoteDto { title: "Garden".to_owned(), template_id: None, parent_id: None });
+    let note = note.unwrap();
+    let block = CreateBlockDto::new(BlockType::Text, "Plant tomatoes in May");
+    client.create_block(&note.id, &block).unwrap();
+    assert_eq!(client.list_blocks(&note.id).unwrap().len(), 1);
+
+    let found = client.search(&SearchQuery::new("tomatoes")).unwrap();
+    assert!(found.notes.is_empty());
+    assert_eq!(found.blocks[0].note.title, "Garden");
+    assert_eq!(found.total, 1);
+    assert_eq!(client.search(&SearchQuery::new("garden")).unwrap().notes[0].id, note.id);
+}
+
+#[test]
+fn chat_answer_is_an_iterator() {
+    let (_runtime, _server, client) = start();
+    let session = client.create_session(&CreateSessionDto {}).unwrap();
+    let dto = AddMessageDto { content: "hi".to_owned(), role: None, model: None, parent_id: None };
+    let events: Vec<ChatStreamEvent> = client.add_message(&session.id, &dto).unwrap().map(Result::unwrap).collect();
+    assert!(matches!(events.first(), Some(ChatStreamEvent::Thought { .. })));
+    assert!(matches!(events.last(), Some(ChatStreamEvent::Done { .. })));
+}
+
+#[test]
+fn uploads_check_the_file_type() {
+    let (_runtime, _server, client) = start();
+    let note = client.create_note(&CreateNoteDto { title: "Pics".to_owned(), template_id: None, parent_id: None });
+    let note = note.unwrap();
+    let png = UploadFile::from_bytes("dot.png", b"\x89PNG\r\n\x1a\n".to_vec());
+    let uploaded = client.upload_image(&note.id, png, UploadOptions::new()).unwrap();
+    assert!(uploaded.path.starts_with("notes/images/"));
+
+    let text = UploadFile::from_bytes("notes.txt", b"plain".to_vec());
+    let rejected = client.upload_image(&note.id, text, UploadOptions::new());
+    assert!(matches!(rejected, Err(UploadError::UnsupportedType { .. })));
+}
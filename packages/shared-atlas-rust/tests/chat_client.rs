@@ -0,0 +1,137 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::extract::{Path, Query};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use axum::routing::{get, patch, post};
+use axum::{Json, Router};
+use futures_util::{stream, StreamExt};
+use serde_json::{json, Value};
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::client_error::ClientError;
+use shared_atlas_rust::dto::get_messages_dto::GetMessagesDto;
+use shared_atlas_rust::error::api_error::ApiError;
+use shared_atlas_rust::ids::session_id::SessionId;
+use shared_atlas_rust::interfaces::add_message_dto::AddMessageDto;
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::interfaces::create_session_dto::CreateSessionDto;
+use shared_atlas_rust::interfaces::update_session_dto::UpdateSessionDto;
+
+use mock_http::{serve, uuid, USER_ID};
+
+fn session(id: &str, title: &str) -> Value {
+    json!({
+        "id": id,
+        "userId": USER_ID,
+        "title": title,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "updatedAt": "2026-01-01T00:00:00.000Z"
+    })
+}
+
+/// Streams `chunks` with a pause between them, as a chunked body.
+fn chunked(content_type: &'static str, chunks: Vec<&'static str>) -> axum::response::Response {
+    let body = stream::iter(chunks).then(|chunk| async move {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        Ok::<_, Infallible>(chunk)
+    });
+    ([(CONTENT_TYPE, content_type)], Body::from_stream(body)).into_response()
+}
+
+fn api() -> Router {
+    Router::new()
+        .route("/chat/sessions", post(|| async { Json(session(&uuid(1), "New Chat")) }))
+        .route(
+            "/chat/sessions/{id}",
+            patch(|Path(id): Path<String>, Json(body): Json<Value>| async move {
+                Json(session(&id, body["title"].as_str().unwrap()))
+            })
+            .delete(|| async { Json(json!({ "success": true })) }),
+        )
+        .route(
+            "/chat/sessions/{id}/messages",
+            get(|Query(query): Query<Value>| async move {
+                assert_eq!(query, json!({ "limit": "2" }));
+                Json(json!({ "messages": [], "hasMore": false, "nextCursor": null }))
+            })
+            .post(|Json(body): Json<Value>| async move {
+                match body["content"].as_str().unwrap() {
+                    "ndjson" => chunked(
+                        "application/x-ndjson",
+                        vec!["{\"type\":\"answer_chunk\",\"con", "tent\":\"Hi\"}\n{\"type\":\"do", "ne\"}\n"],
+                    ),
+                    "broken" => chunked("text/event-stream", vec!["data: {\"type\":\n\n"]),
+                    "overloaded" => chunked(
+                        "text/event-stream",
+                        vec!["data: {\"statusCode\":503,\"message\":\"Model overloaded\",\"error\":\"Service Unavailable\"}\n\n"],
+                    ),
+                    _ => chunked(
+                        "text/event-stream",
+                        vec![
+                            "data: {\"type\":\"thought\",\"content\":\"hm\"}\n",
+                            "\ndata: {\"type\":\"answer_chunk\",\"content\":\"Hi\"}\n\nda",
+                            "ta: {\"type\":\"done\",\"title\":\"Greeting\"}\n\n",
+                        ],
+                    ),
+                }
+            }),
+        )
+}
+
+fn message(content: &str) -> AddMessageDto {
+    AddMessageDto { content: content.to_owned(), role: None, model: None, parent_id: None }
+}
+
+#[tokio::test]
+async fn session_crud() {
+    let client = AtlasClient::new(serve(api()).await, AuthMode::Cookie).unwrap();
+    let created = client.create_session(&CreateSessionDto {}).await.unwrap();
+    let renamed =
+        client.rename_session(&created.id, &UpdateSessionDto { title: "Plans".to_owned() }).await.unwrap();
+    assert_eq!(renamed.title, "Plans");
+
+    let query = GetMessagesDto { limit: Some(2), before: None, leaf_message_id: None };
+    let page = client.list_messages(&created.id, &query).await.unwrap();
+    assert!(!page.has_more);
+    assert_eq!(page.next_cursor, None);
+
+    assert!(client.delete_session(&created.id).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn streams_answer_across_chunk_boundaries() {
+    let client = AtlasClient::new(serve(api()).await, AuthMode::Cookie).unwrap();
+    let id = SessionId::parse(uuid(1)).unwrap();
+
+    let sse: Vec<_> = client.add_message(&id, &message("hello")).await.unwrap().collect().await;
+    let sse: Vec<_> = sse.into_iter().map(Result::unwrap).collect();
+    assert_eq!(
+        sse,
+        vec![
+            ChatStreamEvent::Thought { content: "hm".to_owned() },
+            ChatStreamEvent::AnswerChunk { content: "Hi".to_owned() },
+            ChatStreamEvent::Done { title: Some("Greeting".to_owned()) },
+        ]
+    );
+
+    let ndjson: Vec<_> = client.add_message(&id, &message("ndjson")).await.unwrap().collect().await;
+    assert_eq!(ndjson.len(), 2);
+    assert!(matches!(ndjson[1], Ok(ChatStreamEvent::Done { title: None })));
+
+    let broken: Vec<_> = client.add_message(&id, &message("broken")).await.unwrap().collect().await;
+    assert!(matches!(broken[..], [Err(ClientError::Protocol(_))]));
+
+    let overloaded: Vec<_> = client.add_message(&id, &message("overloaded")).await.unwrap().collect().await;
+    match &overloaded[..] {
+        [Err(ClientError::Api(ApiError::Server(body)))] => assert_eq!(body.message, "Model overloaded"),
+        other => panic!("expected a server error, got {other:?}"),
+    }
+}
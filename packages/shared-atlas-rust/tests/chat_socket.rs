@@ -0,0 +1,251 @@
+#![cfg(feature = "socket")]
+
+mod common;
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use serde_json::json;
+
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::events::atlas_server_event::AtlasServerEvent;
+use shared_atlas_rust::interfaces::chat_send_payload::ChatSendPayload;
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::protocol::engine_handshake::EngineHandshake;
+use shared_atlas_rust::protocol::engine_packet::EnginePacket;
+use shared_atlas_rust::protocol::socket_packet::SocketPacket;
+use shared_atlas_rust::protocol::CHAT_NAMESPACE;
+use shared_atlas_rust::socket::chat_event_stream::ChatEventStream;
+use shared_atlas_rust::socket::chat_socket::ChatSocket;
+use shared_atlas_rust::socket::socket_error::SocketError;
+
+use common::{fake_gateway, session_id, FakeClient, TIMEOUT};
+
+fn message(content: &str) -> ChatSendPayload {
+    ChatSendPayload {
+        session_id: session_id(1),
+        content: content.to_owned(),
+        role: None,
+        model: None,
+        parent_id: None,
+    }
+}
+
+fn chunk(content: &str) -> AtlasServerEvent {
+    AtlasServerEvent::ChatStream(ChatStreamEvent::AnswerChunk { content: content.to_owned() })
+}
+
+async fn collect(stream: ChatEventStream) -> Vec<ChatStreamEvent> {
+    tokio::time::timeout(TIMEOUT, stream.collect()).await.expect("stream did not end")
+}
+
+async fn expect_send(server: &mut FakeClient) -> ChatSendPayload {
+    match server.recv_event().await {
+        AtlasClientEvent::ChatSend(payload) => payload,
+        other => panic!("expected chat:send, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn connects_to_chat_namespace_with_token() {
+    let (options, mut gateway) = fake_gateway("jwt-123");
+    let (socket, accepted) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    socket.unwrap();
+    let (server, auth) = accepted;
+    assert_eq!(server.namespace, CHAT_NAMESPACE);
+    assert_eq!(auth, json!({ "token": "jwt-123" }));
+    assert_eq!(server.url, "http://atlas.test/socket.io/?EIO=4&transport=websocket");
+}
+
+#[tokio::test]
+async fn rejected_connect_fails() {
+    let (options, mut gateway) = fake_gateway("bad");
+    let server = async {
+        let mut server = gateway.accept().await;
+        server.expect_connect().await;
+        server
+            .send_packet(SocketPacket::ConnectError {
+                namespace: CHAT_NAMESPACE.to_owned(),
+                data: json!({ "message": "Authentication error: Invalid token" }),
+            })
+            .await;
+        server
+    };
+    let (socket, _server) = tokio::join!(ChatSocket::connect(options), server);
+    match socket {
+        Err(SocketError::ConnectRefused(message)) => assert_eq!(message, "Authentication error: Invalid token"),
+        other => panic!("expected ConnectRefused, got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn streams_until_done() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    let stream = socket.send(message("hello")).await.unwrap();
+    let sent = expect_send(&mut server).await;
+    assert_eq!(sent.content, "hello");
+    assert_eq!(sent.session_id, session_id(1));
+
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Thought { content: "hmm".to_owned() })).await;
+    server.emit(&chunk("Hi")).await;
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: Some("Greeting".to_owned()) })).await;
+    server.emit(&chunk("late")).await;
+
+    let events = collect(stream).await;
+    assert_eq!(
+        events,
+        vec![
+            ChatStreamEvent::Thought { content: "hmm".to_owned() },
+            ChatStreamEvent::AnswerChunk { content: "Hi".to_owned() },
+            ChatStreamEvent::Done { title: Some("Greeting".to_owned()) },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn survives_huge_heartbeat_values() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let server = async {
+        let mut server = gateway.accept().await;
+        server
+            .send_engine(EnginePacket::Open(EngineHandshake {
+                sid: "fake-sid".to_owned(),
+                upgrades: vec![],
+                ping_interval: u64::MAX,
+                ping_timeout: u64::MAX,
+                max_payload: 1_000_000,
+            }))
+            .await;
+        assert!(matches!(server.recv_packet().await, Some(SocketPacket::Connect { .. })));
+        server.send_packet(SocketPacket::connect(CHAT_NAMESPACE, Some(json!({ "sid": "fake-socket" })))).await;
+        server.namespace = CHAT_NAMESPACE.to_owned();
+        server
+    };
+    let (socket, mut server) = tokio::join!(ChatSocket::connect(options), server);
+    let socket = socket.unwrap();
+
+    let stream = socket.send(message("hello")).await.unwrap();
+    expect_send(&mut server).await;
+    server.emit(&chunk("Hi")).await;
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: None })).await;
+    assert_eq!(collect(stream).await.len(), 2);
+}
+
+#[tokio::test]
+async fn chat_error_ends_stream() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    let stream = socket.send(message("hello")).await.unwrap();
+    expect_send(&mut server).await;
+    server.emit_raw(None, "chat:error", json!({ "error": "Unauthorized access to session" })).await;
+
+    let events = collect(stream).await;
+    assert_eq!(events, vec![ChatStreamEvent::Error { message: "Unauthorized access to session".to_owned() }]);
+}
+
+#[tokio::test]
+async fn one_stream_at_a_time() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    let first = socket.send(message("one")).await.unwrap();
+    assert!(matches!(socket.send(message("two")).await, Err(SocketError::StreamInProgress)));
+    expect_send(&mut server).await;
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: None })).await;
+    assert_eq!(collect(first).await.len(), 1);
+
+    let second = socket.send(message("two")).await.unwrap();
+    assert_eq!(expect_send(&mut server).await.content, "two");
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: None })).await;
+    assert_eq!(collect(second).await, vec![ChatStreamEvent::Done { title: None }]);
+}
+
+#[tokio::test]
+async fn dropped_stream_discards_rest_of_response() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    let mut first = socket.send(message("one")).await.unwrap();
+    expect_send(&mut server).await;
+    server.emit(&chunk("a")).await;
+    assert_eq!(first.next().await, Some(ChatStreamEvent::AnswerChunk { content: "a".to_owned() }));
+    drop(first);
+
+    let pending = tokio::spawn(async move {
+        let second = socket.send(message("two")).await.unwrap();
+        collect(second).await
+    });
+    server.emit(&chunk("b")).await;
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: None })).await;
+
+    assert_eq!(expect_send(&mut server).await.content, "two");
+    server.emit(&chunk("c")).await;
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: None })).await;
+    let events = pending.await.unwrap();
+    assert_eq!(
+        events,
+        vec![ChatStreamEvent::AnswerChunk { content: "c".to_owned() }, ChatStreamEvent::Done { title: None }]
+    );
+}
+
+#[tokio::test]
+async fn gives_up_on_a_dropped_stream_that_never_finishes() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let options = options.with_drain_timeout(Duration::from_millis(50));
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    let mut first = socket.send(message("one")).await.unwrap();
+    expect_send(&mut server).await;
+    server.emit(&chunk("a")).await;
+    assert!(first.next().await.is_some());
+    drop(first);
+
+    let second = tokio::time::timeout(TIMEOUT, socket.send(message("two"))).await.expect("send hung").unwrap();
+    assert_eq!(expect_send(&mut server).await.content, "two");
+    server.emit(&chunk("b")).await;
+    server.emit(&AtlasServerEvent::ChatStream(ChatStreamEvent::Done { title: None })).await;
+    assert_eq!(
+        collect(second).await,
+        vec![ChatStreamEvent::AnswerChunk { content: "b".to_owned() }, ChatStreamEvent::Done { title: None }]
+    );
+}
+
+#[tokio::test]
+async fn answers_packet_ping_ack() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let _socket = socket.unwrap();
+
+    server.emit_raw(Some(7), "packet:ping", json!({})).await;
+    assert_eq!(server.recv_packet().await, Some(SocketPacket::ack(CHAT_NAMESPACE, 7, vec![])));
+}
+
+#[tokio::test]
+async fn lost_connection_ends_stream_with_error() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    let stream = socket.send(message("hello")).await.unwrap();
+    expect_send(&mut server).await;
+    server
+        .emit_raw(
+            None,
+            "error",
+            json!({ "code": 4012, "message": "Authentication token has expired", "category": "AUTH" }),
+        )
+        .await;
+    server.disconnect().await;
+
+    let events = collect(stream).await;
+    assert!(matches!(&events[..], [ChatStreamEvent::Error { message }] if message.contains("expired")));
+    assert!(matches!(socket.send(message("again")).await, Err(SocketError::Closed)));
+}
@@ -0,0 +1,76 @@
+use serde_json::json;
+
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::protocol::chat_stream_decoder::ChatStreamDecoder;
+use shared_atlas_rust::protocol::protocol_error::ProtocolError;
+
+/// Feeds `body` in chunks of `size` bytes and collects every event.
+fn decode_in_chunks(body: &[u8], size: usize) -> Vec<ChatStreamEvent> {
+    let mut decoder = ChatStreamDecoder::new();
+    let mut events = Vec::new();
+    for chunk in body.chunks(size) {
+        decoder.feed(chunk);
+        while let Some(event) = decoder.next_event().unwrap() {
+            events.push(event);
+        }
+    }
+    decoder.finish();
+    while let Some(event) = decoder.next_event().unwrap() {
+        events.push(event);
+    }
+    assert!(!decoder.has_pending());
+    events
+}
+
+fn expected() -> Vec<ChatStreamEvent> {
+    vec![
+        ChatStreamEvent::Thought { content: "Thinking… 思考".to_owned() },
+        ChatStreamEvent::ToolCall { tool: "search".to_owned(), args: json!({ "q": "atlas" }) },
+        ChatStreamEvent::AnswerChunk { content: "Hello".to_owned() },
+        ChatStreamEvent::Done { title: Some("Greeting".to_owned()) },
+    ]
+}
+
+#[test]
+fn decodes_sse_split_at_every_byte() {
+    let body = concat!(
+        ": keep-alive\n\n",
+        "event: message\r\ndata: {\"type\":\"thought\",\"content\":\"Thinking… 思考\"}\r\n\r\n",
+        "data: {\"type\":\"tool_call\",\n",
+        "data:\"tool\":\"search\",\"args\":{\"q\":\"atlas\"}}\n\n",
+        "id: 3\ndata: {\"type\":\"answer_chunk\",\"content\":\"Hello\"}\n\n",
+        "data: {\"type\":\"done\",\"title\":\"Greeting\"}\n\n",
+        "data: [DONE]\n\n",
+    );
+    for size in [1, 2, 7, body.len()] {
+        assert_eq!(decode_in_chunks(body.as_bytes(), size), expected(), "chunk size {size}");
+    }
+}
+
+#[test]
+fn decodes_ndjson_without_trailing_newline() {
+    let body = concat!(
+        "{\"type\":\"thought\",\"content\":\"Thinking… 思考\"}\n",
+        "{\"type\":\"tool_call\",\"tool\":\"search\",\"args\":{\"q\":\"atlas\"}}\r\n",
+        "\n",
+        "{\"type\":\"answer_chunk\",\"content\":\"Hello\"}\n",
+        "{\"type\":\"done\",\"title\":\"Greeting\"}",
+    );
+    for size in [1, 3, body.len()] {
+        assert_eq!(decode_in_chunks(body.as_bytes(), size), expected(), "chunk size {size}");
+    }
+}
+
+#[test]
+fn waits_for_complete_frames() {
+    let mut decoder = ChatStreamDecoder::new();
+    decoder.feed(b"data: {\"type\":\"answer_chunk\",");
+    assert!(decoder.next_event().unwrap().is_none());
+    decoder.feed(b"\"content\":\"Hi\"}\n");
+    assert!(decoder.next_event().unwrap().is_none(), "frame ends at the blank line");
+    decoder.feed(b"\n");
+    assert_eq!(decoder.next_event().unwrap(), Some(ChatStreamEvent::AnswerChunk { content: "Hi".to_owned() }));
+
+    decoder.feed(b"data: {not json}\n\n");
+    assert!(matches!(decoder.next_event(), Err(ProtocolError::InvalidJson(_))));
+}
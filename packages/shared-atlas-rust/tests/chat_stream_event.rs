@@ -0,0 +1,105 @@
+use serde_json::{json, Value};
+
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+
+fn decode(frame: Value) -> ChatStreamEvent {
+    serde_json::from_value(frame.clone()).unwrap_or_else(|err| panic!("{frame}: {err}"))
+}
+
+/// Payloads of `chat:stream` as the chat gateway emits them: agent events are
+/// forwarded as yielded by `ReactAgentEngine`, `done` and `error` are built
+/// by the gateway itself.
+#[test]
+fn decodes_server_frames() {
+    let frames = [
+        (
+            json!({ "type": "thought", "content": "I should search the notes first." }),
+            ChatStreamEvent::Thought { content: "I should search the notes first.".to_owned() },
+        ),
+        (
+            json!({ "type": "tool_call", "tool": "search_notes", "args": { "query": "garden", "limit": 5 } }),
+            ChatStreamEvent::ToolCall {
+                tool: "search_notes".to_owned(),
+                args: json!({ "query": "garden", "limit": 5 }),
+            },
+        ),
+        (
+            json!({ "type": "tool_result", "tool": "search_notes", "result": [{ "id": "n1", "title": "Garden" }] }),
+            ChatStreamEvent::ToolResult {
+                tool: "search_notes".to_owned(),
+                result: json!([{ "id": "n1", "title": "Garden" }]),
+            },
+        ),
+        (
+            json!({ "type": "tool_result", "tool": "web", "result": "Error: Tool \"web\" not found." }),
+            ChatStreamEvent::ToolResult { tool: "web".to_owned(), result: json!("Error: Tool \"web\" not found.") },
+        ),
+        (
+            json!({ "type": "answer_chunk", "content": "You planted " }),
+            ChatStreamEvent::AnswerChunk { content: "You planted ".to_owned() },
+        ),
+        (
+            json!({ "type": "done", "title": "Garden plans" }),
+            ChatStreamEvent::Done { title: Some("Garden plans".to_owned()) },
+        ),
+        (json!({ "type": "done", "title": null }), ChatStreamEvent::Done { title: None }),
+        (json!({ "type": "done" }), ChatStreamEvent::Done { title: None }),
+        (
+            json!({ "type": "error", "message": "Stream failed" }),
+            ChatStreamEvent::Error { message: "Stream failed".to_owned() },
+        ),
+    ];
+    for (frame, expected) in frames {
+        assert_eq!(decode(frame), expected);
+    }
+}
+
+#[test]
+fn encodes_with_a_type_tag() {
+    let event = ChatStreamEvent::ToolCall { tool: "search_notes".to_owned(), args: json!({ "query": "garden" }) };
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        json!({ "type": "tool_call", "tool": "search_notes", "args": { "query": "garden" } })
+    );
+    let done = serde_json::to_value(ChatStreamEvent::Done { title: None }).unwrap();
+    assert_eq!(done, json!({ "type": "done" }));
+}
+
+#[test]
+fn unknown_events_are_kept_verbatim() {
+    let frames = [
+        // Yielded by the agent but not forwarded by the current gateway.
+        json!({ "type": "final_answer", "content": "You planted tomatoes." }),
+        json!({ "type": "citation", "noteId": "n1", "blockId": "b2" }),
+        // A known type with a payload of the wrong shape is not an error either.
+        json!({ "type": "answer_chunk", "content": 42 }),
+        json!({ "content": "no type" }),
+        json!("answer_chunk"),
+    ];
+    for frame in frames {
+        let event = decode(frame.clone());
+        assert_eq!(event, ChatStreamEvent::Unknown(frame.clone()));
+        assert!(!event.is_terminal(), "{frame}");
+        assert_eq!(serde_json::to_value(&event).unwrap(), frame);
+    }
+}
+
+#[test]
+fn only_done_and_error_are_terminal() {
+    let terminal = [
+        json!({ "type": "done", "title": "Garden plans" }),
+        json!({ "type": "error", "message": "Stream failed" }),
+    ];
+    for frame in terminal {
+        assert!(decode(frame.clone()).is_terminal(), "{frame}");
+    }
+    let streaming = [
+        json!({ "type": "thought", "content": "..." }),
+        json!({ "type": "tool_call", "tool": "search_notes", "args": {} }),
+        json!({ "type": "tool_result", "tool": "search_notes", "result": null }),
+        json!({ "type": "answer_chunk", "content": "..." }),
+    ];
+    for frame in streaming {
+        assert!(!decode(frame.clone()).is_terminal(), "{frame}");
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "socket")]
+
+mod common;
+
+use futures_util::StreamExt;
+use serde_json::json;
+
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::ids::note_id::NoteId;
+use shared_atlas_rust::protocol::COLLABORATION_NAMESPACE;
+use shared_atlas_rust::socket::collaboration_socket::CollaborationSocket;
+use shared_atlas_rust::socket::note_event::NoteEvent;
+use shared_atlas_rust::socket::note_subscription::NoteSubscription;
+use shared_atlas_rust::socket::socket_error::SocketError;
+
+use common::{fake_gateway, note_id, FakeClient, TIMEOUT};
+
+/// The other collaborator.
+const BOB: &str = "00000000-0000-4000-8000-000000000b0b";
+
+async fn next(subscription: &mut NoteSubscription) -> Option<NoteEvent> {
+    tokio::time::timeout(TIMEOUT, subscription.next()).await.expect("no event")
+}
+
+async fn connect() -> (CollaborationSocket, FakeClient) {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (server, _)) = tokio::join!(CollaborationSocket::connect(options), gateway.accept_ready());
+    assert_eq!(server.namespace, COLLABORATION_NAMESPACE);
+    (socket.unwrap(), server)
+}
+
+async fn expect_join(server: &mut FakeClient) -> NoteId {
+    match server.recv_event().await {
+        AtlasClientEvent::NoteJoin(payload) => payload.note_id,
+        other => panic!("expected note:join, got {other:?}"),
+    }
+}
+
+async fn accept_join(server: &mut FakeClient, note_id: &NoteId) {
+    assert_eq!(&expect_join(server).await, note_id);
+    server.emit_raw(None, "yjs:sync", json!({ "noteId": note_id, "update": "AAE=", "stateVector": "AA==" })).await;
+    server
+        .emit_raw(
+            None,
+            "presence:list",
+            json!([{ "userId": BOB, "username": "bob", "color": "#f00", "connectedAt": "2026-01-01T00:00:00.000Z" }]),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn join_yields_sync_then_collaborators() {
+    let (socket, mut server) = connect().await;
+    let mut note = socket.join(&note_id(1)).unwrap();
+    accept_join(&mut server, &note_id(1)).await;
+
+    match next(&mut note).await {
+        Some(NoteEvent::Sync(sync)) => {
+            assert_eq!(sync.note_id, note_id(1));
+            assert_eq!(sync.update, "AAE=");
+        }
+        other => panic!("expected Sync, got {other:?}"),
+    }
+    match next(&mut note).await {
+        Some(NoteEvent::Collaborators(list)) => assert_eq!(list[0].username, "bob"),
+        other => panic!("expected Collaborators, got {other:?}"),
+    }
+    assert!(matches!(socket.join(&note_id(1)), Err(SocketError::AlreadyJoined(_))));
+}
+
+#[tokio::test]
+async fn routes_updates_and_cursors_by_note() {
+    let (socket, mut server) = connect().await;
+    let mut first = socket.join(&note_id(1)).unwrap();
+    accept_join(&mut server, &note_id(1)).await;
+    let mut second = socket.join(&note_id(2)).unwrap();
+    accept_join(&mut server, &note_id(2)).await;
+    for note in [&mut first, &mut second] {
+        assert!(matches!(next(note).await, Some(NoteEvent::Sync(_))));
+        assert!(matches!(next(note).await, Some(NoteEvent::Collaborators(_))));
+    }
+
+    server.emit_raw(None, "yjs:update", json!({ "noteId": note_id(2), "update": "AQI=" })).await;
+    let cursor = json!({ "noteId": note_id(1), "userId": BOB, "position": 4, "selection": null });
+    server.emit_raw(None, "cursor:update", cursor).await;
+    server.emit_raw(None, "presence:leave", json!({ "userId": BOB })).await;
+
+    match next(&mut second).await {
+        Some(NoteEvent::Update(update)) => assert_eq!(update.update, "AQI="),
+        other => panic!("expected Update, got {other:?}"),
+    }
+    match next(&mut first).await {
+        Some(NoteEvent::Cursor(cursor)) => {
+            assert_eq!(cursor.user_id.unwrap(), BOB);
+            assert_eq!(cursor.position, Some(json!(4)));
+        }
+        other => panic!("expected Cursor, got {other:?}"),
+    }
+    assert!(matches!(next(&mut first).await, Some(NoteEvent::PresenceLeave(_))));
+    assert!(matches!(next(&mut second).await, Some(NoteEvent::PresenceLeave(_))));
+}
+
+#[tokio::test]
+async fn pushes_updates_and_cursor_moves() {
+    let (socket, mut server) = connect().await;
+    let note = socket.join(&note_id(1)).unwrap();
+    accept_join(&mut server, &note_id(1)).await;
+
+    note.push_update(&[1, 2]).unwrap();
+    note.move_cursor(Some(json!({ "index": 3 })), None).unwrap();
+
+    match server.recv_event().await {
+        AtlasClientEvent::YjsUpdate(update) => {
+            assert_eq!(update.note_id, note_id(1));
+            assert_eq!(update.update, "AQI=");
+        }
+        other => panic!("expected yjs:update, got {other:?}"),
+    }
+    match server.recv_event().await {
+        AtlasClientEvent::CursorUpdate(cursor) => {
+            assert_eq!(cursor.position, Some(json!({ "index": 3 })));
+            assert_eq!(cursor.user_id, None);
+        }
+        other => panic!("expected cursor:update, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn dropping_subscription_leaves_note() {
+    let (socket, mut server) = connect().await;
+    let note = socket.join(&note_id(1)).unwrap();
+    accept_join(&mut server, &note_id(1)).await;
+    drop(note);
+
+    match server.recv_event().await {
+        AtlasClientEvent::NoteLeave(payload) => assert_eq!(payload.note_id, note_id(1)),
+        other => panic!("expected note:leave, got {other:?}"),
+    }
+    socket.join(&note_id(1)).unwrap();
+}
+
+#[tokio::test]
+async fn refused_join_ends_subscription() {
+    let (socket, mut server) = connect().await;
+    let mut full = socket.join(&note_id(3)).unwrap();
+    let mut forbidden = socket.join(&note_id(4)).unwrap();
+    expect_join(&mut server).await;
+    expect_join(&mut server).await;
+
+    server
+        .emit_raw(
+            None,
+            "collaboration:limit",
+            json!({ "error": "Max editors reached", "currentEditors": 5, "maxEditors": 5 }),
+        )
+        .await;
+    server.emit_raw(None, "collaboration:error", json!({ "error": "No edit permission" })).await;
+
+    assert!(matches!(next(&mut full).await, Some(NoteEvent::Limit(limit)) if limit.max_editors == 5.0));
+    assert!(next(&mut full).await.is_none());
+    assert!(matches!(next(&mut forbidden).await, Some(NoteEvent::Rejected(err)) if err.error == "No edit permission"));
+    assert!(next(&mut forbidden).await.is_none());
+}
+
+#[tokio::test]
+async fn disconnect_ends_all_subscriptions() {
+    let (socket, mut server) = connect().await;
+    let mut note = socket.join(&note_id(1)).unwrap();
+    expect_join(&mut server).await;
+    server.disconnect().await;
+
+    assert!(matches!(next(&mut note).await, Some(NoteEvent::Disconnected(_))));
+    assert!(next(&mut note).await.is_none());
+    assert!(matches!(socket.join(&note_id(2)), Err(SocketError::Closed)));
+}
@@ -0,0 +1,167 @@
+//! In-process fake of the Atlas socket.io gateways, driven step by step from
+//! a test.
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::events::atlas_server_event::AtlasServerEvent;
+use shared_atlas_rust::ids::note_id::NoteId;
+use shared_atlas_rust::ids::session_id::SessionId;
+use shared_atlas_rust::protocol::engine_handshake::EngineHandshake;
+use shared_atlas_rust::protocol::engine_packet::EnginePacket;
+use shared_atlas_rust::protocol::frame::Frame;
+use shared_atlas_rust::protocol::socket_packet::SocketPacket;
+use shared_atlas_rust::socket::memory_transport::MemoryTransport;
+use shared_atlas_rust::socket::socket_options::SocketOptions;
+
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A stable UUID for fixture number `n`.
+pub fn uuid(n: u64) -> String {
+    format!("00000000-0000-4000-8000-{n:012x}")
+}
+
+pub fn note_id(n: u64) -> NoteId {
+    NoteId::parse(uuid(n)).unwrap()
+}
+
+pub fn session_id(n: u64) -> SessionId {
+    SessionId::parse(uuid(n)).unwrap()
+}
+
+/// Accepts connections made through the options returned by [`fake_gateway`].
+pub struct FakeGateway {
+    connections: mpsc::UnboundedReceiver<FakeClient>,
+}
+
+/// Socket options whose connector hands the server end to a [`FakeGateway`].
+pub fn fake_gateway(token: &str) -> (SocketOptions, FakeGateway) {
+    let (tx, connections) = mpsc::unbounded_channel();
+    let options = SocketOptions::new("http://atlas.test", token).with_connector(move |url| {
+        let (client, server) = MemoryTransport::pair();
+        let accepted = tx.send(FakeClient { transport: server, url, namespace: String::new() });
+        async move {
+            accepted.map_err(|_| shared_atlas_rust::socket::socket_error::SocketError::Closed)?;
+            Ok(client)
+        }
+    });
+    (options, FakeGateway { connections })
+}
+
+impl FakeGateway {
+    pub async fn accept(&mut self) -> FakeClient {
+        tokio::time::timeout(TIMEOUT, self.connections.recv())
+            .await
+            .expect("no connection")
+            .expect("gateway closed")
+    }
+
+    /// A connection the client has already opened, if any.
+    pub fn try_accept(&mut self) -> Option<FakeClient> {
+        self.connections.try_recv().ok()
+    }
+
+    /// Accepts a connection and completes the Engine.IO and namespace handshakes.
+    /// Returns the client and the auth payload it sent.
+    pub async fn accept_ready(&mut self) -> (FakeClient, Value) {
+        let mut client = self.accept().await;
+        let auth = client.handshake().await;
+        (client, auth)
+    }
+}
+
+/// The server end of one client connection.
+pub struct FakeClient {
+    transport: MemoryTransport,
+    pub url: String,
+    pub namespace: String,
+}
+
+impl FakeClient {
+    pub async fn send_engine(&mut self, packet: EnginePacket) {
+        self.transport.send(packet.encode()).await.expect("client gone");
+    }
+
+    pub async fn send_packet(&mut self, packet: SocketPacket) {
+        for packet in packet.to_engine_packets() {
+            self.send_engine(packet).await;
+        }
+    }
+
+    pub async fn open(&mut self) {
+        self.send_engine(EnginePacket::Open(EngineHandshake {
+            sid: "fake-sid".to_owned(),
+            upgrades: vec![],
+            ping_interval: 10000,
+            ping_timeout: 20000,
+            max_payload: 1_000_000,
+        }))
+        .await;
+    }
+
+    /// Next Engine.IO packet from the client, or `None` once it hung up.
+    pub async fn recv_engine(&mut self) -> Option<EnginePacket> {
+        let frame = tokio::time::timeout(TIMEOUT, self.transport.next()).await.expect("client silent")?;
+        Some(EnginePacket::decode(frame.unwrap()).unwrap())
+    }
+
+    /// Next Socket.IO packet from the client, skipping heartbeats.
+    pub async fn recv_packet(&mut self) -> Option<SocketPacket> {
+        loop {
+            match self.recv_engine().await? {
+                EnginePacket::Message(Frame::Text(text)) => return Some(SocketPacket::decode(&text).unwrap()),
+                EnginePacket::Pong(_) => continue,
+                other => panic!("unexpected engine packet {other:?}"),
+            }
+        }
+    }
+
+    /// Sends the Engine.IO open packet and waits for the namespace `CONNECT`.
+    /// Returns its auth payload without answering.
+    pub async fn expect_connect(&mut self) -> Value {
+        self.open().await;
+        match self.recv_packet().await {
+            Some(SocketPacket::Connect { namespace, data }) => {
+                self.namespace = namespace;
+                data.unwrap_or(Value::Null)
+            }
+            other => panic!("expected CONNECT, got {other:?}"),
+        }
+    }
+
+    pub async fn handshake(&mut self) -> Value {
+        let auth = self.expect_connect().await;
+        let namespace = self.namespace.clone();
+        self.send_packet(SocketPacket::connect(&namespace, Some(json!({ "sid": "fake-socket" })))).await;
+        auth
+    }
+
+    pub async fn recv_event(&mut self) -> AtlasClientEvent {
+        match self.recv_packet().await {
+            Some(SocketPacket::Event { data, .. }) => serde_json::from_value(Value::Array(data)).unwrap(),
+            other => panic!("expected EVENT, got {other:?}"),
+        }
+    }
+
+    pub async fn emit(&mut self, event: &AtlasServerEvent) {
+        let packet = SocketPacket::event(&self.namespace, None, event).unwrap();
+        self.send_packet(packet).await;
+    }
+
+    /// Emits a raw `[name, payload]` event, optionally asking for an ack.
+    pub async fn emit_raw(&mut self, id: Option<u64>, name: &str, payload: Value) {
+        let packet = SocketPacket::Event { namespace: self.namespace.clone(), id, data: vec![json!(name), payload] };
+        self.send_packet(packet).await;
+    }
+
+    /// Drops the client from the namespace, like `client.disconnect()`.
+    pub async fn disconnect(&mut self) {
+        let namespace = self.namespace.clone();
+        self.send_packet(SocketPacket::Disconnect { namespace }).await;
+    }
+}
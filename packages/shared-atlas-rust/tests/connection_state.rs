@@ -0,0 +1,154 @@
+#![cfg(feature = "socket")]
+
+mod common;
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use serde_json::json;
+
+use shared_atlas_rust::dto::refresh_token_dto::RefreshTokenDto;
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::interfaces::token_response::TokenResponse;
+use shared_atlas_rust::socket::chat_socket::ChatSocket;
+use shared_atlas_rust::socket::collaboration_socket::CollaborationSocket;
+use shared_atlas_rust::socket::connection_event::ConnectionEvent;
+use shared_atlas_rust::socket::connection_machine::ConnectionMachine;
+use shared_atlas_rust::socket::connection_state::ConnectionState;
+use shared_atlas_rust::socket::connection_state_stream::ConnectionStateStream;
+use shared_atlas_rust::socket::note_event::NoteEvent;
+use shared_atlas_rust::socket::reconnect_policy::ReconnectPolicy;
+use shared_atlas_rust::socket::token_provider::BoxError;
+
+use common::{fake_gateway, note_id, TIMEOUT};
+
+const FAST: ReconnectPolicy =
+    ReconnectPolicy { initial_delay: Duration::from_millis(1), max_delay: Duration::from_millis(5), max_retries: 3 };
+
+async fn wait_for(states: &mut ConnectionStateStream, wanted: ConnectionState) -> Vec<ConnectionState> {
+    let mut seen = Vec::new();
+    loop {
+        let state = tokio::time::timeout(TIMEOUT, states.next()).await.expect("state did not change").unwrap();
+        seen.push(state);
+        if state == wanted {
+            return seen;
+        }
+    }
+}
+
+fn gateway_error(code: u16) -> serde_json::Value {
+    json!({ "code": code, "message": "boom", "category": "SERVER" })
+}
+
+#[test]
+fn machine_validates_transitions() {
+    use ConnectionEvent as E;
+    use ConnectionState as S;
+
+    let mut machine = ConnectionMachine::new(1);
+    for (event, state) in [
+        (E::Connect, S::Connecting),
+        (E::ConnectionEstablished, S::Connected),
+        (E::Connect, S::Authenticating),
+        (E::AuthSuccess, S::Ready),
+        (E::TokenRefreshed, S::Authenticating),
+        (E::AuthSuccess, S::Ready),
+        (E::FatalError("lost".to_owned()), S::Error),
+        (E::Retry, S::Connecting),
+        (E::AuthFailed("expired".to_owned()), S::Error),
+    ] {
+        assert_eq!(machine.transition(event).unwrap(), state);
+    }
+    assert_eq!(machine.last_error(), Some("expired"));
+
+    let err = machine.transition(E::Retry).unwrap_err();
+    assert_eq!((err.from, err.event), (S::Error, "RETRY"));
+    assert_eq!(err.to_string(), "RETRY is not allowed in state error");
+    assert_eq!(machine.transition(E::Disconnect).unwrap(), S::Disconnected);
+    assert!(machine.transition(E::AuthSuccess).is_err());
+}
+
+#[test]
+fn backoff_doubles_up_to_the_cap_with_jitter() {
+    let policy = ReconnectPolicy::default();
+    let base: Vec<u64> = (0..7).map(|n| policy.base_delay(n).as_millis() as u64).collect();
+    assert_eq!(base, [1000, 2000, 4000, 8000, 16000, 30000, 30000]);
+    assert_eq!(policy.base_delay(100), Duration::from_secs(30));
+    for attempt in 0..6 {
+        let delay = policy.delay(attempt);
+        assert!(delay >= policy.base_delay(attempt) / 2 && delay <= policy.base_delay(attempt));
+    }
+}
+
+#[tokio::test]
+async fn reports_state_changes() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+    let mut states = socket.handle().state_changes();
+    assert_eq!(socket.handle().state(), ConnectionState::Ready);
+
+    server.emit_raw(None, "error", json!({ "code": 4030, "message": "nope", "category": "PERMISSION" })).await;
+    server.disconnect().await;
+    let seen = wait_for(&mut states, ConnectionState::Disconnected).await;
+    assert_eq!(seen.first(), Some(&ConnectionState::Ready));
+    assert_eq!(socket.handle().state(), ConnectionState::Disconnected);
+}
+
+#[tokio::test]
+async fn reconnects_and_rejoins_notes() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) =
+        tokio::join!(CollaborationSocket::connect(options.with_reconnect(FAST)), gateway.accept_ready());
+    let socket = socket.unwrap();
+    let mut states = socket.handle().state_changes();
+    assert_eq!(states.next().await, Some(ConnectionState::Ready));
+    let mut note = socket.join(&note_id(1)).unwrap();
+    assert!(matches!(server.recv_event().await, AtlasClientEvent::NoteJoin(_)));
+
+    server.emit_raw(None, "error", gateway_error(5000)).await;
+    server.disconnect().await;
+    let (mut server, _) = gateway.accept_ready().await;
+    match server.recv_event().await {
+        AtlasClientEvent::NoteJoin(payload) => assert_eq!(payload.note_id, note_id(1)),
+        other => panic!("expected note:join, got {other:?}"),
+    }
+    wait_for(&mut states, ConnectionState::Ready).await;
+    assert_eq!(socket.handle().state(), ConnectionState::Ready);
+
+    server.emit_raw(None, "yjs:sync", json!({ "noteId": note_id(1), "update": "AAE=", "stateVector": "AA==" })).await;
+    let event = tokio::time::timeout(TIMEOUT, note.next()).await.unwrap();
+    assert!(matches!(event, Some(NoteEvent::Sync(_))));
+}
+
+#[tokio::test]
+async fn refreshes_token_before_reconnecting_after_auth_error() {
+    let (options, mut gateway) = fake_gateway("expired");
+    let provider = |_: RefreshTokenDto| async {
+        Ok::<_, BoxError>(TokenResponse { access_token: "fresh".to_owned(), refresh_token: "r2".to_owned() })
+    };
+    let options = options.with_token_provider(provider).with_reconnect(FAST);
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let socket = socket.unwrap();
+
+    server.emit_raw(None, "error", json!({ "code": 4012, "message": "Token expired", "category": "AUTH" })).await;
+    server.disconnect().await;
+    let (_server, auth) = gateway.accept_ready().await;
+    assert_eq!(auth, json!({ "token": "fresh" }));
+    let mut states = socket.handle().state_changes();
+    wait_for(&mut states, ConnectionState::Ready).await;
+}
+
+#[tokio::test]
+async fn fatal_errors_are_not_retried() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let (socket, (mut server, _)) =
+        tokio::join!(ChatSocket::connect(options.with_reconnect(FAST)), gateway.accept_ready());
+    let socket = socket.unwrap();
+    let mut states = socket.handle().state_changes();
+
+    server.emit_raw(None, "error", json!({ "code": 4030, "message": "nope", "category": "PERMISSION" })).await;
+    server.disconnect().await;
+    wait_for(&mut states, ConnectionState::Disconnected).await;
+    assert!(gateway.try_accept().is_none(), "a fatal error must not reconnect");
+}
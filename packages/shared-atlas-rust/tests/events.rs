@@ -0,0 +1,158 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::events::atlas_server_event::AtlasServerEvent;
+
+const NOTE_ID: &str = "3f2504e0-4f89-41d3-9a0c-0305e82c3301";
+const SESSION_ID: &str = "9b2f6c1e-7d4a-4e8b-a5c3-2f1d0e9c8b7a";
+const USER_ID: &str = "c56a4180-65aa-42ec-a945-5fd21dec0538";
+
+/// Parses `[name, payload]`, checks the event name and that it serializes back
+/// to the same array.
+fn round_trip<E: Serialize + DeserializeOwned>(frame: Value, name: impl Fn(&E) -> &str) -> E {
+    let event: E = serde_json::from_value(frame.clone()).unwrap_or_else(|err| panic!("{frame}: {err}"));
+    assert_eq!(name(&event), frame[0].as_str().unwrap());
+    assert_eq!(serde_json::to_value(&event).unwrap(), frame);
+    event
+}
+
+fn client_frames() -> Vec<Value> {
+    vec![
+        json!(["note:join", { "noteId": NOTE_ID }]),
+        json!(["note:leave", { "noteId": NOTE_ID }]),
+        json!(["yjs:update", { "noteId": NOTE_ID, "update": "AQID" }]),
+        json!(["cursor:update", { "noteId": NOTE_ID, "position": { "index": 4 }, "selection": null }]),
+        json!(["chat:send", {
+            "sessionId": SESSION_ID,
+            "content": "Summarize my notes",
+            "role": "user",
+            "model": "default",
+            "parentId": null
+        }]),
+        json!(["message:ack", { "messageId": "msg-1" }]),
+        json!(["auth:token-refreshed", { "newToken": "new-access" }]),
+    ]
+}
+
+fn server_frames() -> Vec<Value> {
+    vec![
+        json!(["yjs:sync", { "noteId": NOTE_ID, "update": "AAE=", "stateVector": "AA==" }]),
+        json!(["yjs:update", { "noteId": NOTE_ID, "update": "AQID" }]),
+        json!(["cursor:update", {
+            "noteId": NOTE_ID,
+            "position": { "index": 4 },
+            "selection": { "anchor": 1, "head": 4 },
+            "userId": USER_ID
+        }]),
+        json!(["presence:join", { "userId": USER_ID, "username": "alice", "avatar": null, "color": "#E57373" }]),
+        json!(["presence:leave", { "userId": USER_ID }]),
+        json!(["presence:list", [{
+            "userId": USER_ID,
+            "username": "alice",
+            "avatar": "https://example.com/a.png",
+            "color": "#E57373",
+            "cursorPosition": null,
+            "selection": null,
+            "connectedAt": 1767607200000u64
+        }]]),
+        json!(["collaboration:limit", { "error": "Too many editors", "currentEditors": 10.0, "maxEditors": 10.0 }]),
+        json!(["collaboration:error", { "error": "Note not found" }]),
+        json!(["chat:stream", { "type": "answer_chunk", "content": "Hello" }]),
+        json!(["chat:error", { "error": "Session not found" }]),
+        json!(["activity:status", {
+            "sessionId": SESSION_ID,
+            "activityId": "0f8fad5b-d9cb-469f-a165-70867728950e",
+            "type": "TOOL_EXECUTION",
+            "description": "Searching notes",
+            "status": "STARTED",
+            "timestamp": 1767607200000u64,
+            "metadata": null
+        }]),
+        json!(["auth:token-expiring", { "expiresIn": 300.0, "action": "refresh" }]),
+        json!(["auth:token-renewed", { "success": true }]),
+        json!(["auth:error", { "message": "Invalid token" }]),
+        json!(["packet:ping", { "messageId": "msg-2", "timestamp": 1767607200000u64 }]),
+        json!(["error", {
+            "code": 4012,
+            "message": "Token expired",
+            "category": "AUTH",
+            "details": null,
+            "timestamp": null
+        }]),
+    ]
+}
+
+#[test]
+fn client_events_round_trip_by_name() {
+    let frames = client_frames();
+    for frame in &frames {
+        let event = round_trip(frame.clone(), AtlasClientEvent::name);
+        assert!(!matches!(event, AtlasClientEvent::Unknown { .. }), "{frame} decoded as Unknown");
+        assert_eq!(event.to_args().unwrap(), vec![frame[1].clone()]);
+    }
+    assert_eq!(frames.len(), 7, "one frame per AtlasClientEvent variant");
+}
+
+#[test]
+fn server_events_round_trip_by_name() {
+    let frames = server_frames();
+    for frame in &frames {
+        let event = round_trip(frame.clone(), AtlasServerEvent::name);
+        assert!(!matches!(event, AtlasServerEvent::Unknown { .. }), "{frame} decoded as Unknown");
+        let rebuilt = AtlasServerEvent::from_args(event.name(), event.to_args().unwrap()).unwrap();
+        assert_eq!(serde_json::to_value(rebuilt).unwrap(), *frame);
+    }
+    assert_eq!(frames.len(), 16, "one frame per AtlasServerEvent variant");
+}
+
+#[test]
+fn unknown_events_keep_name_and_args() {
+    let frame = json!(["note:archived", { "noteId": NOTE_ID }, 2, "extra"]);
+    match round_trip(frame.clone(), AtlasServerEvent::name) {
+        AtlasServerEvent::Unknown { name, args } => {
+            assert_eq!(name, "note:archived");
+            assert_eq!(args, vec![frame[1].clone(), json!(2), json!("extra")]);
+        }
+        other => panic!("expected Unknown, got {other:?}"),
+    }
+    match round_trip(json!(["typing:start"]), AtlasClientEvent::name) {
+        AtlasClientEvent::Unknown { name, args } => {
+            assert_eq!(name, "typing:start");
+            assert!(args.is_empty());
+        }
+        other => panic!("expected Unknown, got {other:?}"),
+    }
+}
+
+#[test]
+fn known_names_with_malformed_payloads_are_errors() {
+    let malformed = [
+        json!(["note:join", { "noteId": "not-a-uuid" }]),
+        json!(["note:join", "3f2504e0-4f89-41d3-9a0c-0305e82c3301"]),
+        json!(["note:join"]),
+        json!(["chat:send", { "sessionId": SESSION_ID }]),
+    ];
+    for frame in malformed {
+        assert!(serde_json::from_value::<AtlasClientEvent>(frame.clone()).is_err(), "{frame} must not decode");
+    }
+    let malformed = [
+        json!(["yjs:sync", { "noteId": NOTE_ID }]),
+        json!(["presence:list", { "userId": USER_ID }]),
+        json!(["auth:token-expiring", { "expiresIn": "soon" }]),
+        json!(["error", { "code": "AUTH", "message": "nope", "category": "AUTH" }]),
+    ];
+    for frame in malformed {
+        assert!(serde_json::from_value::<AtlasServerEvent>(frame.clone()).is_err(), "{frame} must not decode");
+    }
+    assert!(AtlasServerEvent::from_args("auth:token-renewed", vec![json!({ "success": "yes" })]).is_err());
+}
+
+#[test]
+fn frames_without_a_string_name_are_errors() {
+    for frame in [json!([]), json!([42, {}]), json!({ "name": "note:join" }), json!("note:join")] {
+        assert!(serde_json::from_value::<AtlasServerEvent>(frame.clone()).is_err(), "{frame} must not decode");
+        assert!(serde_json::from_value::<AtlasClientEvent>(frame.clone()).is_err(), "{frame} must not decode");
+    }
+}
@@ -0,0 +1,41 @@
+use serde_json::json;
+
+use shared_atlas_rust::dto::storage_module::StorageModule;
+use shared_atlas_rust::interfaces::activity_status::ActivityStatus;
+use shared_atlas_rust::interfaces::block_type::BlockType;
+use shared_atlas_rust::interfaces::chat_role::ChatRole;
+use shared_atlas_rust::interfaces::web_socket_error_code::{ErrorDisposition, WebSocketErrorCode};
+use shared_atlas_rust::interfaces::ws_error_response::WsErrorResponse;
+
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: serde_json::Value) -> T {
+    let parsed: T = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+    parsed
+}
+
+#[test]
+fn unknown_strings_round_trip_unchanged() {
+    assert_eq!(round_trip::<BlockType>(json!("KANBAN")), BlockType::Unknown("KANBAN".to_owned()));
+    assert_eq!(round_trip::<ActivityStatus>(json!("PAUSED")), ActivityStatus::Unknown("PAUSED".to_owned()));
+    assert_eq!(round_trip::<StorageModule>(json!("avatars")), StorageModule::Unknown("avatars".to_owned()));
+    assert_eq!(round_trip::<BlockType>(json!("TODO_ITEM")), BlockType::TodoItem);
+}
+
+#[test]
+fn chat_roles_include_system_and_tool() {
+    assert_eq!(round_trip::<ChatRole>(json!("system")), ChatRole::System);
+    assert_eq!(round_trip::<ChatRole>(json!("tool")), ChatRole::Tool);
+    assert_eq!(round_trip::<ChatRole>(json!("critic")), ChatRole::Unknown("critic".to_owned()));
+}
+
+#[test]
+fn unknown_error_codes_are_kept() {
+    let body = json!({ "code": 4099, "message": "Slow down", "category": "VALIDATION", "details": null });
+    let response: WsErrorResponse = serde_json::from_value(body).unwrap();
+    assert_eq!(response.code, WebSocketErrorCode::Unknown(4099));
+    assert_eq!(serde_json::to_value(response.code).unwrap(), json!(4099));
+    assert_eq!(WebSocketErrorCode::from_code(4012), WebSocketErrorCode::AuthTokenExpired);
+    assert_eq!(WebSocketErrorCode::Unknown(4015).disposition(), ErrorDisposition::Reauthenticate);
+    assert!(WebSocketErrorCode::Unknown(5009).is_retryable());
+    assert!(WebSocketErrorCode::Unknown(4099).is_fatal());
+}
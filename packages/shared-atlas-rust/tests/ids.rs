@@ -0,0 +1,34 @@
+use serde_json::json;
+
+use shared_atlas_rust::ids::message_id::MessageId;
+use shared_atlas_rust::ids::note_id::NoteId;
+use shared_atlas_rust::interfaces::get_messages_response::GetMessagesResponse;
+use shared_atlas_rust::interfaces::note_join_payload::NoteJoinPayload;
+
+const NOTE: &str = "3f2b8c1e-7a4d-4e9b-9c2a-5d6e7f8a9b0c";
+
+#[test]
+fn ids_are_plain_json_strings() {
+    let payload: NoteJoinPayload = serde_json::from_value(json!({ "noteId": NOTE })).unwrap();
+    assert_eq!(payload.note_id, NOTE);
+    assert_eq!(serde_json::to_value(&payload).unwrap(), json!({ "noteId": NOTE }));
+    assert_eq!(NOTE.parse::<NoteId>().unwrap().to_string(), NOTE);
+}
+
+#[test]
+fn rejects_values_that_are_not_uuids() {
+    for value in ["note-1", "", "3f2b8c1e7a4d4e9b9c2a5d6e7f8a9b0c", "3f2b8c1e-7a4d-4e9b-9c2a-5d6e7f8a9b0g"] {
+        let err = NoteId::parse(value).unwrap_err();
+        assert_eq!(err.to_string(), format!("invalid note id {value:?}: expected a UUID"));
+    }
+    assert!(serde_json::from_value::<NoteJoinPayload>(json!({ "noteId": "ROOT" })).is_err());
+}
+
+#[test]
+fn message_ids_accept_the_root_parent() {
+    assert!(MessageId::parse("ROOT").unwrap().is_root());
+    assert_eq!(MessageId::root(), "ROOT");
+    let page: GetMessagesResponse =
+        serde_json::from_value(json!({ "messages": [], "hasMore": true, "nextCursor": NOTE })).unwrap();
+    assert_eq!(page.next_cursor.unwrap().as_str(), NOTE);
+}
@@ -0,0 +1,69 @@
+//! Local stand-in for the Atlas REST API, built from an axum router.
+#![allow(dead_code)]
+
+use axum::Router;
+use serde_json::{json, Value};
+
+/// Serves `router` on an ephemeral port and returns its base URL.
+pub async fn serve(router: Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    format!("http://{addr}")
+}
+
+/// The id of [`user`].
+pub const USER_ID: &str = "00000000-0000-4000-8000-0000000000a1";
+
+/// A stable UUID for fixture number `n`.
+pub fn uuid(n: u64) -> String {
+    format!("00000000-0000-4000-8000-{n:012x}")
+}
+
+pub fn user() -> Value {
+    json!({
+        "id": USER_ID,
+        "username": "alice",
+        "email": "alice@example.com",
+        "avatar": null,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "updatedAt": "2026-01-01T00:00:00.000Z"
+    })
+}
+
+/// NestJS-style error body.
+pub fn nest_error(status: u16, message: &str) -> Value {
+    json!({ "statusCode": status, "message": message })
+}
+
+pub fn note(id: &str, parent_id: Option<&str>) -> Value {
+    json!({
+        "id": id,
+        "userId": USER_ID,
+        "title": format!("Note {id}"),
+        "coverImage": null,
+        "icon": null,
+        "parentId": parent_id,
+        "isPublic": false,
+        "isDeleted": false,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "updatedAt": "2026-01-01T00:00:00.000Z",
+        "lastEditedBy": USER_ID
+    })
+}
+
+pub fn block(id: &str, note_id: &str, position: f64) -> Value {
+    json!({
+        "id": id,
+        "noteId": note_id,
+        "type": "TEXT",
+        "content": format!("Block {id}"),
+        "metadata": {},
+        "parentBlockId": null,
+        "position": position,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "updatedAt": "2026-01-01T00:00:00.000Z",
+        "createdBy": USER_ID,
+        "lastEditedBy": USER_ID
+    })
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "mock-server")]
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::client_error::ClientError;
+use shared_atlas_rust::dto::get_messages_dto::GetMessagesDto;
+use shared_atlas_rust::dto::login_dto::LoginDto;
+use shared_atlas_rust::error::api_error::ApiError;
+use shared_atlas_rust::interfaces::add_message_dto::AddMessageDto;
+use shared_atlas_rust::interfaces::chat_send_payload::ChatSendPayload;
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::interfaces::create_note_dto::CreateNoteDto;
+use shared_atlas_rust::interfaces::create_session_dto::CreateSessionDto;
+use shared_atlas_rust::mock::mock_server::{MockServer, DEFAULT_PASSWORD, DEFAULT_USERNAME};
+use shared_atlas_rust::socket::chat_socket::ChatSocket;
+use shared_atlas_rust::socket::collaboration_socket::CollaborationSocket;
+use shared_atlas_rust::socket::note_event::NoteEvent;
+use shared_atlas_rust::socket::note_subscription::NoteSubscription;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn login() -> LoginDto {
+    LoginDto { username: DEFAULT_USERNAME.to_owned(), password: DEFAULT_PASSWORD.to_owned() }
+}
+
+fn note(title: &str) -> CreateNoteDto {
+    CreateNoteDto { title: title.to_owned(), template_id: None, parent_id: None }
+}
+
+async fn next(events: &mut NoteSubscription) -> Option<NoteEvent> {
+    tokio::time::timeout(TIMEOUT, events.next()).await.expect("no note event")
+}
+
+#[tokio::test]
+async fn expired_tokens_are_refreshed_until_revoked() {
+    let server = MockServer::start().await.unwrap();
+    for mode in [AuthMode::Cookie, AuthMode::Bearer] {
+        let client = server.client(mode).unwrap();
+        assert_eq!(client.login(&login()).await.unwrap().user.id, server.user().id);
+        let created = client.create_note(&note("Plans")).await.unwrap();
+
+        server.expire_tokens();
+        assert_eq!(client.get_note(&created.id).await.unwrap().title, "Plans");
+
+        server.expire_tokens();
+        server.revoke_refresh_tokens();
+        assert!(matches!(client.get_note(&created.id).await, Err(ClientError::SessionExpired)));
+    }
+}
+
+#[tokio::test]
+async fn scripted_failures_answer_once() {
+    let server = MockServer::start().await.unwrap();
+    let client = server.client(AuthMode::Bearer).unwrap();
+    client.login(&login()).await.unwrap();
+
+    server.fail_next("/notes", 429, "Too Many Requests");
+    match client.create_note(&note("Plans")).await {
+        Err(ClientError::Api(ApiError::RateLimited { retry_after, .. })) => {
+            assert_eq!(retry_after, Some(Duration::from_secs(1)));
+        }
+        other => panic!("expected a rate limit, got {other:?}"),
+    }
+    assert!(client.create_note(&note("Plans")).await.is_ok());
+}
+
+#[tokio::test]
+async fn chat_answers_over_sse_and_socket() {
+    let server = MockServer::start().await.unwrap();
+    let client = server.client(AuthMode::Bearer).unwrap();
+    let tokens = client.login(&login()).await.unwrap().tokens;
+    let session = client.create_session(&CreateSessionDto {}).await.unwrap();
+
+    let dto = AddMessageDto { content: "hello there".to_owned(), role: None, model: None, parent_id: None };
+    let events: Vec<_> = client.add_message(&session.id, &dto).await.unwrap().collect().await;
+    assert!(matches!(events.last(), Some(Ok(ChatStreamEvent::Done { title: Some(title) })) if title == "hello there"));
+
+    server.set_stream_delay(Duration::from_millis(5));
+    let socket = ChatSocket::connect(server.socket_options(tokens.access_token)).await.unwrap();
+    let payload = ChatSendPayload {
+        session_id: session.id.clone(),
+        content: "again".to_owned(),
+        role: None,
+        model: None,
+        parent_id: None,
+    };
+    let stream = socket.send(payload).await.unwrap();
+    let events: Vec<_> = tokio::time::timeout(TIMEOUT, stream.collect()).await.unwrap();
+    let answer: String = events
+        .iter()
+        .filter_map(|event| match event {
+            ChatStreamEvent::AnswerChunk { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(answer, "Echo: again");
+    let query = GetMessagesDto { limit: None, before: None, leaf_message_id: None };
+    assert_eq!(client.list_messages(&session.id, &query).await.unwrap().messages.len(), 4);
+}
+
+#[tokio::test]
+async fn collaboration_limit_and_dropped_sockets() {
+    let server = MockServer::start().await.unwrap();
+    let tokens = server.issue_tokens(&server.user().id);
+    let client = server.client(AuthMode::Bearer).unwrap().with_tokens(tokens.clone());
+    let created = client.create_note(&note("Shared")).await.unwrap();
+    let first = CollaborationSocket::connect(server.socket_options(tokens.access_token.clone())).await.unwrap();
+    let second = CollaborationSocket::connect(server.socket_options(tokens.access_token)).await.unwrap();
+
+    server.set_max_editors(0);
+    let mut refused = first.join(&created.id).unwrap();
+    assert!(matches!(next(&mut refused).await, Some(NoteEvent::Limit(limit)) if limit.max_editors == 0.0));
+
+    server.set_max_editors(1);
+    let mut joined = second.join(&created.id).unwrap();
+    assert!(matches!(next(&mut joined).await, Some(NoteEvent::Sync(_))));
+    assert!(matches!(next(&mut joined).await, Some(NoteEvent::Collaborators(_))));
+
+    assert_eq!(server.connected_sockets(), 2);
+    server.drop_sockets();
+    assert!(matches!(next(&mut joined).await, Some(NoteEvent::Disconnected(_))));
+}
+
+#[tokio::test]
+async fn access_token_cookie_wins_over_bearer_header() {
+    let server = MockServer::start().await.unwrap();
+    let bob = server.add_user("bob", "hunter2");
+    let alice_tokens = server.issue_tokens(&server.user().id);
+    let bob_tokens = server.issue_tokens(&bob.id);
+
+    let profile: serde_json::Value = reqwest::Client::new()
+        .get(format!("{}/auth/profile", server.url()))
+        .header("cookie", format!("access_token={}", alice_tokens.access_token))
+        .bearer_auth(&bob_tokens.access_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(profile["username"], DEFAULT_USERNAME);
+}
+
+#[tokio::test]
+async fn gateways_answer_over_websocket() {
+    let server = MockServer::start().await.unwrap();
+    let client = server.client(AuthMode::Bearer).unwrap();
+    let tokens = client.login(&login()).await.unwrap().tokens;
+    let session = client.create_session(&CreateSessionDto {}).await.unwrap();
+    let created = client.create_note(&note("Plans")).await.unwrap();
+
+    let chat = ChatSocket::connect(server.websocket_options(tokens.access_token.clone())).await.unwrap();
+    let payload = ChatSendPayload {
+        session_id: session.id.clone(),
+        content: "over the wire".to_owned(),
+        role: None,
+        model: None,
+        parent_id: None,
+    };
+    let events: Vec<_> = tokio::time::timeout(TIMEOUT, chat.send(payload).await.unwrap().collect()).await.unwrap();
+    let answer: String = events
+        .iter()
+        .filter_map(|event| match event {
+            ChatStreamEvent::AnswerChunk { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(answer, "Echo: over the wire");
+
+    let collaboration = CollaborationSocket::connect(server.websocket_options(tokens.access_token)).await.unwrap();
+    let mut joined = collaboration.join(&created.id).unwrap();
+    assert!(matches!(next(&mut joined).await, Some(NoteEvent::Sync(_))));
+    assert!(matches!(next(&mut joined).await, Some(NoteEvent::Collaborators(_))));
+    assert_eq!(server.connected_sockets(), 2);
+
+    server.drop_sockets();
+    assert!(matches!(next(&mut joined).await, Some(NoteEvent::Disconnected(_))));
+}
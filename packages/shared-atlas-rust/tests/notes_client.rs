@@ -0,0 +1,117 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{json, Value};
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::client_error::ClientError;
+use shared_atlas_rust::dto::list_notes_query::ListNotesQuery;
+use shared_atlas_rust::error::api_error::ApiError;
+use shared_atlas_rust::ids::block_id::BlockId;
+use shared_atlas_rust::ids::note_id::NoteId;
+use shared_atlas_rust::interfaces::move_block_dto::MoveBlockDto;
+use shared_atlas_rust::interfaces::update_note_dto::UpdateNoteDto;
+
+use mock_http::{block, nest_error, note, serve, uuid};
+
+fn api() -> Router {
+    Router::new()
+        .route(
+            "/notes",
+            get(|Query(query): Query<HashMap<String, String>>| async move {
+                let parent = query.get("parentId").cloned();
+                let limit = query.get("limit").map_or(50, |limit| limit.parse().unwrap());
+                let notes: Vec<Value> = (0..limit.min(2)).map(|i| note(&uuid(i), parent.as_deref())).collect();
+                Json(json!({ "notes": notes, "total": 7 }))
+            }),
+        )
+        .route(
+            "/notes/{id}",
+            get(|Path(id): Path<String>| async move {
+                if id == uuid(404) {
+                    return (StatusCode::NOT_FOUND, Json(nest_error(404, "Note not found"))).into_response();
+                }
+                Json(note(&id, None)).into_response()
+            })
+            .patch(|Path(id): Path<String>, Json(body): Json<Value>| async move {
+                // `None` fields must be omitted, not sent as `null`.
+                assert_eq!(body, json!({ "title": "Renamed" }));
+                let mut renamed = note(&id, None);
+                renamed["title"] = body["title"].clone();
+                Json(renamed).into_response()
+            })
+            .delete(|| async { Json(json!({ "message": "Note deleted successfully" })) }),
+        )
+        .route(
+            "/notes/{id}/blocks",
+            get(|Path(id): Path<String>| async move {
+                Json(json!([block(&uuid(11), &id, 1.0), block(&uuid(12), &id, 2.0)]))
+            }),
+        )
+        .route(
+            "/blocks/{id}/move",
+            post(|Path(id): Path<String>, Json(body): Json<Value>| async move {
+                let mut moved = block(&id, &uuid(1), body["position"].as_f64().unwrap());
+                moved["parentBlockId"] = body["parentBlockId"].clone();
+                Json(moved)
+            }),
+        )
+}
+
+async fn client() -> AtlasClient {
+    AtlasClient::new(serve(api()).await, AuthMode::Cookie).unwrap()
+}
+
+#[tokio::test]
+async fn lists_children_with_typed_query() {
+    let client = client().await;
+    let parent = NoteId::parse(uuid(100)).unwrap();
+    let query = ListNotesQuery { parent_id: Some(parent.clone()), limit: Some(1), offset: None };
+    let page = client.list_notes(&query).await.unwrap();
+    assert_eq!(page.total, 7);
+    assert_eq!(page.notes.len(), 1);
+    assert_eq!(page.notes[0].parent_id, Some(parent));
+
+    let roots = client.list_notes(&ListNotesQuery::default()).await.unwrap();
+    assert_eq!(roots.notes.len(), 2);
+    assert!(roots.notes.iter().all(|note| note.parent_id.is_none()));
+}
+
+#[tokio::test]
+async fn note_crud_round_trip() {
+    let client = client().await;
+    let id = NoteId::parse(uuid(1)).unwrap();
+    let with_blocks = client.get_note_with_blocks(&id).await.unwrap();
+    assert_eq!(with_blocks.note.id, id);
+    assert_eq!(with_blocks.blocks.len(), 2);
+
+    let update = UpdateNoteDto { title: Some("Renamed".to_owned()), ..Default::default() };
+    assert_eq!(client.update_note(&id, &update).await.unwrap().title, "Renamed");
+    assert_eq!(client.delete_note(&id).await.unwrap().message, "Note deleted successfully");
+
+    match client.get_note(&NoteId::parse(uuid(404)).unwrap()).await {
+        Err(ClientError::Api(ApiError::NotFound(body))) => assert_eq!(body.message, "Note not found"),
+        other => panic!("expected 404, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn moves_block() {
+    let client = client().await;
+    let id = BlockId::parse(uuid(12)).unwrap();
+    let parent = BlockId::parse(uuid(10)).unwrap();
+    let dto = MoveBlockDto { position: 1.5, parent_block_id: Some(parent.clone()) };
+    let moved = client.move_block(&id, &dto).await.unwrap();
+    assert_eq!(moved.id, id);
+    assert_eq!(moved.position, 1.5);
+    assert_eq!(moved.parent_block_id, Some(parent));
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use futures_util::{StreamExt, TryStreamExt};
+use serde_json::{json, Value};
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::dto::get_messages_dto::GetMessagesDto;
+use shared_atlas_rust::dto::list_notes_query::ListNotesQuery;
+use shared_atlas_rust::ids::session_id::SessionId;
+
+use mock_http::{note, serve, uuid};
+
+/// Query strings received by the stand-in server, in order.
+type Requests = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+const MESSAGES: usize = 5;
+
+/// Sessions with paged messages and with a cursorless `hasMore`.
+const PAGED: u64 = 1;
+const CURSORLESS: u64 = 2;
+
+fn session(n: u64) -> SessionId {
+    SessionId::parse(uuid(n)).unwrap()
+}
+
+fn message(index: usize) -> Value {
+    json!({
+        "id": uuid(index as u64),
+        "sessionId": uuid(PAGED),
+        "role": "user",
+        "content": format!("message {index}"),
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "parentId": "ROOT"
+    })
+}
+
+/// Messages `0..5`, paged newest first like `ChatService.getSessionMessages`.
+async fn messages(State(requests): State<Requests>, Query(query): Query<HashMap<String, String>>) -> Json<Value> {
+    requests.lock().unwrap().push(query.clone());
+    let limit: usize = query["limit"].parse().unwrap();
+    let end = match query.get("before") {
+        Some(before) => usize::from_str_radix(&before[24..], 16).unwrap(),
+        None => MESSAGES,
+    };
+    let start = end.saturating_sub(limit);
+    let has_more = start > 0;
+    let page: Vec<Value> = (start..end).map(message).collect();
+    let cursor = if has_more { json!(uuid(start as u64)) } else { Value::Null };
+    Json(json!({ "messages": page, "hasMore": has_more, "nextCursor": cursor }))
+}
+
+async fn notes(State(requests): State<Requests>, Query(query): Query<HashMap<String, String>>) -> Json<Value> {
+    requests.lock().unwrap().push(query.clone());
+    let limit: usize = query["limit"].parse().unwrap();
+    let offset: usize = query.get("offset").map_or(0, |offset| offset.parse().unwrap());
+    let page: Vec<Value> = (offset..(offset + limit).min(5)).map(|i| note(&uuid(i as u64), None)).collect();
+    Json(json!({ "notes": page, "total": 5 }))
+}
+
+async fn setup() -> (AtlasClient, Requests) {
+    let requests = Requests::default();
+    let router = Router::new()
+        .route(&format!("/chat/sessions/{}/messages", uuid(PAGED)), get(messages))
+        .route(
+            &format!("/chat/sessions/{}/messages", uuid(CURSORLESS)),
+            // `hasMore` without a cursor must not loop over the first page.
+            get(|| async { Json(json!({ "messages": [message(0)], "hasMore": true, "nextCursor": null })) }),
+        )
+        .route("/notes", get(notes))
+        .with_state(requests.clone());
+    (AtlasClient::new(serve(router).await, AuthMode::Cookie).unwrap(), requests)
+}
+
+/// The fixture numbers of `items`, see [`uuid`].
+fn ids<T>(items: &[T], id: impl Fn(&T) -> &str) -> Vec<u64> {
+    items.iter().map(|item| u64::from_str_radix(&id(item)[24..], 16).unwrap()).collect()
+}
+
+#[tokio::test]
+async fn follows_message_cursors_to_the_end() {
+    let (client, requests) = setup().await;
+    let query = GetMessagesDto { limit: Some(2), before: None, leaf_message_id: None };
+    let pages: Vec<_> = client.message_pages(&session(PAGED), query).try_collect().await.unwrap();
+    let pages: Vec<_> = pages.iter().map(|page| ids(page, |message| message.id.as_str())).collect();
+    assert_eq!(pages, vec![vec![3, 4], vec![1, 2], vec![0]]);
+    assert_eq!(requests.lock().unwrap().len(), 3);
+
+    let query = GetMessagesDto { limit: None, before: None, leaf_message_id: None };
+    let pages: Vec<_> = client.message_pages(&session(CURSORLESS), query).try_collect().await.unwrap();
+    assert_eq!(pages.len(), 1);
+}
+
+#[tokio::test]
+async fn caps_page_size_and_stops_early() {
+    let (client, requests) = setup().await;
+    let query = GetMessagesDto { limit: Some(500), before: None, leaf_message_id: None };
+    let all: Vec<_> = client.message_pages(&session(PAGED), query).items().try_collect().await.unwrap();
+    assert_eq!(all.len(), MESSAGES);
+    assert_eq!(requests.lock().unwrap()[0]["limit"], "100");
+
+    requests.lock().unwrap().clear();
+    let query = GetMessagesDto { limit: Some(1), before: None, leaf_message_id: None };
+    let first: Vec<_> = client.message_pages(&session(PAGED), query).items().take(1).try_collect().await.unwrap();
+    assert_eq!(ids(&first, |message| message.id.as_str()), vec![4]);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    // The first page and at most one prefetched page.
+    assert!(requests.lock().unwrap().len() <= 2);
+}
+
+#[tokio::test]
+async fn pages_notes_by_offset() {
+    let (client, requests) = setup().await;
+    let query = ListNotesQuery { limit: Some(2), ..Default::default() };
+    let notes: Vec<_> = client.note_pages(query).items().try_collect().await.unwrap();
+    assert_eq!(ids(&notes, |note| note.id.as_str()), vec![0, 1, 2, 3, 4]);
+    let offsets: Vec<_> = requests.lock().unwrap().iter().map(|query| query.get("offset").cloned()).collect();
+    assert_eq!(offsets, vec![None, Some("2".to_owned()), Some("4".to_owned())]);
+}
@@ -0,0 +1,487 @@
+//! Golden vectors for the Engine.IO v4 / Socket.IO v5 codec.
+//!
+//! The protocol-level strings follow `engine.io-parser` v5 and
+//! `socket.io-parser` v4 (the versions bundled with the NestJS gateways).
+//! The Atlas frames are what the `ChatGateway`, `CollaborationGateway` and
+//! `ActivityGateway` put on the wire, with object keys in the order the
+//! gateway code builds its payloads (`JSON.stringify` keeps insertion order),
+//! e.g. `{"type":"answer_chunk","content":"Hi"}`. `serde_json` does not keep
+//! that order, so the tests compare decoded values, never re-encoded bytes.
+
+use serde_json::{json, Value};
+
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::events::atlas_server_event::AtlasServerEvent;
+use shared_atlas_rust::interfaces::activity_status::ActivityStatus;
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::interfaces::error_category::ErrorCategory;
+use shared_atlas_rust::interfaces::web_socket_error_code::WebSocketErrorCode;
+use shared_atlas_rust::protocol::engine_handshake::EngineHandshake;
+use shared_atlas_rust::protocol::engine_packet::EnginePacket;
+use shared_atlas_rust::protocol::frame::Frame;
+use shared_atlas_rust::protocol::protocol_error::ProtocolError;
+use shared_atlas_rust::protocol::socket_decoder::SocketDecoder;
+use shared_atlas_rust::protocol::socket_packet::SocketPacket;
+use shared_atlas_rust::protocol::{ACTIVITY_NAMESPACE, CHAT_NAMESPACE, COLLABORATION_NAMESPACE};
+
+const NOTE_ID: &str = "3f2b8c1e-6d4a-4f7e-9b2c-5a1d7e8f9c0b";
+const SESSION_ID: &str = "7c9e6679-7425-40de-944b-e07fc1f90ae7";
+const USER_ID: &str = "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d";
+
+fn engine_round_trip(wire: &str, expected: EnginePacket) {
+    let decoded = EnginePacket::decode(Frame::from(wire)).unwrap();
+    assert_eq!(decoded, expected, "decoding {wire:?}");
+    assert_eq!(EnginePacket::decode(expected.encode()).unwrap(), expected, "re-decoding {wire:?}");
+}
+
+fn socket_round_trip(wire: &str, expected: SocketPacket) {
+    let decoded = SocketPacket::decode(wire).unwrap();
+    assert_eq!(decoded, expected, "decoding {wire:?}");
+    assert_eq!(SocketPacket::decode(&expected.encode()).unwrap(), expected, "re-decoding {wire:?}");
+}
+
+/// Decodes a recorded WebSocket frame carrying an `EVENT`: the namespace,
+/// the ack id, the raw `[name, payload]` array and the typed event.
+fn server_event(wire: &str) -> (String, Option<u64>, Value, AtlasServerEvent) {
+    let Ok(EnginePacket::Message(Frame::Text(text))) = EnginePacket::decode(Frame::from(wire)) else {
+        panic!("expected a text message in {wire:?}");
+    };
+    let Ok(SocketPacket::Event { namespace, id, data }) = SocketPacket::decode(&text) else {
+        panic!("expected an event in {wire:?}");
+    };
+    let raw = Value::Array(data);
+    let event = serde_json::from_value(raw.clone()).unwrap_or_else(|err| panic!("typing {wire:?}: {err}"));
+    (namespace, id, raw, event)
+}
+
+/// Decodes a recorded `chat:stream` frame into its `ChatStreamEvent`.
+fn chat_stream(wire: &str, payload: Value) -> ChatStreamEvent {
+    let (namespace, id, raw, event) = server_event(wire);
+    assert_eq!(namespace, CHAT_NAMESPACE);
+    assert_eq!(id, None);
+    assert_eq!(raw, json!(["chat:stream", payload]), "decoding {wire:?}");
+    let AtlasServerEvent::ChatStream(event) = event else {
+        panic!("expected chat:stream in {wire:?}");
+    };
+    assert_eq!(serde_json::to_value(&event).unwrap(), payload, "re-encoding {wire:?}");
+    event
+}
+
+#[test]
+fn engine_open() {
+    engine_round_trip(
+        r#"0{"sid":"lv_VI97HAXpY6yYWAAAC","upgrades":["websocket"],"pingInterval":10000,"pingTimeout":20000,"maxPayload":1000000}"#,
+        EnginePacket::Open(EngineHandshake {
+            sid: "lv_VI97HAXpY6yYWAAAC".to_owned(),
+            upgrades: vec!["websocket".to_owned()],
+            ping_interval: 10000,
+            ping_timeout: 20000,
+            max_payload: 1_000_000,
+        }),
+    );
+}
+
+#[test]
+fn engine_control_packets() {
+    engine_round_trip("1", EnginePacket::Close);
+    engine_round_trip("2", EnginePacket::Ping(None));
+    engine_round_trip("3", EnginePacket::Pong(None));
+    engine_round_trip("2probe", EnginePacket::Ping(Some("probe".to_owned())));
+    engine_round_trip("3probe", EnginePacket::Pong(Some("probe".to_owned())));
+    engine_round_trip("5", EnginePacket::Upgrade);
+    engine_round_trip("6", EnginePacket::Noop);
+}
+
+#[test]
+fn engine_messages() {
+    engine_round_trip("4hello", EnginePacket::Message(Frame::from("hello")));
+    let binary = EnginePacket::Message(Frame::from(vec![1u8, 2, 3, 4]));
+    assert_eq!(EnginePacket::decode(Frame::from(vec![1u8, 2, 3, 4])).unwrap(), binary);
+    assert_eq!(binary.encode(), Frame::from(vec![1u8, 2, 3, 4]));
+}
+
+#[test]
+fn engine_polling_payload() {
+    let wire = "4hello\u{1e}bAQIDBA==\u{1e}2";
+    let packets = vec![
+        EnginePacket::Message(Frame::from("hello")),
+        EnginePacket::Message(Frame::from(vec![1u8, 2, 3, 4])),
+        EnginePacket::Ping(None),
+    ];
+    assert_eq!(EnginePacket::decode_payload(wire).unwrap(), packets);
+    assert_eq!(EnginePacket::encode_payload(&packets), wire);
+}
+
+#[test]
+fn engine_rejects_garbage() {
+    assert!(matches!(EnginePacket::decode(Frame::from("")), Err(ProtocolError::Empty)));
+    assert!(matches!(EnginePacket::decode(Frame::from("9")), Err(ProtocolError::UnknownPacketType('9'))));
+    assert!(matches!(EnginePacket::decode_payload("b!!"), Err(ProtocolError::InvalidBase64(_))));
+}
+
+#[test]
+fn socket_connect() {
+    socket_round_trip("0", SocketPacket::connect("/", None));
+    socket_round_trip(r#"0/admin,{"token":"123"}"#, SocketPacket::connect("/admin", Some(json!({ "token": "123" }))));
+    socket_round_trip(
+        r#"0/api/chat,{"token":"eyJhbGciOiJIUzI1NiJ9"}"#,
+        SocketPacket::connect(CHAT_NAMESPACE, Some(json!({ "token": "eyJhbGciOiJIUzI1NiJ9" }))),
+    );
+    socket_round_trip(
+        r#"0/api/chat,{"sid":"oSO0OpakMV_3jnilAAAA"}"#,
+        SocketPacket::connect(CHAT_NAMESPACE, Some(json!({ "sid": "oSO0OpakMV_3jnilAAAA" }))),
+    );
+}
+
+#[test]
+fn socket_disconnect() {
+    socket_round_trip("1", SocketPacket::Disconnect { namespace: "/".to_owned() });
+    socket_round_trip("1/admin,", SocketPacket::Disconnect { namespace: "/admin".to_owned() });
+}
+
+#[test]
+fn socket_event_and_ack() {
+    socket_round_trip(
+        r#"2["hello",1]"#,
+        SocketPacket::Event { namespace: "/".to_owned(), id: None, data: vec![json!("hello"), json!(1)] },
+    );
+    socket_round_trip(
+        r#"2/admin,456["project:delete",123]"#,
+        SocketPacket::Event {
+            namespace: "/admin".to_owned(),
+            id: Some(456),
+            data: vec![json!("project:delete"), json!(123)],
+        },
+    );
+    socket_round_trip(r#"3/admin,456[]"#, SocketPacket::ack("/admin", 456, vec![]));
+}
+
+#[test]
+fn socket_connect_error() {
+    socket_round_trip(
+        r#"4{"message":"Not authorized"}"#,
+        SocketPacket::ConnectError { namespace: "/".to_owned(), data: json!({ "message": "Not authorized" }) },
+    );
+    socket_round_trip(
+        r#"4/activity,{"message":"Authentication error: No token provided"}"#,
+        SocketPacket::ConnectError {
+            namespace: "/activity".to_owned(),
+            data: json!({ "message": "Authentication error: No token provided" }),
+        },
+    );
+}
+
+#[test]
+fn socket_binary_event() {
+    let header = r#"51-["hello",{"_placeholder":true,"num":0}]"#;
+    let packet = SocketPacket::BinaryEvent {
+        namespace: "/".to_owned(),
+        id: None,
+        data: vec![json!("hello"), SocketPacket::placeholder(0)],
+        attachments: vec![vec![1, 2, 3]],
+    };
+    assert_eq!(packet.encode(), header);
+    assert_eq!(
+        packet.to_engine_packets(),
+        vec![EnginePacket::Message(Frame::from(header)), EnginePacket::Message(Frame::from(vec![1u8, 2, 3]))]
+    );
+
+    let mut decoder = SocketDecoder::new();
+    assert_eq!(decoder.feed(Frame::from(header)).unwrap(), None);
+    assert!(decoder.is_reconstructing());
+    assert_eq!(decoder.feed(Frame::from(vec![1u8, 2, 3])).unwrap(), Some(packet));
+    assert!(!decoder.is_reconstructing());
+
+    assert!(matches!(SocketPacket::decode(header), Err(ProtocolError::MissingAttachments(1))));
+}
+
+#[test]
+fn socket_binary_ack() {
+    let header = r#"62-/admin,456[{"_placeholder":true,"num":0},{"_placeholder":true,"num":1}]"#;
+    let mut decoder = SocketDecoder::new();
+    assert_eq!(decoder.feed(Frame::from(header)).unwrap(), None);
+    assert_eq!(decoder.feed(Frame::from(vec![1u8])).unwrap(), None);
+    let packet = decoder.feed(Frame::from(vec![2u8])).unwrap().unwrap();
+    assert_eq!(
+        packet,
+        SocketPacket::BinaryAck {
+            namespace: "/admin".to_owned(),
+            id: 456,
+            data: vec![SocketPacket::placeholder(0), SocketPacket::placeholder(1)],
+            attachments: vec![vec![1], vec![2]],
+        }
+    );
+    assert_eq!(packet.encode(), header);
+}
+
+#[test]
+fn socket_decoder_rejects_stray_attachments() {
+    let mut decoder = SocketDecoder::new();
+    assert!(matches!(decoder.feed(Frame::from(vec![1u8])), Err(ProtocolError::UnexpectedFrame)));
+    assert!(!decoder.is_reconstructing());
+}
+
+#[test]
+fn socket_decoder_keeps_events_that_interrupt_reconstruction() {
+    let mut decoder = SocketDecoder::new();
+    decoder.feed(Frame::from(r#"52-["hello",{"_placeholder":true,"num":0},{"_placeholder":true,"num":1}]"#)).unwrap();
+    assert_eq!(decoder.feed(Frame::from(vec![1u8])).unwrap(), None);
+    let event = decoder.feed(Frame::from(r#"2/api/chat,["chat:stream",{"type":"answer_chunk","content":"Hi"}]"#));
+    assert_eq!(
+        event.unwrap(),
+        Some(SocketPacket::Event {
+            namespace: CHAT_NAMESPACE.to_owned(),
+            id: None,
+            data: vec![json!("chat:stream"), json!({ "type": "answer_chunk", "content": "Hi" })],
+        })
+    );
+    assert!(!decoder.is_reconstructing());
+    // The rest of the dropped packet's attachments no longer belong to anything.
+    assert!(matches!(decoder.feed(Frame::from(vec![2u8])), Err(ProtocolError::UnexpectedFrame)));
+
+    // A new binary header restarts reconstruction from scratch.
+    decoder.feed(Frame::from(r#"51-["hello",{"_placeholder":true,"num":0}]"#)).unwrap();
+    assert_eq!(decoder.feed(Frame::from(r#"51-["again",{"_placeholder":true,"num":0}]"#)).unwrap(), None);
+    let packet = decoder.feed(Frame::from(vec![3u8])).unwrap().unwrap();
+    assert_eq!(packet.attachments(), &[vec![3u8]]);
+    assert!(matches!(packet, SocketPacket::BinaryEvent { ref data, .. } if data[0] == json!("again")));
+}
+
+#[test]
+fn socket_rejects_malformed() {
+    assert!(matches!(SocketPacket::decode(""), Err(ProtocolError::Empty)));
+    assert!(matches!(SocketPacket::decode("7"), Err(ProtocolError::UnknownPacketType('7'))));
+    assert!(matches!(SocketPacket::decode("2{}"), Err(ProtocolError::InvalidPayload(_))));
+    assert!(matches!(SocketPacket::decode("3[]"), Err(ProtocolError::InvalidPayload(_))));
+    assert!(matches!(SocketPacket::decode("0[]"), Err(ProtocolError::InvalidPayload(_))));
+    assert!(matches!(SocketPacket::decode("2[\"a\""), Err(ProtocolError::InvalidJson(_))));
+    assert!(matches!(SocketPacket::decode("5x-[\"a\"]"), Err(ProtocolError::InvalidNumber(_))));
+}
+
+#[test]
+fn socket_rejects_non_ascii_and_truncated_frames() {
+    assert!(matches!(SocketPacket::decode("é"), Err(ProtocolError::UnknownPacketType('é'))));
+    assert!(matches!(SocketPacket::decode("€2[\"a\"]"), Err(ProtocolError::UnknownPacketType('€'))));
+    assert!(matches!(SocketPacket::decode("5"), Err(ProtocolError::InvalidNumber(_))));
+    assert!(matches!(SocketPacket::decode("51"), Err(ProtocolError::InvalidNumber(_))));
+    assert!(matches!(SocketPacket::decode("2/api/chat,"), Err(ProtocolError::InvalidPayload(_))));
+    assert!(matches!(SocketPacket::decode("3/api/chat,7"), Err(ProtocolError::InvalidPayload(_))));
+    assert!(matches!(SocketPacket::decode("2[\"chat:sé"), Err(ProtocolError::InvalidJson(_))));
+
+    let mut decoder = SocketDecoder::new();
+    assert!(matches!(decoder.feed(Frame::from("é")), Err(ProtocolError::UnknownPacketType('é'))));
+    assert!(!decoder.is_reconstructing());
+}
+
+#[test]
+fn socket_does_not_trust_attachment_counts() {
+    let headers = [("518446744073709551615-[\"a\"]", usize::MAX), ("51000000000000-[\"a\"]", 1_000_000_000_000)];
+    for (header, count) in headers {
+        assert!(matches!(SocketPacket::decode(header), Err(ProtocolError::MissingAttachments(n)) if n == count));
+        let mut decoder = SocketDecoder::new();
+        assert_eq!(decoder.feed(Frame::from(header)).unwrap(), None);
+        assert_eq!(decoder.feed(Frame::from(vec![1u8])).unwrap(), None);
+        assert!(decoder.is_reconstructing());
+    }
+}
+
+#[test]
+fn atlas_chat_stream_frames() {
+    assert_eq!(
+        chat_stream(
+            r#"42/api/chat,["chat:stream",{"type":"answer_chunk","content":"Hi"}]"#,
+            json!({ "type": "answer_chunk", "content": "Hi" }),
+        ),
+        ChatStreamEvent::AnswerChunk { content: "Hi".to_owned() }
+    );
+    assert_eq!(
+        chat_stream(
+            r#"42/api/chat,["chat:stream",{"type":"thought","content":"I should search the notes."}]"#,
+            json!({ "type": "thought", "content": "I should search the notes." }),
+        ),
+        ChatStreamEvent::Thought { content: "I should search the notes.".to_owned() }
+    );
+    assert_eq!(
+        chat_stream(
+            r#"42/api/chat,["chat:stream",{"type":"tool_call","tool":"search_notes","args":{"query":"rust","limit":5}}]"#,
+            json!({ "type": "tool_call", "tool": "search_notes", "args": { "query": "rust", "limit": 5 } }),
+        ),
+        ChatStreamEvent::ToolCall { tool: "search_notes".to_owned(), args: json!({ "query": "rust", "limit": 5 }) }
+    );
+    assert_eq!(
+        chat_stream(
+            r#"42/api/chat,["chat:stream",{"type":"tool_result","tool":"search_notes","result":{"total":2}}]"#,
+            json!({ "type": "tool_result", "tool": "search_notes", "result": { "total": 2 } }),
+        ),
+        ChatStreamEvent::ToolResult { tool: "search_notes".to_owned(), result: json!({ "total": 2 }) }
+    );
+    assert_eq!(
+        chat_stream(
+            r#"42/api/chat,["chat:stream",{"type":"done","title":"Rust notes"}]"#,
+            json!({ "type": "done", "title": "Rust notes" }),
+        ),
+        ChatStreamEvent::Done { title: Some("Rust notes".to_owned()) }
+    );
+    assert_eq!(
+        chat_stream(
+            r#"42/api/chat,["chat:stream",{"type":"error","message":"Stream failed"}]"#,
+            json!({ "type": "error", "message": "Stream failed" }),
+        ),
+        ChatStreamEvent::Error { message: "Stream failed".to_owned() }
+    );
+}
+
+#[test]
+fn atlas_chat_control_frames() {
+    let Ok(EnginePacket::Message(Frame::Text(text))) =
+        EnginePacket::decode(Frame::from(r#"40/api/chat,{"sid":"oSO0OpakMV_3jnilAAAA"}"#))
+    else {
+        panic!("expected a text message");
+    };
+    assert_eq!(
+        SocketPacket::decode(&text).unwrap(),
+        SocketPacket::connect(CHAT_NAMESPACE, Some(json!({ "sid": "oSO0OpakMV_3jnilAAAA" })))
+    );
+
+    let (_, _, raw, event) = server_event(
+        r#"42/api/chat,["error",{"code":4011,"message":"No authentication token provided","category":"AUTH"}]"#,
+    );
+    assert_eq!(
+        raw,
+        json!(["error", { "code": 4011, "message": "No authentication token provided", "category": "AUTH" }])
+    );
+    let AtlasServerEvent::Error(error) = event else { panic!("expected error") };
+    assert_eq!(error.code, WebSocketErrorCode::AuthTokenInvalid);
+    assert_eq!(error.category, ErrorCategory::Auth);
+
+    let (_, _, _, event) = server_event(r#"42/api/chat,["chat:error",{"error":"Unauthorized"}]"#);
+    assert!(matches!(event, AtlasServerEvent::ChatError(ref payload) if payload.error == "Unauthorized"));
+
+    let (_, _, _, event) = server_event(r#"42/api/chat,["auth:token-expiring",{"expiresIn":300,"action":"refresh"}]"#);
+    let AtlasServerEvent::TokenExpiring(expiring) = event else { panic!("expected auth:token-expiring") };
+    assert_eq!(expiring.expires_in, 300.0);
+    assert_eq!(expiring.action.as_deref(), Some("refresh"));
+
+    let (_, _, _, event) = server_event(r#"42/api/chat,["auth:token-renewed",{"success":true}]"#);
+    assert!(matches!(event, AtlasServerEvent::TokenRenewed(ref payload) if payload.success));
+
+    let (_, _, _, event) = server_event(r#"42/api/chat,["auth:error",{"message":"Invalid refresh token"}]"#);
+    assert!(matches!(event, AtlasServerEvent::AuthError(ref payload) if payload.message == "Invalid refresh token"));
+}
+
+#[test]
+fn atlas_collaboration_frames() {
+    let wire = format!(
+        r#"42/api/collaboration,["yjs:sync",{{"noteId":"{NOTE_ID}","update":"AQHYqOH2DQAEAQ==","stateVector":"AQHYqOH2DQE="}}]"#
+    );
+    let (namespace, _, raw, event) = server_event(&wire);
+    assert_eq!(namespace, COLLABORATION_NAMESPACE);
+    assert_eq!(
+        raw,
+        json!(["yjs:sync", { "noteId": NOTE_ID, "update": "AQHYqOH2DQAEAQ==", "stateVector": "AQHYqOH2DQE=" }])
+    );
+    let AtlasServerEvent::YjsSync(sync) = event else { panic!("expected yjs:sync") };
+    assert_eq!(sync.note_id.as_str(), NOTE_ID);
+    assert_eq!(sync.state_vector, "AQHYqOH2DQE=");
+
+    let wire = format!(
+        r##"42/api/collaboration,["presence:list",[{{"userId":"{USER_ID}","username":"bob","color":"#FF6B6B","connectedAt":"2026-01-05T10:00:00.000Z"}}]]"##
+    );
+    let (_, _, _, event) = server_event(&wire);
+    let AtlasServerEvent::PresenceList(list) = event else { panic!("expected presence:list") };
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].user_id.as_str(), USER_ID);
+    assert_eq!(list[0].avatar, None);
+    assert_eq!(list[0].connected_at.to_string(), "2026-01-05T10:00:00.000Z");
+
+    let wire = format!(
+        r#"42/api/collaboration,["cursor:update",{{"noteId":"{NOTE_ID}","userId":"{USER_ID}","position":{{"blockId":"b1","offset":4}}}}]"#
+    );
+    let (_, _, _, event) = server_event(&wire);
+    let AtlasServerEvent::CursorUpdate(cursor) = event else { panic!("expected cursor:update") };
+    assert_eq!(cursor.user_id.as_ref().map(|id| id.as_str()), Some(USER_ID));
+    assert_eq!(cursor.position, Some(json!({ "blockId": "b1", "offset": 4 })));
+    assert_eq!(cursor.selection, None);
+
+    let wire = format!(r#"42/api/collaboration,["presence:leave",{{"userId":"{USER_ID}"}}]"#);
+    let (_, _, _, event) = server_event(&wire);
+    assert!(matches!(event, AtlasServerEvent::PresenceLeave(ref payload) if payload.user_id.as_str() == USER_ID));
+
+    let (_, _, _, event) = server_event(
+        r#"42/api/collaboration,["collaboration:limit",{"error":"Maximum editors reached","currentEditors":5,"maxEditors":5}]"#,
+    );
+    let AtlasServerEvent::CollaborationLimit(limit) = event else { panic!("expected collaboration:limit") };
+    assert_eq!((limit.current_editors, limit.max_editors), (5.0, 5.0));
+}
+
+#[test]
+fn atlas_reliable_ping_ack() {
+    let (namespace, id, raw, event) = server_event(r#"42/api/collaboration,0["packet:ping",{}]"#);
+    assert_eq!(namespace, COLLABORATION_NAMESPACE);
+    assert_eq!(raw, json!(["packet:ping", {}]));
+    assert!(matches!(event, AtlasServerEvent::PacketPing(_)));
+    let id = id.expect("packet:ping requests an ack");
+    let ack = SocketPacket::ack(&namespace, id, vec![]);
+    assert_eq!(SocketPacket::decode(&ack.encode()).unwrap(), SocketPacket::decode("3/api/collaboration,0[]").unwrap());
+}
+
+#[test]
+fn atlas_activity_frames() {
+    let wire = format!(
+        r#"42/activity,["activity:status",{{"sessionId":"{SESSION_ID}","activityId":"0f8fad5b-d9cb-469f-a165-70867728950e","type":"TOOL_EXECUTION","description":"Searching notes","status":"STARTED","timestamp":1767607200000}}]"#
+    );
+    let (namespace, _, _, event) = server_event(&wire);
+    assert_eq!(namespace, ACTIVITY_NAMESPACE);
+    let AtlasServerEvent::ActivityStatus(activity) = event else { panic!("expected activity:status") };
+    assert_eq!(activity.session_id.as_str(), SESSION_ID);
+    assert_eq!(activity.status, ActivityStatus::Started);
+    assert_eq!(activity.timestamp.as_millis(), 1_767_607_200_000);
+    assert_eq!(activity.metadata, None);
+
+    let Ok(EnginePacket::Message(Frame::Text(text))) =
+        EnginePacket::decode(Frame::from(r#"44/activity,{"message":"Authentication error: Invalid token"}"#))
+    else {
+        panic!("expected a text message");
+    };
+    assert_eq!(
+        SocketPacket::decode(&text).unwrap(),
+        SocketPacket::ConnectError {
+            namespace: ACTIVITY_NAMESPACE.to_owned(),
+            data: json!({ "message": "Authentication error: Invalid token" }),
+        }
+    );
+}
+
+#[test]
+fn atlas_client_frames() {
+    // As sent by the web client: `socket.emit('chat:send', payload, ack)`.
+    let wire = format!(
+        r#"2/api/chat,0["chat:send",{{"sessionId":"{SESSION_ID}","content":"hello","model":"gemini-2.5-flash","parentId":"ROOT"}}]"#
+    );
+    let SocketPacket::Event { namespace, id, data } = SocketPacket::decode(&wire).unwrap() else {
+        panic!("expected an event");
+    };
+    assert_eq!((namespace.as_str(), id), (CHAT_NAMESPACE, Some(0)));
+    let AtlasClientEvent::ChatSend(send) = serde_json::from_value(Value::Array(data)).unwrap() else {
+        panic!("expected chat:send");
+    };
+    assert_eq!(send.session_id.as_str(), SESSION_ID);
+    assert_eq!(send.parent_id.as_ref().map(|id| id.as_str()), Some("ROOT"));
+
+    let packet = SocketPacket::event(CHAT_NAMESPACE, Some(0), &AtlasClientEvent::ChatSend(send)).unwrap();
+    let SocketPacket::Event { data, .. } = SocketPacket::decode(&packet.encode()).unwrap() else {
+        panic!("expected an event");
+    };
+    assert_eq!(data[0], json!("chat:send"));
+    assert_eq!(data[1]["sessionId"], json!(SESSION_ID));
+    assert_eq!(data[1]["content"], json!("hello"));
+    assert_eq!(data[1]["model"], json!("gemini-2.5-flash"));
+    assert_eq!(data[1]["parentId"], json!("ROOT"));
+
+    let wire = format!(r#"2/api/collaboration,["note:join",{{"noteId":"{NOTE_ID}","stateVector":"AA=="}}]"#);
+    let SocketPacket::Event { data, .. } = SocketPacket::decode(&wire).unwrap() else {
+        panic!("expected an event");
+    };
+    let event: AtlasClientEvent = serde_json::from_value(Value::Array(data)).unwrap();
+    assert!(matches!(event, AtlasClientEvent::NoteJoin(ref join) if join.note_id.as_str() == NOTE_ID));
+}
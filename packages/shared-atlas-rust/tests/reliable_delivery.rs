@@ -0,0 +1,93 @@
+#![cfg(feature = "socket")]
+
+mod common;
+
+use futures_util::StreamExt;
+use serde_json::json;
+
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::interfaces::chat_send_payload::ChatSendPayload;
+use shared_atlas_rust::interfaces::chat_stream_event::ChatStreamEvent;
+use shared_atlas_rust::socket::chat_socket::ChatSocket;
+use shared_atlas_rust::socket::reliable_delivery::ReliableDelivery;
+use shared_atlas_rust::socket::socket_options::SocketOptions;
+
+use common::{fake_gateway, session_id, FakeClient, FakeGateway, TIMEOUT};
+
+async fn connect(options: SocketOptions, gateway: &mut FakeGateway) -> (ChatSocket, FakeClient) {
+    let (socket, (server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    (socket.unwrap(), server)
+}
+
+fn message() -> ChatSendPayload {
+    ChatSendPayload {
+        session_id: session_id(1),
+        content: "hello".to_owned(),
+        role: None,
+        model: None,
+        parent_id: None,
+    }
+}
+
+async fn expect_ack(server: &mut FakeClient) -> String {
+    match server.recv_event().await {
+        AtlasClientEvent::MessageAck(ack) => ack.message_id,
+        other => panic!("expected message:ack, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn acks_and_deduplicates_resent_messages() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let reliable = ReliableDelivery::new(16);
+    let metrics = reliable.metrics();
+    let (socket, mut server) = connect(options.with_middleware(reliable), &mut gateway).await;
+
+    let stream = socket.send(message()).await.unwrap();
+    assert!(matches!(server.recv_event().await, AtlasClientEvent::ChatSend(_)));
+
+    let chunk = json!({ "messageId": "m1", "type": "answer_chunk", "content": "Hi" });
+    server.emit_raw(None, "chat:stream", chunk.clone()).await;
+    server.emit_raw(None, "chat:stream", chunk).await;
+    server.emit_raw(None, "chat:stream", json!({ "type": "answer_chunk", "content": "!" })).await;
+    server.emit_raw(None, "chat:stream", json!({ "messageId": "m2", "type": "done" })).await;
+
+    let events: Vec<_> = tokio::time::timeout(TIMEOUT, stream.collect()).await.unwrap();
+    assert_eq!(
+        events,
+        vec![
+            ChatStreamEvent::AnswerChunk { content: "Hi".to_owned() },
+            ChatStreamEvent::AnswerChunk { content: "!".to_owned() },
+            ChatStreamEvent::Done { title: None },
+        ]
+    );
+
+    assert_eq!(expect_ack(&mut server).await, "m1");
+    assert_eq!(expect_ack(&mut server).await, "m1");
+    assert_eq!(expect_ack(&mut server).await, "m2");
+
+    assert_eq!(metrics.received(), 3);
+    assert_eq!(metrics.duplicates(), 1);
+    assert_eq!(metrics.delivered(), 2);
+    assert_eq!(metrics.acks_sent(), 3);
+    assert_eq!(metrics.ack_failures(), 0);
+}
+
+#[tokio::test]
+async fn seen_set_is_bounded() {
+    let (options, mut gateway) = fake_gateway("jwt");
+    let reliable = ReliableDelivery::new(1);
+    let metrics = reliable.metrics();
+    let (socket, mut server) = connect(options.with_middleware(reliable), &mut gateway).await;
+
+    let stream = socket.send(message()).await.unwrap();
+    server.recv_event().await;
+    for id in ["m1", "m2", "m1"] {
+        server.emit_raw(None, "chat:stream", json!({ "messageId": id, "type": "thought", "content": id })).await;
+    }
+    server.emit_raw(None, "chat:stream", json!({ "type": "done" })).await;
+
+    let events: Vec<_> = tokio::time::timeout(TIMEOUT, stream.collect()).await.unwrap();
+    assert_eq!(events.len(), 4, "m1 was evicted by m2 and delivered again");
+    assert_eq!(metrics.duplicates(), 0);
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "scheduler")]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use serde_json::json;
+use tokio::time::Instant;
+
+use shared_atlas_rust::error::api_error::ApiError;
+use shared_atlas_rust::scheduler::ceiling_reached::CeilingReached;
+use shared_atlas_rust::scheduler::rate_limit::RateLimit;
+use shared_atlas_rust::scheduler::request_scheduler::RequestScheduler;
+use shared_atlas_rust::scheduler::safety_ceiling::SafetyCeiling;
+use shared_atlas_rust::scheduler::scheduler_options::SchedulerOptions;
+
+fn ok() -> Result<(), ApiError> {
+    Ok(())
+}
+
+fn too_many_requests(retry_after: &str) -> Result<(), ApiError> {
+    let body = json!({ "statusCode": 429, "message": "Too Many Requests" }).to_string();
+    Err(ApiError::from_response(429, Some(retry_after), body.as_bytes()))
+}
+
+#[tokio::test(start_paused = true)]
+async fn spaces_requests_per_route() {
+    let options = SchedulerOptions::new(RateLimit::new(100, Duration::from_secs(1)))
+        .with_route("/auth/login", RateLimit::new(2, Duration::from_secs(10)));
+    let scheduler = RequestScheduler::new(options);
+    let start = Instant::now();
+
+    for _ in 0..3 {
+        scheduler.schedule("/auth/login", || async { ok() }).await.unwrap().unwrap();
+    }
+    // Burst of 2, then one token every 5 s.
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+
+    let start = Instant::now();
+    scheduler.schedule("/notes?limit=5", || async { ok() }).await.unwrap().unwrap();
+    assert_eq!(start.elapsed(), Duration::ZERO, "other routes have their own bucket");
+}
+
+#[tokio::test(start_paused = true)]
+async fn waits_for_retry_after_and_resends() {
+    let scheduler = RequestScheduler::new(SchedulerOptions::default());
+    let attempts = AtomicU32::new(0);
+    let start = Instant::now();
+
+    let outcome = scheduler
+        .schedule("/notes/n1", || async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => too_many_requests("30"),
+                _ => ok(),
+            }
+        })
+        .await
+        .unwrap();
+    assert!(outcome.is_ok());
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() >= Duration::from_secs(30));
+
+    let exhausted = scheduler
+        .schedule("/notes/n1", || async { too_many_requests("1") })
+        .await
+        .unwrap();
+    assert!(matches!(exhausted, Err(ApiError::RateLimited { .. })), "last outcome returned after max_retries");
+}
+
+#[tokio::test(start_paused = true)]
+async fn caps_huge_retry_after_delays() {
+    let scheduler = RequestScheduler::new(SchedulerOptions::default());
+    let attempts = AtomicU32::new(0);
+    let start = Instant::now();
+
+    let outcome = scheduler
+        .schedule("/notes/n1", || async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => too_many_requests("18446744073709551615"),
+                _ => ok(),
+            }
+        })
+        .await
+        .unwrap();
+    assert!(outcome.is_ok());
+    let hour = Duration::from_secs(60 * 60);
+    assert!(start.elapsed() >= hour && start.elapsed() < hour + Duration::from_secs(60), "{:?}", start.elapsed());
+}
+
+#[tokio::test(start_paused = true)]
+async fn refuses_requests_over_the_ceiling() {
+    let options = SchedulerOptions::new(RateLimit::new(100, Duration::from_secs(1)))
+        .with_ceiling(SafetyCeiling::new(3, Duration::from_secs(60)));
+    let scheduler = RequestScheduler::new(options);
+    let sent = AtomicU32::new(0);
+
+    for _ in 0..3 {
+        let send = || async {
+            sent.fetch_add(1, Ordering::SeqCst);
+            ok()
+        };
+        scheduler.schedule("/notes", send).await.unwrap().unwrap();
+    }
+    let refused = scheduler.schedule("/blocks", || async { ok() }).await;
+    assert_eq!(refused.unwrap_err(), CeilingReached { retry_in: Duration::from_secs(60) });
+    assert_eq!(sent.load(Ordering::SeqCst), 3);
+    assert_eq!(scheduler.queued(), 0);
+}
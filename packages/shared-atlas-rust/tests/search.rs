@@ -0,0 +1,82 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use axum::extract::Query;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde_json::{json, Value};
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::dto::search_query::SearchQuery;
+use shared_atlas_rust::interfaces::block_search_hit::BlockSearchHit;
+use shared_atlas_rust::interfaces::block_type::BlockType;
+
+use mock_http::{note, serve, uuid};
+
+fn hit(highlight: &str) -> BlockSearchHit {
+    serde_json::from_value(json!({
+        "id": uuid(11),
+        "noteId": uuid(1),
+        "type": "TEXT",
+        "content": "unused",
+        "note": { "id": uuid(1), "title": "Garden" },
+        "highlight": highlight
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn decodes_typed_block_hits() {
+    let router = Router::new().route(
+        "/search",
+        get(|Query(query): Query<Value>| async move {
+            assert_eq!(query, json!({ "q": "tomato" }));
+            Json(json!({
+                "notes": [note(&uuid(2), None)],
+                "blocks": [{
+                    "id": uuid(11),
+                    "noteId": uuid(1),
+                    "type": "CODE",
+                    "content": "let tomato = 1;",
+                    "updatedAt": "2026-01-01T00:00:00.000Z",
+                    "note": { "id": uuid(1), "title": "Garden" },
+                    "highlight": "let tomato = 1;"
+                }],
+                "total": 2
+            }))
+        }),
+    );
+    let client = AtlasClient::new(serve(router).await, AuthMode::Cookie).unwrap();
+    let found = client.search(&SearchQuery::new("tomato")).await.unwrap();
+    assert_eq!(found.total, 2);
+    let block = &found.blocks[0];
+    assert_eq!(block.r#type, BlockType::Code);
+    assert_eq!(block.note.id, block.note_id);
+    assert_eq!(block.note.title, "Garden");
+    assert_eq!(block.highlight_ranges("tomato"), vec![4..10]);
+}
+
+#[test]
+fn highlight_ranges_match_case_insensitively() {
+    let highlight = "...Tomatoes, TOMATO soup and Ünïcode tomato";
+    let ranges = hit(highlight).highlight_ranges(" tomato ");
+    let matched: Vec<&str> = ranges.iter().map(|range| &highlight[range.clone()]).collect();
+    assert_eq!(matched, vec!["Tomato", "TOMATO", "tomato"]);
+    assert_eq!(hit("Ünïcode").highlight_ranges("ünï"), vec![0..5]);
+    assert!(hit(highlight).highlight_ranges("").is_empty());
+    assert!(hit(highlight).highlight_ranges("potato").is_empty());
+}
+
+#[test]
+fn highlight_ranges_match_each_query_term() {
+    let highlight = "async code in rust";
+    let ranges = hit(highlight).highlight_ranges("rust async");
+    let matched: Vec<&str> = ranges.iter().map(|range| &highlight[range.clone()]).collect();
+    assert_eq!(matched, vec!["async", "rust"]);
+    assert_eq!(hit("tomatoes").highlight_ranges("tomato toes"), vec![0..8]);
+    assert_eq!(hit("tomato soup").highlight_ranges("tomato  soup"), vec![0..6, 7..11]);
+    assert_eq!(hit("tomatosoup").highlight_ranges("soup tomato"), vec![0..10]);
+    assert!(hit(highlight).highlight_ranges(" \t ").is_empty());
+}
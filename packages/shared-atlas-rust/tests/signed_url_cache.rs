@@ -0,0 +1,96 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use futures_util::future::join_all;
+use serde_json::json;
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::json_file_store::JsonFileStore;
+use shared_atlas_rust::client::signed_url_cache::SignedUrlCache;
+
+use mock_http::serve;
+
+/// Signs URLs valid for `expirationSeconds` (default 3600), numbering them.
+async fn setup() -> (AtlasClient, Arc<AtomicU32>) {
+    let calls = Arc::new(AtomicU32::new(0));
+    let router = Router::new()
+        .route(
+            "/storage/url",
+            get(|State(calls): State<Arc<AtomicU32>>, Query(query): Query<HashMap<String, String>>| async move {
+                let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                tokio::time::sleep(Duration::from_millis(30)).await;
+                let expires_in: f64 = query.get("expirationSeconds").map_or(3600.0, |s| s.parse().unwrap());
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as f64;
+                Json(json!({
+                    "url": format!("https://storage.example.com/{}?sig={n}", query["path"]),
+                    "path": query["path"],
+                    "expiresAt": now + expires_in * 1000.0,
+                    "expiresIn": expires_in
+                }))
+            }),
+        )
+        .with_state(calls.clone());
+    (AtlasClient::new(serve(router).await, AuthMode::Cookie).unwrap(), calls)
+}
+
+#[tokio::test]
+async fn coalesces_concurrent_lookups() {
+    let (client, calls) = setup().await;
+    let cache = SignedUrlCache::new(client);
+
+    let urls = join_all((0..5).map(|_| cache.get("notes/images/a.png"))).await;
+    assert!(urls.iter().all(|url| url.as_ref().unwrap().url.ends_with("sig=1")));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    assert!(cache.get("notes/images/a.png").await.unwrap().url.ends_with("sig=1"));
+    cache.get("notes/images/b.png").await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn renews_read_urls_ahead_of_expiry() {
+    let (client, calls) = setup().await;
+    // 1s URLs are renewed after half their lifetime.
+    let cache = SignedUrlCache::new(client).with_expiration_seconds(1);
+
+    cache.get("a.png").await.unwrap();
+    cache.get("a.png").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(700)).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2, "renewed in the background");
+    assert!(cache.cached("a.png").unwrap().url.ends_with("sig=2"));
+
+    // Not read since the renewal: left to expire.
+    tokio::time::sleep(Duration::from_millis(700)).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(cache.cached("a.png").is_none());
+    assert!(cache.get("a.png").await.unwrap().url.ends_with("sig=3"));
+}
+
+#[tokio::test]
+async fn persists_across_restarts() {
+    let (client, calls) = setup().await;
+    let file = std::env::temp_dir().join(format!("signed-urls-{}.json", std::process::id()));
+
+    let cache = SignedUrlCache::new(client.clone()).with_store(JsonFileStore::new(&file)).unwrap();
+    cache.get("a.png").await.unwrap();
+    drop(cache);
+
+    let restarted = SignedUrlCache::new(client.clone()).with_store(JsonFileStore::new(&file)).unwrap();
+    assert!(restarted.get("a.png").await.unwrap().url.ends_with("sig=1"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    restarted.clear();
+    let empty = SignedUrlCache::new(client).with_store(JsonFileStore::new(&file)).unwrap();
+    assert!(empty.cached("a.png").is_none());
+    std::fs::remove_file(file).unwrap();
+}
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use shared_atlas_rust::interfaces::isigned_url_response::ISignedUrlResponse;
+use shared_atlas_rust::interfaces::note_with_blocks_response::NoteWithBlocksResponse;
+use shared_atlas_rust::time::timestamp::Timestamp;
+use shared_atlas_rust::time::timestamp_format::TimestampFormat;
+
+#[test]
+fn keeps_the_wire_format_it_was_read_in() {
+    let iso: Timestamp = serde_json::from_value(json!("2026-01-01T00:00:00.000Z")).unwrap();
+    let millis: Timestamp = serde_json::from_value(json!(1_767_225_600_000u64)).unwrap();
+    assert_eq!(iso, millis);
+    assert_eq!(iso.format(), TimestampFormat::Iso8601);
+    assert_eq!(millis.format(), TimestampFormat::EpochMillis);
+    assert_eq!(serde_json::to_value(iso).unwrap(), json!("2026-01-01T00:00:00.000Z"));
+    assert_eq!(serde_json::to_value(millis).unwrap(), json!(1_767_225_600_000u64));
+
+    let offset: Timestamp = serde_json::from_value(json!("2026-01-01T08:00:00+08:00")).unwrap();
+    assert_eq!(offset, iso);
+    assert!(serde_json::from_value::<Timestamp>(json!("yesterday")).is_err());
+}
+
+#[test]
+fn orders_and_measures_instants() {
+    let start = Timestamp::from_millis(1_767_225_600_000).unwrap();
+    let end = start.checked_add(Duration::from_secs(90)).unwrap();
+    assert!(start < end);
+    assert_eq!(end.duration_since(&start), Some(Duration::from_secs(90)));
+    assert_eq!(start.duration_since(&end), None);
+    assert!(start.is_past());
+    assert_eq!(start.remaining(), Duration::ZERO);
+
+    let expiry = Timestamp::now().checked_add(Duration::from_secs(3600)).unwrap();
+    assert!(!expiry.is_past());
+    assert!(expiry.remaining() > Duration::from_secs(3590));
+}
+
+#[test]
+fn api_types_use_timestamps() {
+    let signed: ISignedUrlResponse = serde_json::from_value(json!({
+        "url": "https://storage.example.com/a.png",
+        "path": "notes/a.png",
+        "expiresAt": 1_767_225_600_000u64,
+        "expiresIn": 3600
+    }))
+    .unwrap();
+    assert_eq!(signed.expires_at.as_millis(), 1_767_225_600_000);
+
+    let note = json!({
+        "blocks": [],
+        "id": "00000000-0000-4000-8000-000000000001",
+        "userId": "00000000-0000-4000-8000-000000000002",
+        "title": "Plans",
+        "coverImage": null,
+        "icon": null,
+        "parentId": null,
+        "isPublic": false,
+        "isDeleted": false,
+        "createdAt": "2026-01-01T00:00:00.000Z",
+        "updatedAt": "2026-01-02T00:00:00.000Z",
+        "lastEditedBy": "00000000-0000-4000-8000-000000000002"
+    });
+    let response: NoteWithBlocksResponse = serde_json::from_value(note.clone()).unwrap();
+    assert_eq!(response.note.title, "Plans");
+    assert_eq!(response.note.updated_at.duration_since(&response.note.created_at), Some(Duration::from_secs(86_400)));
+    let mut written = serde_json::to_value(&response).unwrap();
+    written.as_object_mut().unwrap().retain(|_, value| !value.is_null());
+    let mut expected = note;
+    expected.as_object_mut().unwrap().retain(|_, value| !value.is_null());
+    assert_eq!(written, expected);
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "socket")]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::json;
+use tokio::sync::broadcast;
+
+use shared_atlas_rust::dto::refresh_token_dto::RefreshTokenDto;
+use shared_atlas_rust::events::atlas_client_event::AtlasClientEvent;
+use shared_atlas_rust::interfaces::token_response::TokenResponse;
+use shared_atlas_rust::socket::chat_socket::ChatSocket;
+use shared_atlas_rust::socket::reconnect_policy::ReconnectPolicy;
+use shared_atlas_rust::socket::token_event::TokenEvent;
+use shared_atlas_rust::socket::token_lifecycle::TokenLifecycle;
+use shared_atlas_rust::socket::token_provider::BoxError;
+
+use common::{fake_gateway, TIMEOUT};
+
+async fn next_event(events: &mut broadcast::Receiver<TokenEvent>) -> TokenEvent {
+    tokio::time::timeout(TIMEOUT, events.recv()).await.expect("no token event").unwrap()
+}
+
+#[tokio::test]
+async fn refreshes_token_when_expiring() {
+    let (options, mut gateway) = fake_gateway("old-access");
+    let options = options.with_refresh_token("old-refresh");
+    let credentials = options.credentials.clone();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+    let provider = move |request: RefreshTokenDto| {
+        seen.lock().unwrap().push(request.refresh_token);
+        async {
+            Ok::<_, BoxError>(TokenResponse {
+                access_token: "new-access".to_owned(),
+                refresh_token: "new-refresh".to_owned(),
+            })
+        }
+    };
+    let lifecycle = TokenLifecycle::new(provider, credentials.clone());
+    let mut events = lifecycle.subscribe();
+    let (socket, (mut server, auth)) =
+        tokio::join!(ChatSocket::connect(options.with_middleware(lifecycle)), gateway.accept_ready());
+    let _socket = socket.unwrap();
+    assert_eq!(auth, json!({ "token": "old-access" }));
+
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 300, "action": "refresh" })).await;
+    assert_eq!(next_event(&mut events).await, TokenEvent::Expiring { expires_in: 300.0 });
+    match server.recv_event().await {
+        AtlasClientEvent::TokenRefreshed(payload) => assert_eq!(payload.new_token, "new-access"),
+        other => panic!("expected auth:token-refreshed, got {other:?}"),
+    }
+    assert_eq!(next_event(&mut events).await, TokenEvent::Refreshed);
+    assert_eq!(*requests.lock().unwrap(), vec![Some("old-refresh".to_owned())]);
+    assert_eq!(credentials.access_token(), "new-access");
+    assert_eq!(credentials.refresh_token().as_deref(), Some("new-refresh"));
+
+    server.emit_raw(None, "auth:token-renewed", json!({ "success": true })).await;
+    assert_eq!(next_event(&mut events).await, TokenEvent::Renewed);
+}
+
+#[tokio::test]
+async fn reports_refresh_failures() {
+    let (options, mut gateway) = fake_gateway("old-access");
+    let provider = |_: RefreshTokenDto| async { Err::<TokenResponse, BoxError>("refresh token revoked".into()) };
+    let lifecycle = TokenLifecycle::new(provider, options.credentials.clone());
+    let mut events = lifecycle.subscribe();
+    let (socket, (mut server, _)) =
+        tokio::join!(ChatSocket::connect(options.with_middleware(lifecycle)), gateway.accept_ready());
+    let _socket = socket.unwrap();
+
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 120 })).await;
+    assert!(matches!(next_event(&mut events).await, TokenEvent::Expiring { .. }));
+    assert_eq!(next_event(&mut events).await, TokenEvent::RefreshFailed("refresh token revoked".to_owned()));
+
+    server.emit_raw(None, "auth:error", json!({ "message": "Invalid refresh token" })).await;
+    assert_eq!(next_event(&mut events).await, TokenEvent::Rejected("Invalid refresh token".to_owned()));
+}
+
+/// A provider handing out `access-1`, `access-2`, ... and recording how often it ran.
+fn counting_provider(
+    calls: Arc<Mutex<u32>>,
+) -> impl Fn(RefreshTokenDto) -> std::future::Ready<Result<TokenResponse, BoxError>> {
+    move |_| {
+        let mut calls = calls.lock().unwrap();
+        *calls += 1;
+        std::future::ready(Ok(TokenResponse {
+            access_token: format!("access-{calls}"),
+            refresh_token: format!("refresh-{calls}"),
+        }))
+    }
+}
+
+async fn expect_refreshed(server: &mut common::FakeClient, token: &str) {
+    match server.recv_event().await {
+        AtlasClientEvent::TokenRefreshed(payload) => assert_eq!(payload.new_token, token),
+        other => panic!("expected auth:token-refreshed, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn refreshes_again_when_the_server_never_answers() {
+    let (options, mut gateway) = fake_gateway("old-access");
+    let calls = Arc::new(Mutex::new(0));
+    let lifecycle = TokenLifecycle::new(counting_provider(calls.clone()), options.credentials.clone())
+        .with_renewal_timeout(Duration::from_millis(50));
+    let mut events = lifecycle.subscribe();
+    let (socket, (mut server, _)) =
+        tokio::join!(ChatSocket::connect(options.with_middleware(lifecycle)), gateway.accept_ready());
+    let _socket = socket.unwrap();
+
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 300 })).await;
+    expect_refreshed(&mut server, "access-1").await;
+    assert_eq!(next_event(&mut events).await, TokenEvent::Expiring { expires_in: 300.0 });
+    assert_eq!(next_event(&mut events).await, TokenEvent::Refreshed);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 200 })).await;
+    expect_refreshed(&mut server, "access-2").await;
+    assert_eq!(*calls.lock().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn refreshes_again_after_reconnecting() {
+    let (options, mut gateway) = fake_gateway("old-access");
+    let calls = Arc::new(Mutex::new(0));
+    let lifecycle = TokenLifecycle::new(counting_provider(calls.clone()), options.credentials.clone());
+    let options = options.with_middleware(lifecycle).with_reconnect(ReconnectPolicy {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+        max_retries: 3,
+    });
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let _socket = socket.unwrap();
+
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 300 })).await;
+    expect_refreshed(&mut server, "access-1").await;
+    server.disconnect().await;
+
+    let (mut server, auth) = gateway.accept_ready().await;
+    assert_eq!(auth, json!({ "token": "access-1" }));
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 300 })).await;
+    expect_refreshed(&mut server, "access-2").await;
+    assert_eq!(*calls.lock().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn lifecycle_passed_to_options_also_refreshes_before_reconnecting() {
+    let (options, mut gateway) = fake_gateway("expired");
+    let calls = Arc::new(Mutex::new(0));
+    let lifecycle = TokenLifecycle::new(counting_provider(calls.clone()), options.credentials.clone());
+    let mut events = lifecycle.subscribe();
+    let options = options.with_token_lifecycle(lifecycle).with_reconnect(ReconnectPolicy {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+        max_retries: 3,
+    });
+    let (socket, (mut server, _)) = tokio::join!(ChatSocket::connect(options), gateway.accept_ready());
+    let _socket = socket.unwrap();
+
+    server.emit_raw(None, "error", json!({ "code": 4012, "message": "Token expired", "category": "AUTH" })).await;
+    server.disconnect().await;
+    let (mut server, auth) = gateway.accept_ready().await;
+    assert_eq!(auth, json!({ "token": "access-1" }));
+
+    server.emit_raw(None, "auth:token-expiring", json!({ "expiresIn": 300 })).await;
+    assert_eq!(next_event(&mut events).await, TokenEvent::Expiring { expires_in: 300.0 });
+    expect_refreshed(&mut server, "access-2").await;
+    assert_eq!(next_event(&mut events).await, TokenEvent::Refreshed);
+}
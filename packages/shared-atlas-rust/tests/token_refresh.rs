@@ -0,0 +1,119 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use futures_util::future::join_all;
+use serde_json::{json, Value};
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::client_error::ClientError;
+use shared_atlas_rust::error::api_error::ApiError;
+use shared_atlas_rust::interfaces::token_response::TokenResponse;
+
+use mock_http::{nest_error, serve, user};
+
+/// Token store of the stand-in server; tests expire tokens by editing it.
+#[derive(Default)]
+struct Tokens {
+    access: String,
+    refresh: String,
+    issued: u32,
+    refresh_calls: u32,
+}
+
+type Shared = Arc<Mutex<Tokens>>;
+
+fn unauthorized() -> Response {
+    (StatusCode::UNAUTHORIZED, Json(nest_error(401, "Unauthorized"))).into_response()
+}
+
+fn api(tokens: Shared) -> Router {
+    Router::new()
+        .route(
+            "/auth/profile",
+            get(|State(tokens): State<Shared>, headers: HeaderMap| async move {
+                let expected = format!("Bearer {}", tokens.lock().unwrap().access);
+                match headers.get(AUTHORIZATION) {
+                    Some(value) if value == expected.as_str() => Json(user()).into_response(),
+                    _ => unauthorized(),
+                }
+            }),
+        )
+        .route(
+            "/auth/refresh",
+            post(|State(tokens): State<Shared>, Json(body): Json<Value>| async move {
+                tokens.lock().unwrap().refresh_calls += 1;
+                // Slow enough for every concurrent request to hit its 401 first.
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let mut tokens = tokens.lock().unwrap();
+                if body["refreshToken"] != tokens.refresh.as_str() {
+                    return unauthorized();
+                }
+                tokens.issued += 1;
+                tokens.access = format!("a{}", tokens.issued);
+                tokens.refresh = format!("r{}", tokens.issued);
+                Json(json!({ "tokens": { "access_token": tokens.access, "refresh_token": tokens.refresh } }))
+                    .into_response()
+            }),
+        )
+        .with_state(tokens)
+}
+
+async fn setup() -> (AtlasClient, Shared) {
+    let tokens = Shared::new(Mutex::new(Tokens { access: "a0".into(), refresh: "r0".into(), ..Default::default() }));
+    let base_url = serve(api(tokens.clone())).await;
+    let client = AtlasClient::new(base_url, AuthMode::Bearer)
+        .unwrap()
+        .with_tokens(TokenResponse { access_token: "a0".into(), refresh_token: "r0".into() });
+    (client, tokens)
+}
+
+#[tokio::test]
+async fn concurrent_401s_share_one_refresh() {
+    let (client, tokens) = setup().await;
+    assert!(client.profile().await.is_ok());
+
+    tokens.lock().unwrap().access = "expired".into();
+    let results = join_all((0..5).map(|_| client.profile())).await;
+    assert!(results.iter().all(Result::is_ok), "{results:?}");
+    assert_eq!(tokens.lock().unwrap().refresh_calls, 1);
+    assert_eq!(client.tokens().unwrap().access_token, "a1");
+
+    // A later expiry refreshes again, with the rotated refresh token.
+    tokens.lock().unwrap().access = "expired".into();
+    assert!(client.profile().await.is_ok());
+    assert_eq!(tokens.lock().unwrap().refresh_calls, 2);
+}
+
+#[tokio::test]
+async fn rejected_refresh_token_expires_the_session() {
+    let (client, tokens) = setup().await;
+    {
+        let mut tokens = tokens.lock().unwrap();
+        tokens.access = "expired".into();
+        tokens.refresh = "revoked".into();
+    }
+    let results = join_all((0..3).map(|_| client.profile())).await;
+    assert!(results.iter().all(|result| matches!(result, Err(ClientError::SessionExpired))), "{results:?}");
+    assert_eq!(tokens.lock().unwrap().refresh_calls, 1);
+    assert!(client.tokens().is_none());
+}
+
+#[tokio::test]
+async fn auto_refresh_can_be_disabled() {
+    let (client, tokens) = setup().await;
+    let client = client.with_auto_refresh(false);
+    tokens.lock().unwrap().access = "expired".into();
+    assert!(matches!(client.profile().await, Err(ClientError::Api(ApiError::Unauthorized(_)))));
+    assert_eq!(tokens.lock().unwrap().refresh_calls, 0);
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "client")]
+
+mod mock_http;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::Bytes;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::json;
+
+use shared_atlas_rust::client::atlas_client::AtlasClient;
+use shared_atlas_rust::client::auth_mode::AuthMode;
+use shared_atlas_rust::client::cancel_token::CancelToken;
+use shared_atlas_rust::client::mime_sniff::detect_mime_type;
+use shared_atlas_rust::client::upload_error::UploadError;
+use shared_atlas_rust::client::upload_file::UploadFile;
+use shared_atlas_rust::client::upload_options::UploadOptions;
+use shared_atlas_rust::dto::storage_module::StorageModule;
+use shared_atlas_rust::dto::upload_file_query_dto::UploadFileQueryDto;
+use shared_atlas_rust::ids::note_id::NoteId;
+
+use mock_http::{nest_error, serve, uuid};
+
+const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+
+fn uploaded(body: &[u8]) -> Json<serde_json::Value> {
+    let text = String::from_utf8_lossy(body);
+    let content_type = text.lines().find_map(|line| line.strip_prefix("Content-Type: ")).unwrap_or_default();
+    Json(json!({
+        "success": true,
+        "url": "https://storage.example.com/signed",
+        "path": "notes/images/a.png",
+        "expiresAt": 1_767_225_600_000u64,
+        "metadata": { "contentType": content_type, "size": body.len() }
+    }))
+}
+
+fn api() -> Router {
+    Router::new()
+        .route("/upload/image/{note}", post(|body: Bytes| async move { uploaded(&body) }))
+        .route(
+            "/upload/video/{note}",
+            post(|| async {
+                (StatusCode::BAD_REQUEST, Json(nest_error(400, "File too large. Maximum size: 100MB"))).into_response()
+            }),
+        )
+        .route(
+            "/storage/upload",
+            post(|| async {
+                let message = "Invalid file type. Accepted types: image/png, image/gif";
+                (StatusCode::BAD_REQUEST, Json(nest_error(400, message))).into_response()
+            }),
+        )
+}
+
+fn note_id() -> NoteId {
+    NoteId::parse(uuid(1)).unwrap()
+}
+
+async fn client() -> AtlasClient {
+    AtlasClient::new(serve(api()).await, AuthMode::Cookie).unwrap()
+}
+
+#[test]
+fn detects_mime_types() {
+    assert_eq!(detect_mime_type("photo.bin", PNG), Some("image/png"));
+    assert_eq!(detect_mime_type("clip", b"\0\0\0\x18ftypqt  "), Some("video/quicktime"));
+    assert_eq!(
+        detect_mime_type("report.docx", b"PK\x03\x04rest"),
+        Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document")
+    );
+    assert_eq!(detect_mime_type("archive", b"PK\x03\x04rest"), Some("application/zip"));
+    assert_eq!(detect_mime_type("icon", b"<?xml version=\"1.0\"?><svg/>"), Some("image/svg+xml"));
+    assert_eq!(detect_mime_type("notes.txt", b"hello"), Some("text/plain"));
+    assert_eq!(detect_mime_type("unknown", b"hello"), None);
+}
+
+#[tokio::test]
+async fn streams_file_with_progress() {
+    let client = client().await;
+    let mut content = PNG.to_vec();
+    content.resize(200 * 1024, 7);
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let seen = progress.clone();
+    let options = UploadOptions::new().with_progress(move |update| seen.lock().unwrap().push(update));
+    // Unknown length: sent as a chunked stream.
+    let file = UploadFile::new("a.png", std::io::Cursor::new(content.clone()));
+    let response = client.upload_image(&note_id(), file, options).await.unwrap();
+    assert_eq!(response.path, "notes/images/a.png");
+    assert_eq!(response.metadata["contentType"], "image/png");
+
+    let progress = progress.lock().unwrap();
+    assert!(progress.len() > 1);
+    assert_eq!(progress.last().unwrap().sent, content.len() as u64);
+    assert!(progress.windows(2).all(|pair| pair[0].sent < pair[1].sent));
+}
+
+#[tokio::test]
+async fn rejects_and_decodes_type_and_size_errors() {
+    let client = client().await;
+
+    let text = UploadFile::from_bytes("notes.txt", "hello");
+    match client.upload_image(&note_id(), text, UploadOptions::new()).await {
+        Err(UploadError::UnsupportedType { mime_type, .. }) => assert_eq!(mime_type, "text/plain"),
+        other => panic!("expected UnsupportedType before sending, got {other:?}"),
+    }
+
+    let video = UploadFile::from_bytes("clip.mp4", b"\0\0\0\x18ftypisom".to_vec());
+    let result = client.upload_video(&note_id(), video, UploadOptions::new()).await;
+    assert!(matches!(result, Err(UploadError::TooLarge { max_bytes: Some(104_857_600) })), "{result:?}");
+
+    let query = UploadFileQueryDto { module: Some(StorageModule::Notes), folder: None };
+    let file = UploadFile::from_bytes("a.pdf", b"%PDF-1.7".to_vec());
+    match client.upload_to_storage(&query, file, UploadOptions::new()).await {
+        Err(UploadError::UnsupportedType { mime_type, accepted }) => {
+            assert_eq!(mime_type, "application/pdf");
+            assert_eq!(accepted, vec!["image/png", "image/gif"]);
+        }
+        other => panic!("expected UnsupportedType, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn cancels_a_stalled_upload() {
+    let client = client().await;
+    // The writer half stays open without writing, so the body never ends.
+    let (reader, _writer) = tokio::io::duplex(64);
+    let cancel = CancelToken::new();
+    let options = UploadOptions::new().with_cancel(cancel.clone());
+    let upload = client.upload_to_storage(
+        &UploadFileQueryDto { module: None, folder: None },
+        UploadFile::new("stalled.bin", reader),
+        options,
+    );
+    let cancel_later = async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        cancel.cancel();
+    };
+    let (result, ()) = tokio::join!(upload, cancel_later);
+    assert!(matches!(result, Err(UploadError::Cancelled)), "{result:?}");
+}
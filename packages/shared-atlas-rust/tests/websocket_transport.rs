@@ -0,0 +1,33 @@
+#![cfg(feature = "socket")]
+
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpListener;
+
+use shared_atlas_rust::socket::socket_error::SocketError;
+use shared_atlas_rust::socket::websocket_transport::WebSocketTransport;
+
+/// TLS record type of a handshake message (the ClientHello).
+const TLS_HANDSHAKE: u8 = 0x16;
+
+/// Accepts one connection and returns the first byte the client sends.
+async fn first_byte(listener: TcpListener) -> u8 {
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut byte = [0u8; 1];
+    stream.read_exact(&mut byte).await.unwrap();
+    byte[0]
+}
+
+#[tokio::test]
+async fn wss_urls_start_a_tls_handshake() {
+    for scheme in ["wss", "https"] {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("{scheme}://{}/socket.io/?EIO=4&transport=websocket", listener.local_addr().unwrap());
+        let server = tokio::spawn(first_byte(listener));
+
+        let Err(SocketError::Transport(message)) = WebSocketTransport::connect(url).await else {
+            panic!("a plain TCP peer must not complete a TLS handshake");
+        };
+        assert!(!message.contains("not compiled in"), "{scheme}: {message}");
+        assert_eq!(server.await.unwrap(), TLS_HANDSHAKE, "{scheme}: expected a ClientHello");
+    }
+}
@@ -0,0 +1,107 @@
+use serde_json::json;
+
+use shared_atlas_rust::error::atlas_ws_error::AtlasWsError;
+use shared_atlas_rust::interfaces::error_category::ErrorCategory;
+use shared_atlas_rust::interfaces::web_socket_error_code::{ErrorDisposition, WebSocketErrorCode};
+use shared_atlas_rust::interfaces::ws_error_response::WsErrorResponse;
+
+use ErrorDisposition::{Fatal, Reauthenticate, Retry};
+
+/// Every code the gateways send, with its category and disposition.
+const TABLE: [(WebSocketErrorCode, u16, ErrorCategory, ErrorDisposition); 12] = [
+    (WebSocketErrorCode::AuthTokenMissing, 4010, ErrorCategory::Auth, Reauthenticate),
+    (WebSocketErrorCode::AuthTokenInvalid, 4011, ErrorCategory::Auth, Reauthenticate),
+    (WebSocketErrorCode::AuthTokenExpired, 4012, ErrorCategory::Auth, Reauthenticate),
+    (WebSocketErrorCode::PermissionDenied, 4030, ErrorCategory::Permission, Fatal),
+    (WebSocketErrorCode::RateLimitExceeded, 4031, ErrorCategory::Permission, Retry),
+    (WebSocketErrorCode::ConcurrentLimitReached, 4032, ErrorCategory::Permission, Retry),
+    (WebSocketErrorCode::InvalidPayload, 4220, ErrorCategory::Validation, Fatal),
+    (WebSocketErrorCode::NoteNotFound, 4221, ErrorCategory::Validation, Fatal),
+    (WebSocketErrorCode::SessionNotFound, 4222, ErrorCategory::Validation, Fatal),
+    (WebSocketErrorCode::InternalError, 5000, ErrorCategory::Server, Retry),
+    (WebSocketErrorCode::DatabaseError, 5001, ErrorCategory::Server, Retry),
+    (WebSocketErrorCode::YjsSyncFailed, 5002, ErrorCategory::Server, Retry),
+];
+
+fn assert_disposition(code: WebSocketErrorCode, disposition: ErrorDisposition) {
+    assert_eq!(code.disposition(), disposition, "{code}");
+    assert_eq!(code.is_fatal(), disposition == Fatal, "{code}");
+    assert_eq!(code.requires_reauth(), disposition == Reauthenticate, "{code}");
+    assert_eq!(code.is_retryable(), disposition == Retry, "{code}");
+}
+
+#[test]
+fn known_codes_serialize_as_numbers() {
+    for (code, number, category, disposition) in TABLE {
+        assert_eq!(code.code(), number);
+        assert_eq!(WebSocketErrorCode::from_code(number), code);
+        assert_eq!(serde_json::to_value(code).unwrap(), json!(number));
+        assert_eq!(serde_json::from_value::<WebSocketErrorCode>(json!(number)).unwrap(), code);
+        assert_eq!(code.to_string(), number.to_string());
+        assert_eq!(code.category(), category, "{code}");
+        assert_disposition(code, disposition);
+    }
+}
+
+#[test]
+fn unknown_codes_are_kept_and_classified_by_range() {
+    let cases = [
+        (4013, ErrorCategory::Auth, Reauthenticate),
+        (4019, ErrorCategory::Auth, Reauthenticate),
+        (4033, ErrorCategory::Permission, Fatal),
+        (4223, ErrorCategory::Validation, Fatal),
+        (4000, ErrorCategory::Server, Fatal),
+        (4999, ErrorCategory::Server, Fatal),
+        (5003, ErrorCategory::Server, Retry),
+        (5999, ErrorCategory::Server, Retry),
+    ];
+    for (number, category, disposition) in cases {
+        let code = serde_json::from_value::<WebSocketErrorCode>(json!(number)).unwrap();
+        assert_eq!(code, WebSocketErrorCode::Unknown(number));
+        assert_eq!(serde_json::to_value(code).unwrap(), json!(number));
+        assert_eq!(code.category(), category, "{code}");
+        assert_disposition(code, disposition);
+    }
+}
+
+#[test]
+fn codes_must_be_u16_numbers() {
+    for value in [json!("4012"), json!(-1), json!(70000), json!(4012.5), json!(null)] {
+        assert!(serde_json::from_value::<WebSocketErrorCode>(value.clone()).is_err(), "{value} must not decode");
+    }
+}
+
+#[test]
+fn atlas_ws_error_exposes_the_gateway_frame() {
+    let response: WsErrorResponse = serde_json::from_value(json!({
+        "code": 5001,
+        "message": "Database operation failed",
+        "category": "SERVER",
+        "details": { "dbCode": "23505" },
+        "timestamp": "2026-01-05T10:00:00.000Z"
+    }))
+    .unwrap();
+    let err = AtlasWsError::from(response.clone());
+    assert_eq!(err.code(), WebSocketErrorCode::DatabaseError);
+    assert_eq!(err.category(), ErrorCategory::Server);
+    assert_eq!(err.message(), "Database operation failed");
+    assert_eq!(err.details(), Some(&json!({ "dbCode": "23505" })));
+    assert_eq!(err.disposition(), Retry);
+    assert_eq!(err.to_string(), r#"WebSocket error 5001: Database operation failed ({"dbCode":"23505"})"#);
+    assert_eq!(serde_json::to_value(err.into_response()).unwrap(), serde_json::to_value(response).unwrap());
+
+    let unknown: WsErrorResponse = serde_json::from_value(json!({
+        "code": 4015,
+        "message": "Session revoked",
+        "category": "AUTH",
+        "details": null
+    }))
+    .unwrap();
+    let err = AtlasWsError::new(unknown);
+    assert_eq!(err.code(), WebSocketErrorCode::Unknown(4015));
+    assert_eq!(err.details(), None);
+    assert_eq!(err.disposition(), Reauthenticate);
+    assert_eq!(err.to_string(), "WebSocket error 4015: Session revoked");
+    let source: &dyn std::error::Error = &err;
+    assert!(source.source().is_none());
+}
@@ -6,6 +6,33 @@ const SHARED_ATLAS_PATH = path.join(__dirname, '../src');
 const OUTPUT_DIR = path.join(__dirname, '../../shared-atlas-rust');
 const SRC_DIR = path.join(OUTPUT_DIR, 'src');
 
+// First line of every generated model file. Files without it are
+// hand-maintained and never overwritten; delete the line before editing a
+// generated file by hand.
+const GENERATED_MARKER = '// @generated by packages/shared-atlas/script/generate-rust.ts';
+
+// Hand-written crate types the generated models refer to, by module path.
+const CRATE_TYPES: Record<string, string> = {
+    Timestamp: 'time::timestamp',
+    ActivityId: 'ids::activity_id',
+    BlockId: 'ids::block_id',
+    NoteId: 'ids::note_id',
+    SessionId: 'ids::session_id',
+    TemplateId: 'ids::template_id',
+    UserId: 'ids::user_id',
+};
+
+// String fields holding a typed ID. `messageId` and `parentId` are left out:
+// they name different kinds of IDs depending on the payload.
+const ID_FIELDS: Record<string, string> = {
+    activityId: 'ActivityId',
+    blockId: 'BlockId',
+    noteId: 'NoteId',
+    sessionId: 'SessionId',
+    templateId: 'TemplateId',
+    userId: 'UserId',
+};
+
 // Ensure output directories exist
 if (!fs.existsSync(SRC_DIR)) {
     fs.mkdirSync(SRC_DIR, { recursive: true });
@@ -49,7 +76,7 @@ function mapTypeToRust(type: Type, isOptional: boolean): string {
             rustType = 'Vec<serde_json::Value>';
         }
     } else if (text === 'Date') {
-        rustType = 'chrono::DateTime<chrono::Utc>';
+        rustType = 'Timestamp';
     } else if (type.isClass() || type.isInterface()) {
         const symbol = type.getSymbol();
         if (symbol) {
@@ -98,7 +125,7 @@ function generateRustEnum(enumDec: EnumDeclaration): string {
     const jsDoc = extractJsDoc(enumDec);
     if (jsDoc.length > 0) lines.push(...jsDoc);
 
-    lines.push(`#[derive(Debug, Clone, Serialize, Deserialize)]`);
+    lines.push(`#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]`);
     lines.push(`pub enum ${enumName} {`);
 
     const members = enumDec.getMembers();
@@ -119,6 +146,11 @@ function generateRustEnum(enumDec: EnumDeclaration): string {
         lines.push(`    ${rustName},`);
     });
 
+    // Newer servers may send values this crate does not know yet.
+    lines.push(`/// A value this crate does not know yet, kept as sent.`);
+    lines.push(`/// 本库尚未识别的值，按原样保留。`);
+    lines.push(`    #[serde(untagged)]`);
+    lines.push(`    Unknown(String),`);
     lines.push(`}`);
     return lines.join('\n');
 }
@@ -184,6 +216,9 @@ function generateRustStruct(classDec: ClassDeclaration | InterfaceDeclaration):
         }
 
         let rustType = mapTypeToRust(propType, isOptional);
+        if (ID_FIELDS[originalName]) {
+            rustType = rustType.replace(/\bString\b/, ID_FIELDS[originalName]);
+        }
 
         // Int override
         if (rustType.includes('f64') && Node.isPropertyDeclaration(propDecl)) {
@@ -205,6 +240,31 @@ function generateRustStruct(classDec: ClassDeclaration | InterfaceDeclaration):
     return lines.join('\n');
 }
 
+// Writes a generated file unless a hand-maintained one (no marker) is there.
+function writeGenerated(filePath: string, content: string) {
+    if (fs.existsSync(filePath)) {
+        const firstLine = fs.readFileSync(filePath, 'utf-8').split(/\r?\n/, 1)[0];
+        if (firstLine !== GENERATED_MARKER) {
+            console.log(`Kept hand-maintained ${path.relative(OUTPUT_DIR, filePath)}`);
+            return;
+        }
+    }
+    fs.writeFileSync(filePath, content);
+}
+
+// Appends `pub mod` lines for modules the file does not declare yet.
+function mergeModules(filePath: string, modNames: string[]) {
+    const existing = fs.existsSync(filePath) ? fs.readFileSync(filePath, 'utf-8') : '';
+    const declared = new Set<string>();
+    const pattern = /^\s*pub(?:\([^)]*\))?\s+mod\s+(\w+)\s*;/gm;
+    let match: RegExpExecArray | null;
+    while ((match = pattern.exec(existing)) !== null) declared.add(match[1]);
+    const missing = modNames.filter(name => !declared.has(name));
+    if (missing.length === 0) return;
+    const separator = existing === '' || existing.endsWith('\n') ? '' : '\n';
+    fs.writeFileSync(filePath, existing + separator + missing.map(name => `pub mod ${name};`).join('\n') + '\n');
+}
+
 async function main() {
     console.log('Generating Rust models...');
 
@@ -233,10 +293,6 @@ async function main() {
         const uses = [
             'use serde::{Serialize, Deserialize};',
         ];
-        // If content has DateTime, add chrono
-        if (content.includes('DateTime<Utc>')) {
-            uses.push('use chrono::{DateTime, Utc};');
-        }
         // If content has serde_json::Value
         if (content.includes('serde_json::Value')) {
             uses.push('use serde_json;');
@@ -379,18 +435,23 @@ async function main() {
                         requiredImports.add(`use crate::${modPath}::${typeName};`);
                     }
                 });
+
+                for (const [typeName, modPath] of Object.entries(CRATE_TYPES)) {
+                    if (new RegExp(`\\b${typeName}\\b`).test(codeWithoutComments)) {
+                        requiredImports.add(`use crate::${modPath}::${typeName};`);
+                    }
+                }
             }
 
             const importsList = [
                 'use serde::{Serialize, Deserialize};',
                 ...Array.from(requiredImports)
             ];
-            if (code.includes('DateTime<Utc>')) importsList.push('use chrono::{DateTime, Utc};');
             if (code.includes('serde_json::Value')) importsList.push('use serde_json;');
 
 
-            const fileOut = importsList.join('\n') + '\n\n' + code;
-            fs.writeFileSync(path.join(targetDir, snakeName + '.rs'), fileOut);
+            const fileOut = GENERATED_MARKER + '\n' + importsList.join('\n') + '\n\n' + code;
+            writeGenerated(path.join(targetDir, snakeName + '.rs'), fileOut);
 
             // Track for mod.rs
             if (!modules.has(relativeDir)) modules.set(relativeDir, []);
@@ -398,27 +459,14 @@ async function main() {
         }
     }
 
-    // Generate mod.rs files
+    // Add new modules to the mod.rs files, keeping the hand-written entries
     modules.forEach((modNames, dir) => {
-        const modContent = modNames.map(n => `pub mod ${n};`).join('\n');
-        fs.writeFileSync(path.join(SRC_DIR, dir, 'mod.rs'), modContent);
-    });
-
-    // Generate lib.rs (or main mod) imports
-    // We assume top level dirs (dto, interfaces) need to be in lib.rs
-    const topDirs = new Set<string>(modules.keys());
-    const libContent: string[] = [];
-    topDirs.forEach(d => {
-        // d is like "dto" or "interfaces"
-        // If it's nested "foo/bar" -> structure "foo" mod.rs?
-        // simple support for 1 level nesting for now as per shared-atlas
-
-        // Actually shared-atlas has "dto" and "interfaces" at root of src.
-        // So d is "dto".
-        libContent.push(`pub mod ${d};`);
+        mergeModules(path.join(SRC_DIR, dir, 'mod.rs'), modNames);
     });
 
-    fs.writeFileSync(path.join(SRC_DIR, 'lib.rs'), libContent.join('\n'));
+    // lib.rs also declares hand-written modules, so only missing model
+    // directories are added.
+    mergeModules(path.join(SRC_DIR, 'lib.rs'), Array.from(modules.keys()));
     console.log('Done.');
 
     // Update Cargo.toml version